@@ -1,4 +1,4 @@
-use clap::{Parser, Subcommand};
+use clap::{Args, Parser, Subcommand};
 
 #[derive(Parser)]
 #[command(name = "tasky")]
@@ -6,6 +6,9 @@ use clap::{Parser, Subcommand};
 #[command(version = "0.1.8")]
 #[command(about = "개인용 할일 관리 CLI 도구", long_about = None)]
 pub struct Cli {
+  /// 출력 형식: table, json, jsonl, csv, tsv, ids
+  #[arg(long, global = true, default_value = "table")]
+  pub format: String,
   #[command(subcommand)]
   pub command: Commands,
 }
@@ -20,46 +23,150 @@ pub enum Commands {
     priority: String,
     #[arg(long, allow_hyphen_values = true)]
     due: Option<String>,
+    #[arg(long = "tag")]
+    tags: Vec<String>,
+    #[arg(long)]
+    project: Option<String>,
+    /// 상위 할일 ID나 UUID 앞부분 (하위 할일로 추가)
+    #[arg(long)]
+    parent: Option<String>,
+    /// 반복 규칙 (daily, weekly, weekly:mon,thu, monthly, monthly:last, "every 3 days")
+    #[arg(long)]
+    repeat: Option<String>,
   },
 
+  /// 할일 목록 조회 (필터 식과 옵션으로 범위와 정렬 지정)
   List {
+    #[command(flatten)]
+    options: ListOptions,
     #[arg(short, long)]
-    status: Option<String>,
-    #[arg(short, long)]
-    priority: Option<String>,
-    #[arg(long, default_value = "created")]
-    sort: String,
-    #[arg(long, default_value = "desc")]
-    order: String,
-    #[arg(long)]
-    today: bool,
-    #[arg(long)]
-    overdue: bool,
-    #[arg(long)]
-    urgent: bool,
+    verbose: bool,
+  },
+
+  /// 저장된 보기로 목록 조회 (today, overdue, urgent는 기본 제공)
+  #[command(args_conflicts_with_subcommands = true)]
+  View {
+    #[command(subcommand)]
+    action: Option<ViewAction>,
+    /// 보기 이름 (없으면 보기 목록 표시)
+    name: Option<String>,
+    /// 보기에 더할 조건
+    #[command(flatten)]
+    options: ListOptions,
     #[arg(short, long)]
     verbose: bool,
   },
 
+  /// 제목과 설명 전문 검색 ("구절", 접두*, -제외)
+  Search {
+    #[arg(required = true, allow_hyphen_values = true)]
+    query: Vec<String>,
+    /// 표시할 최대 건수
+    #[arg(short = 'n', long, default_value_t = 20)]
+    limit: usize,
+  },
+
+  /// 할일 상세 (ID나 UUID 앞부분으로 지정, 이후 명령도 같음)
   Show {
-    id: i64
+    id: String
+  },
+
+  /// 최근 작업 되돌리기
+  Undo {
+    /// 되돌릴 작업 수
+    #[arg(default_value_t = 1)]
+    count: usize,
+    /// 되돌릴 내용만 표시하고 실행하지 않음
+    #[arg(long)]
+    dry_run: bool,
+  },
+
+  /// 되돌린 작업 다시 실행
+  Redo {
+    /// 다시 실행할 작업 수
+    #[arg(default_value_t = 1)]
+    count: usize,
+    /// 다시 실행할 내용만 표시하고 실행하지 않음
+    #[arg(long)]
+    dry_run: bool,
+  },
+
+  /// 변경 이력 (ID를 주면 해당 할일만)
+  Log {
+    id: Option<String>,
+    /// 표시할 최대 건수
+    #[arg(short = 'n', long, default_value_t = 20)]
+    limit: usize,
   },
 
   Done {
-    ids: Vec<i64>
+    ids: Vec<String>,
+    /// 완료되지 않은 하위 할일도 함께 완료
+    #[arg(long)]
+    cascade: bool,
   },
 
   Undone {
-    id: i64
+    id: String
+  },
+
+  /// 완료된 할일 보관 (기본 목록과 통계에서 제외)
+  Archive {
+    #[arg(conflicts_with = "done_before")]
+    ids: Vec<String>,
+    /// 이 날짜 전에 완료된 할일을 모두 보관
+    #[arg(long, allow_hyphen_values = true)]
+    done_before: Option<String>,
   },
 
+  /// 보관 해제
+  Unarchive {
+    ids: Vec<String>,
+  },
+
+  /// 할일을 휴지통으로 이동 (하위 할일 포함)
   Remove {
-    id: i64
+    id: String
+  },
+
+  /// 휴지통 목록
+  Trash,
+
+  /// 휴지통의 할일 복원 (함께 삭제된 하위 할일 포함)
+  Restore {
+    id: String
+  },
+
+  /// 휴지통의 할일을 영구 삭제
+  Purge {
+    /// 영구 삭제할 할일 ID
+    #[arg(conflicts_with_all = ["older_than", "all"])]
+    ids: Vec<String>,
+    /// 휴지통에 들어간 지 지정한 기간이 지난 할일만 (예: 30d, 2w, 12h)
+    #[arg(long, conflicts_with = "all")]
+    older_than: Option<String>,
+    /// 휴지통 비우기
+    #[arg(long)]
+    all: bool,
+  },
+
+  /// 선행 할일 지정 (ID는 --on 할일이 완료되어야 시작 가능)
+  Depend {
+    id: String,
+    #[arg(long, required = true)]
+    on: Vec<String>,
+  },
+
+  /// 선행 할일 해제
+  Undepend {
+    id: String,
+    #[arg(long, required = true)]
+    on: Vec<String>,
   },
 
   Edit {
 
-    id: i64,
+    id: String,
     #[arg(short, long)]
     title: Option<String>,
     #[arg(short, long)]
@@ -68,12 +175,232 @@ pub enum Commands {
     priority: Option<String>,
     #[arg(long, allow_hyphen_values = true)]
     due: Option<String>,
+    /// 태그 목록을 지정한 태그로 교체
+    #[arg(long = "tag")]
+    tags: Vec<String>,
+    /// 모든 태그 제거
+    #[arg(long, conflicts_with = "tags")]
+    clear_tags: bool,
+    #[arg(long)]
+    project: Option<String>,
+    /// 프로젝트 해제
+    #[arg(long, conflicts_with = "project")]
+    clear_project: bool,
+  },
+
+  Stats {
+    /// 지정한 프로젝트(하위 프로젝트 포함)로 통계 범위 한정
+    #[arg(long)]
+    project: Option<String>,
+    /// 보관된 할일도 포함
+    #[arg(long)]
+    archived: bool,
+  },
+  Projects {
+    /// 보관된 할일도 포함
+    #[arg(long)]
+    archived: bool,
+  },
+
+  /// 할일을 파일로 내보내기 (표준 출력, 기본은 JSON, 보관된 할일 포함)
+  Export {
+    /// 내보낼 할일 (list와 같은 필터와 정렬)
+    #[command(flatten)]
+    options: ListOptions,
+    /// 필터 식 (위치 인자와 같음, 여러 번 주면 모두 만족)
+    #[arg(long = "filter")]
+    filters: Vec<String>,
+    /// 스프레드시트용 CSV로 내보내기
+    #[arg(long, group = "file_format")]
+    csv: bool,
+    /// todo.txt 형식으로 내보내기
+    #[arg(long, group = "file_format")]
+    todotxt: bool,
+    /// iCalendar(.ics) VTODO로 내보내기
+    #[arg(long, group = "file_format")]
+    ics: bool,
+    /// Taskwarrior `task import`용 JSON으로 내보내기
+    #[arg(long, group = "file_format")]
+    taskwarrior: bool,
+    /// GitHub 체크박스 Markdown 목록으로 내보내기 (기본은 우선순위별로 묶음)
+    #[arg(long, groups = ["file_format", "document"])]
+    markdown: bool,
+    /// Org-mode TODO/DONE 제목으로 내보내기
+    #[arg(long, groups = ["file_format", "document"])]
+    org: bool,
+    /// Markdown/Org 목록을 묶는 기준: priority, due
+    #[arg(long, requires = "document")]
+    group_by: Option<String>,
+    /// iCalendar에 마감일 일정(VEVENT)도 함께 쓰기
+    #[arg(long, requires = "ics")]
+    events: bool,
+    /// CSV 열 지정 (필드=열 이름, 지정한 열만 그 순서로 내보냄)
+    #[arg(long = "column", requires = "csv")]
+    columns: Vec<String>,
   },
 
-  Stats,
+  /// 파일에서 할일 가져오기 (기본은 export로 만든 JSON)
+  Import {
+    /// 가져올 파일 (-이면 표준 입력)
+    file: String,
+    /// 스프레드시트 CSV 가져오기 (잘못된 행은 건너뛰고 보고)
+    #[arg(long, group = "file_format")]
+    csv: bool,
+    /// todo.txt 파일 가져오기 (모두 새 ID로 추가)
+    #[arg(long, group = "file_format")]
+    todotxt: bool,
+    /// iCalendar(.ics)의 VTODO 가져오기 (잘못된 항목은 건너뛰고 보고)
+    #[arg(long, group = "file_format")]
+    ics: bool,
+    /// Taskwarrior `task export` JSON 가져오기 (모두 새 ID로 추가)
+    #[arg(long, group = "file_format")]
+    taskwarrior: bool,
+    /// CSV 열 지정 (필드=열 이름, 예: title=제목)
+    #[arg(long = "column", requires = "csv")]
+    columns: Vec<String>,
+    /// CSV 날짜가 31/12/2026처럼 일이 먼저 옴
+    #[arg(long, requires = "csv")]
+    day_first: bool,
+    /// 같은 할일(UUID)이 있으면 건너뜀
+    #[arg(long, conflicts_with_all = ["overwrite", "renumber", "file_format"])]
+    skip_existing: bool,
+    /// 같은 할일(UUID)이 있으면 파일 내용으로 덮어씀
+    #[arg(long, conflicts_with_all = ["renumber", "file_format"])]
+    overwrite: bool,
+    /// 모든 할일을 새 ID의 사본으로 추가
+    #[arg(long, conflicts_with = "file_format")]
+    renumber: bool,
+    /// 가져올 내용만 표시하고 실행하지 않음
+    #[arg(long)]
+    dry_run: bool,
+  },
+
+  /// 다른 Tasky 데이터베이스 파일을 합침 (같은 할일은 UUID로 찾아 필드별로 나중 값을 씀)
+  Merge {
+    /// 합칠 데이터베이스 파일
+    file: String,
+    /// 바뀔 내용만 표시하고 합치지 않음
+    #[arg(long)]
+    dry_run: bool,
+  },
+
+  /// 반복 시리즈 목록 및 종료
+  Series {
+    #[command(subcommand)]
+    action: Option<SeriesAction>,
+  },
   Init {
     #[arg(long)]
     force: bool,
   },
   DbInfo,
-}
\ No newline at end of file
+
+  /// 데이터베이스 스키마 관리
+  Db {
+    #[command(subcommand)]
+    action: DbAction,
+  },
+}
+
+#[derive(Subcommand)]
+pub enum DbAction {
+  /// 대기중인 스키마 마이그레이션 적용
+  Migrate {
+    /// 마이그레이션별 적용 상태만 표시
+    #[arg(long, conflicts_with = "dry_run")]
+    status: bool,
+    /// 적용될 마이그레이션만 표시하고 실제로 실행하지 않음
+    #[arg(long)]
+    dry_run: bool,
+  },
+}
+
+#[derive(Subcommand)]
+pub enum SeriesAction {
+  /// 모든 반복 시리즈 표시 (기본 동작)
+  List,
+  /// 시리즈를 종료해 더 이상 다음 회차가 생기지 않게 함
+  End {
+    id: i64
+  },
+}
+
+// `list`와 보기가 함께 쓰는 필터/정렬 옵션
+// (`///`로 쓰면 clap이 `list` 명령의 설명으로 가져감)
+#[derive(Args)]
+pub struct ListOptions {
+  /// 필터 식 (예: 'priority>=medium and (due<+7d or tag:work) and not status:done')
+  pub filter: Vec<String>,
+  #[arg(short, long)]
+  pub status: Option<String>,
+  #[arg(short, long)]
+  pub priority: Option<String>,
+  /// 정렬 기준 (기본: created, --today/--overdue/--urgent는 due)
+  #[arg(long)]
+  pub sort: Option<String>,
+  /// 정렬 순서 (기본: desc, --today/--overdue/--urgent는 asc)
+  #[arg(long)]
+  pub order: Option<String>,
+  #[arg(long)]
+  pub today: bool,
+  #[arg(long)]
+  pub overdue: bool,
+  #[arg(long)]
+  pub urgent: bool,
+  #[arg(long = "tag")]
+  pub tags: Vec<String>,
+  #[arg(long = "without-tag")]
+  pub without_tags: Vec<String>,
+  /// 하위 프로젝트 포함
+  #[arg(long)]
+  pub project: Option<String>,
+  /// 완료되지 않은 선행 할일이 있는 할일만
+  #[arg(long, conflicts_with = "ready")]
+  pub blocked: bool,
+  /// 바로 시작할 수 있는 대기 할일만
+  #[arg(long)]
+  pub ready: bool,
+  /// 보관된 할일도 포함
+  #[arg(long)]
+  pub archived: bool,
+  /// 제목/설명 검색 (search와 같은 문법)
+  #[arg(long, allow_hyphen_values = true)]
+  pub grep: Option<String>,
+}
+
+impl ListOptions {
+  /// 필터 식이나 옵션이 하나도 주어지지 않았는지
+  pub fn is_empty(&self) -> bool {
+    self.filter.is_empty()
+      && self.status.is_none()
+      && self.priority.is_none()
+      && self.sort.is_none()
+      && self.order.is_none()
+      && !self.today
+      && !self.overdue
+      && !self.urgent
+      && self.tags.is_empty()
+      && self.without_tags.is_empty()
+      && self.project.is_none()
+      && !self.blocked
+      && !self.ready
+      && !self.archived
+      && self.grep.is_none()
+  }
+}
+
+#[derive(Subcommand)]
+pub enum ViewAction {
+  /// list 옵션을 이름을 붙여 저장 (같은 이름이면 덮어씀)
+  Save {
+    name: String,
+    #[command(flatten)]
+    options: Box<ListOptions>,
+  },
+  /// 기본 보기와 저장된 보기 표시
+  List,
+  /// 저장된 보기 삭제
+  Delete {
+    name: String
+  },
+}
@@ -1,58 +1,263 @@
 use colored::*;
 use prettytable::{Cell, Row, Table};
-
-use crate::cli::args::Commands;
-use crate::error::Result;
-use crate::models::{CreateTodo, Priority, SortBy, SortOrder, Status, Todo, TodoFilter, UpdateTodo};
+use std::collections::{HashMap, HashSet};
+
+use crate::cli::args::{Cli, Commands, DbAction, ListOptions, SeriesAction, ViewAction};
+use crate::cli::output::{self, OutputFormat};
+use crate::error::{Result, TaskyError};
+use crate::formats::spreadsheet::{self, ColumnMapping, DateOptions};
+use crate::formats::taskwarrior::{self, UnmappedField};
+use crate::formats::document::{self, GroupBy};
+use crate::formats::{icalendar, todotxt, ImportRow, ImportRowReport};
+use crate::models::{
+  field_changes, field_display_name, normalize_tag, ConflictStrategy, CreateTodo, EventKind, ExportFile, ImportAction,
+  MergeAction, MergeSide, Operation, OperationChange, Priority, RecurrenceRule, SearchQuery, SortBy, SortOrder, Status, Todo, TodoEvent,
+  TodoSnapshot, UpdateTodo, ViewDefinition,
+};
 use crate::services::{TodoService, DefaultTodoService};
-use crate::utils;
+use crate::utils::{self, Clock};
+
+pub fn execute(cli: Cli) -> Result<()> {
+  let Cli { command, format } = cli;
+  let format = OutputFormat::from_str(&format)?;
+
+  // 데이터베이스 관리 명령은 자동 마이그레이션 전에 처리
+  let command = match command {
+    Commands::Init { force } => {
+      require_table(format, "init")?;
+      return handle_init(force);
+    }
+    Commands::DbInfo => return handle_db_info(format),
+    Commands::Db { action: DbAction::Migrate { status, dry_run } } => {
+      require_table(format, "db migrate")?;
+      return handle_db_migrate(status, dry_run);
+    }
+    command => command,
+  };
 
-pub fn execute(command: Commands) -> Result<()> {
   let service = DefaultTodoService::default()?;
 
+  // 할일을 바꾸는 명령은 하나의 작업으로 저널에 기록 (undo/redo 단위)
+  let journaled = matches!(command,
+    Commands::Add { .. } | Commands::Edit { .. } | Commands::Done { .. } | Commands::Undone { .. }
+      | Commands::Archive { .. } | Commands::Unarchive { .. }
+      | Commands::Remove { .. } | Commands::Restore { .. } | Commands::Purge { .. }
+      | Commands::Depend { .. } | Commands::Undepend { .. } | Commands::Import { .. }
+      | Commands::Merge { .. }
+  );
+  if journaled {
+    let label = std::env::args().skip(1).collect::<Vec<_>>().join(" ");
+    service.begin_operation(&label)?;
+  }
+
+  let result = run_command(&service, command, format);
+
+  if journaled {
+    service.end_operation()?;
+  }
+
+  result
+}
+
+/// 명령 인자의 할일 ID나 UUID 앞부분들을 ID로
+fn resolve_todo_ids(service: &impl TodoService, references: &[String]) -> Result<Vec<i64>> {
+  references.iter().map(|reference| service.resolve_todo_id(reference)).collect()
+}
+
+fn run_command(service: &DefaultTodoService, command: Commands, format: OutputFormat) -> Result<()> {
   match command {
-    Commands::Add { title, description, priority, due } => {
-      handle_add(&service, title, description, priority, due)
+    Commands::Add { title, description, priority, due, tags, project, parent, repeat } => {
+      let parent = parent.as_deref().map(|parent| service.resolve_todo_id(parent)).transpose()?;
+      handle_add(service, title, description, priority, due, tags, project, parent, repeat, format)
+    }
+    Commands::List { options, verbose } => {
+      handle_list(service, options, verbose, format)
+    }
+    Commands::View { action, name, options, verbose } => {
+      match (action, name) {
+        (Some(ViewAction::Save { name, options }), _) => {
+          require_table(format, "view save")?;
+          handle_view_save(service, name, *options)
+        }
+        (Some(ViewAction::Delete { name }), _) => {
+          require_table(format, "view delete")?;
+          handle_view_delete(service, name)
+        }
+        (Some(ViewAction::List), _) => handle_view_list(service, format),
+        (None, None) => {
+          // 이름 없이 준 조건을 조용히 버리지 않음
+          if !options.is_empty() || verbose {
+            return Err(TaskyError::InvalidInput {
+              message: "보기 이름 없이 조건이나 옵션을 줄 수 없습니다 (예: tasky view today --tag work)".to_string()
+            });
+          }
+          handle_view_list(service, format)
+        }
+        (None, Some(name)) => handle_view(service, name, options, verbose, format),
+      }
     }
-    Commands::List { status, priority, sort, order, today, overdue, urgent, verbose } => {
-      handle_list(&service, status, priority, sort, order, today, overdue, urgent, verbose)
+    Commands::Search { query, limit } => {
+      handle_search(service, query.join(" "), limit, format)
     }
     Commands::Show { id } => {
-      handle_show(&service, id)
+      handle_show(service, service.resolve_todo_id(&id)?, format)
+    }
+    Commands::Log { id, limit } => {
+      let id = id.as_deref().map(|id| service.resolve_todo_id(id)).transpose()?;
+      handle_log(service, id, limit, format)
+    }
+    Commands::Undo { count, dry_run } => {
+      handle_undo(service, count, dry_run, format)
     }
-    Commands::Done { ids } => {
-      handle_done(&service, ids)
+    Commands::Redo { count, dry_run } => {
+      handle_redo(service, count, dry_run, format)
+    }
+    Commands::Done { ids, cascade } => {
+      handle_done(service, resolve_todo_ids(service, &ids)?, cascade, format)
     }
     Commands::Undone { id } => {
-      handle_undone(&service, id)
+      handle_undone(service, service.resolve_todo_id(&id)?, format)
+    }
+    Commands::Archive { ids, done_before } => {
+      handle_archive(service, resolve_todo_ids(service, &ids)?, done_before, format)
+    }
+    Commands::Unarchive { ids } => {
+      handle_unarchive(service, resolve_todo_ids(service, &ids)?, format)
     }
     Commands::Remove { id } => {
-      handle_remove(&service, id)
+      handle_remove(service, service.resolve_todo_id(&id)?, format)
     }
-    Commands::Edit { id, title, description, priority, due } => {
-      handle_edit(&service, id, title, description, priority, due)
+    Commands::Trash => {
+      handle_trash(service, format)
     }
-    Commands::Stats => {
-      handle_stats(&service)
+    Commands::Restore { id } => {
+      handle_restore(service, service.resolve_todo_id(&id)?, format)
     }
-    Commands::Init { force } => {
-      handle_init(force)
+    Commands::Purge { ids, older_than, all } => {
+      handle_purge(service, resolve_todo_ids(service, &ids)?, older_than, all, format)
+    }
+    Commands::Depend { id, on } => {
+      handle_depend(service, service.resolve_todo_id(&id)?, resolve_todo_ids(service, &on)?, format)
+    }
+    Commands::Undepend { id, on } => {
+      handle_undepend(service, service.resolve_todo_id(&id)?, resolve_todo_ids(service, &on)?, format)
     }
-    Commands::DbInfo => {
-      handle_db_info()
+    Commands::Edit { id, title, description, priority, due, tags, clear_tags, project, clear_project } => {
+      handle_edit(service, service.resolve_todo_id(&id)?, title, description, priority, due, tags, clear_tags, project, clear_project, format)
     }
+    Commands::Stats { project, archived } => {
+      handle_stats(service, project, archived, format)
+    }
+    Commands::Projects { archived } => {
+      handle_projects(service, archived, format)
+    }
+    Commands::Export { options, filters, csv, todotxt, ics, taskwarrior, markdown, org, group_by, events, columns } => {
+      let group_by = group_by.as_deref().map(GroupBy::from_str).transpose()?;
+      let export_format = if csv {
+        ExportFormat::Csv(ColumnMapping::parse(&columns)?)
+      } else if todotxt {
+        ExportFormat::TodoTxt
+      } else if ics {
+        ExportFormat::Ics { events }
+      } else if taskwarrior {
+        ExportFormat::Taskwarrior
+      } else if markdown {
+        ExportFormat::Markdown(Some(group_by.unwrap_or(GroupBy::Priority)))
+      } else if org {
+        ExportFormat::Org(group_by)
+      } else {
+        ExportFormat::Json
+      };
+      handle_export(service, options, filters, export_format)
+    }
+    Commands::Import { file, csv: true, columns, day_first, dry_run, .. } => {
+      let mapping = ColumnMapping::parse(&columns)?;
+      let rows = spreadsheet::read_csv(&read_import_source(&file)?, &mapping, DateOptions { day_first }, service.clock())?;
+      handle_import_rows(service, rows, "CSV", dry_run, format)
+    }
+    Commands::Import { file, taskwarrior: true, dry_run, .. } => {
+      let imported = taskwarrior::read_taskwarrior(&read_import_source(&file)?, service.clock().now())?;
+      // 파일 안의 ID는 순서일 뿐이므로 모두 새 ID로
+      handle_import(service, imported.file, ConflictStrategy::Renumber, dry_run, format)?;
+      print_unmapped_fields(&imported.unmapped, !format.is_table());
+      Ok(())
+    }
+    Commands::Import { file, ics: true, dry_run, .. } => {
+      let rows = icalendar::read_ics(&read_import_source(&file)?)?;
+      handle_import_rows(service, rows, "iCalendar", dry_run, format)
+    }
+    Commands::Import { file, todotxt: true, dry_run, .. } => {
+      let mut parsed = ExportFile::new(service.clock().now());
+      parsed.todos = todotxt::read_todotxt(&read_import_source(&file)?, service.clock())?;
+      handle_import(service, parsed, ConflictStrategy::Fail, dry_run, format)
+    }
+    Commands::Import { file, skip_existing, overwrite, renumber, dry_run, .. } => {
+      let strategy = if skip_existing {
+        ConflictStrategy::SkipExisting
+      } else if overwrite {
+        ConflictStrategy::Overwrite
+      } else if renumber {
+        ConflictStrategy::Renumber
+      } else {
+        ConflictStrategy::Fail
+      };
+      handle_import(service, ExportFile::from_json(&read_import_source(&file)?)?, strategy, dry_run, format)
+    }
+    Commands::Merge { file, dry_run } => handle_merge(service, &file, dry_run, format),
+    Commands::Series { action } => {
+      match action.unwrap_or(SeriesAction::List) {
+        SeriesAction::List => handle_series_list(service, format),
+        SeriesAction::End { id } => {
+          require_table(format, "series end")?;
+          handle_series_end(service, id)
+        }
+      }
+    }
+    Commands::Init { .. } | Commands::DbInfo | Commands::Db { .. } => unreachable!(),
   }
 }
 
+/// 표 출력만 있는 명령에 다른 --format을 주면 오류
+fn require_table(format: OutputFormat, command: &str) -> Result<()> {
+  if format.is_table() {
+    Ok(())
+  } else {
+    Err(TaskyError::InvalidInput { message: format!("'{}' 명령은 --format table만 지원합니다", command) })
+  }
+}
+
+/// 휴지통에 있는 할일 ID. 명령 전후를 비교해 영향을 받은 할일을 찾음 (--format 출력용)
+fn trash_ids(service: &impl TodoService) -> Result<HashSet<i64>> {
+  Ok(service.list_trash()?.into_iter().filter_map(|todo| todo.id).collect())
+}
+
+#[allow(clippy::too_many_arguments)]
 fn handle_add(
   service: &impl TodoService,
   title: String,
   description: Option<String>,
   priority: String,
   due: Option<String>,
+  tags: Vec<String>,
+  project: Option<String>,
+  parent: Option<i64>,
+  repeat: Option<String>,
+  format: OutputFormat,
 ) -> Result<()> {
 
-  let mut create_todo = CreateTodo::new(title.clone());
+  let mut create_todo = CreateTodo::new(title.clone()).with_tags(tags);
+
+  if let Some(project) = project {
+    create_todo = create_todo.with_project(project);
+  }
+
+  if let Some(parent_id) = parent {
+    create_todo = create_todo.with_parent(parent_id);
+  }
+
+  if let Some(rule) = repeat {
+    create_todo = create_todo.with_recurrence(RecurrenceRule::parse(&rule)?);
+  }
 
   if let Some(desc) = description {
     create_todo = create_todo.with_description(desc);
@@ -62,12 +267,20 @@ fn handle_add(
   create_todo = create_todo.with_priority(priority);
 
   if let Some(due_str) = due {
-    let due_date = utils::parse_date(&due_str)?;
-    create_todo = create_todo.with_due_date(due_date);
+    let due = utils::parse_due(&due_str, service.clock())?;
+    create_todo = if due.all_day {
+      create_todo.with_due_date(due.at)
+    } else {
+      create_todo.with_due_time(due.at)
+    };
   }
 
   let todo = service.create_todo(create_todo)?;
 
+  if !format.is_table() {
+    return output::print_todos(format, &[todo]);
+  }
+
   println!("{} 할일이 추가되었습니다!", "✅".green());
   println!("  ID: {}", todo.id.unwrap_or(0).to_string().cyan());
   println!("  제목: {}", todo.title.bold());
@@ -77,46 +290,71 @@ fn handle_add(
 
   println!("  우선순위: {} {}", todo.priority.to_emoji(), todo.priority.to_display_string());
   if let Some(due) = todo.due_date {
-      println!("  마감일: {}", utils::format_date(&due).yellow());
+      println!("  마감일: {}", utils::format_due(&due, todo.due_all_day).yellow());
+  }
+  if !todo.tags.is_empty() {
+    println!("  태그: {}", format_tags(&todo.tags).cyan());
+  }
+  if let Some(project) = &todo.project {
+    println!("  프로젝트: {}", project.magenta());
+  }
+  if let Some(parent_id) = todo.parent_id {
+    println!("  상위 할일: {}", parent_id.to_string().cyan());
+  }
+  if let Some(rule) = &todo.recurrence {
+    println!("  반복: {}", rule.blue());
   }
 
   Ok(())
 }
 
-fn handle_list(
-  service: &impl TodoService,
-  status: Option<String>,
-  priority: Option<String>,
-  sort: String,
-  order: String,
-  today: bool,
-  overdue: bool,
-  urgent: bool,
-  verbose: bool,
-) -> Result<()> {
+/// list 옵션을 보기 정의로. --today/--overdue/--urgent는 같은 이름의 기본 보기를 합침
+fn view_definition(options: ListOptions) -> Result<ViewDefinition> {
+  let mut definition = ViewDefinition::default();
 
-  let todos = if today {
-    service.get_today_todos()?
-  } else if overdue {
-    service.get_overdue_todos()?
-  } else if urgent {
-    service.get_urgent_todos()?
-  } else {
-    let mut filter = TodoFilter::default();
+  let expression = options.filter.join(" ");
+  if !expression.trim().is_empty() {
+    definition.expressions.push(expression);
+  }
+  definition.status = options.status.as_deref().map(Status::from_str).transpose()?;
+  definition.priority = options.priority.as_deref().map(Priority::from_str).transpose()?;
+  definition.tags = options.tags.iter().map(|t| normalize_tag(t)).collect();
+  definition.without_tags = options.without_tags.iter().map(|t| normalize_tag(t)).collect();
+  definition.project = options.project;
+  if options.blocked {
+    definition.blocked = Some(true);
+  } else if options.ready {
+    definition.blocked = Some(false);
+  }
+  definition.archived = options.archived;
+  if let Some(grep) = options.grep {
+    // 잘못된 검색어는 바로 알림
+    SearchQuery::parse(&grep)?;
+    definition.grep = Some(grep);
+  }
+  definition.sort = options.sort.as_deref().map(SortBy::from_str).transpose()?;
+  definition.order = options.order.as_deref().map(SortOrder::from_str).transpose()?;
 
-    if let Some(status_str) = status {
-      filter.status = Some(Status::from_str(&status_str)?);
+  for (enabled, name) in [(options.today, "today"), (options.overdue, "overdue"), (options.urgent, "urgent")] {
+    if let Some(builtin) = ViewDefinition::builtin(name).filter(|_| enabled) {
+      definition = definition.merge(builtin);
     }
+  }
 
-    if let Some(priority_str) = priority {
-      filter.priority = Some(Priority::from_str(&priority_str)?);
-    }
+  Ok(definition)
+}
 
-    let sort_by = SortBy::from_str(&sort)?;
-    let sort_order = SortOrder::from_str(&order)?;
+fn handle_list(service: &impl TodoService, options: ListOptions, verbose: bool, format: OutputFormat) -> Result<()> {
+  let definition = view_definition(options)?;
+  print_view(service, &definition, verbose, format)
+}
 
-    service.list_todos(Some(filter), Some(sort_by), Some(sort_order))?
-  };
+fn print_view(service: &impl TodoService, definition: &ViewDefinition, verbose: bool, format: OutputFormat) -> Result<()> {
+  let todos = service.list_view(definition)?;
+
+  if !format.is_table() {
+    return output::print_todos(format, &todos);
+  }
 
   if todos.is_empty() {
     println!("{}", "할일이 없습니다.".yellow());
@@ -124,22 +362,159 @@ fn handle_list(
   }
 
   if verbose {
-    print_todos_verbose(&todos);
+    print_todos_verbose(&todos, service.clock());
   } else {
-    print_todos_table(&todos);
+    print_todos_table(&todos, service.clock());
   }
   println!("\n총 {}개의 할일", todos.len().to_string().cyan());
 
   Ok(())
 }
 
-fn handle_show(service: &impl TodoService, id: i64) -> Result<()> {
+fn handle_view(service: &impl TodoService, name: String, options: ListOptions, verbose: bool, format: OutputFormat) -> Result<()> {
+  let view = service.find_view(&name)?;
+
+  // 명령줄에서 준 옵션이 보기보다 우선
+  let definition = view_definition(options)?.merge(view.definition);
+  print_view(service, &definition, verbose, format)
+}
+
+fn handle_view_save(service: &impl TodoService, name: String, options: ListOptions) -> Result<()> {
+  let definition = view_definition(options)?;
+
+  if service.save_view(&name, &definition)? {
+    println!("{} '{}' 보기를 저장했습니다.", "✅".green(), name.cyan());
+  } else {
+    println!("{} '{}' 보기를 수정했습니다.", "✅".green(), name.cyan());
+  }
+  println!("  {}", format!("tasky list {}", definition.to_args().join(" ")).dimmed());
+
+  Ok(())
+}
+
+fn handle_view_list(service: &impl TodoService, format: OutputFormat) -> Result<()> {
+  let views = service.list_views()?;
+
+  if !format.is_table() {
+    return output::print_records(format, &views);
+  }
+
+  let mut table = Table::new();
+  table.add_row(Row::new(vec![
+    Cell::new("이름").style_spec("bFc"),
+    Cell::new("조건").style_spec("bFc"),
+    Cell::new("수정일").style_spec("bFc"),
+  ]));
+
+  for view in &views {
+    let updated = match &view.updated_at {
+      Some(updated_at) => utils::format_date(updated_at),
+      None => "기본".to_string(),
+    };
+
+    table.add_row(Row::new(vec![
+      Cell::new(&view.name),
+      Cell::new(&view.definition.to_args().join(" ")),
+      Cell::new(&updated),
+    ]));
+  }
+
+  table.printstd();
+  println!("\n총 {}개의 보기", views.len().to_string().cyan());
+
+  Ok(())
+}
+
+fn handle_view_delete(service: &impl TodoService, name: String) -> Result<()> {
+  service.delete_view(&name)?;
+  println!("{} '{}' 보기를 삭제했습니다.", "🗑️".red(), name.cyan());
+  Ok(())
+}
+
+fn handle_search(service: &impl TodoService, query: String, limit: usize, format: OutputFormat) -> Result<()> {
+  let query = SearchQuery::parse(&query)?;
+  let todos = service.search_todos(&query, limit)?;
+
+  if !format.is_table() {
+    return output::print_todos(format, &todos);
+  }
+
+  if todos.is_empty() {
+    println!("{}", "검색 결과가 없습니다.".yellow());
+    return Ok(());
+  }
+
+  let paint = |s: &str| s.black().on_yellow().to_string();
+
+  let mut table = Table::new();
+  table.add_row(Row::new(vec![
+    Cell::new("ID").style_spec("bFc"),
+    Cell::new("상태").style_spec("bFc"),
+    Cell::new("제목").style_spec("bFc"),
+    Cell::new("설명").style_spec("bFc"),
+  ]));
+
+  for todo in &todos {
+    let status = if todo.archived_at.is_some() {
+      "📦 보관됨".to_string()
+    } else {
+      format!("{} {}", todo.status.to_emoji(), todo.status.to_display_string())
+    };
+    let description = todo.description
+      .as_deref()
+      .map(|d| query.highlight(&match_snippet(&query, d, 40), paint))
+      .unwrap_or_default();
+
+    table.add_row(Row::new(vec![
+      Cell::new(&todo.id.unwrap_or(0).to_string()),
+      Cell::new(&status),
+      Cell::new(&query.highlight(&utils::truncate_title_for_terminal(&todo.title), paint)),
+      Cell::new(&description),
+    ]));
+  }
+
+  table.printstd();
+  println!("\n{}개의 할일을 찾았습니다 (관련도 순)", todos.len().to_string().cyan());
+
+  Ok(())
+}
+
+/// 첫 번째 일치 위치 주변을 최대 `width`자로 잘라낸 설명
+fn match_snippet(query: &SearchQuery, text: &str, width: usize) -> String {
+  let text = text.lines().collect::<Vec<_>>().join(" ");
+  let chars: Vec<char> = text.chars().collect();
+  if chars.len() <= width {
+    return text;
+  }
+
+  let first_match = query.terms
+    .iter()
+    .filter(|term| !term.excluded)
+    .filter_map(|term| term.find_in(&text).first().map(|range| text[..range.start].chars().count()))
+    .min()
+    .unwrap_or(0);
+
+  let start = first_match.saturating_sub(width / 4).min(chars.len() - width);
+  let end = start + width;
+  format!("{}{}{}",
+    if start > 0 { "…" } else { "" },
+    chars[start..end].iter().collect::<String>(),
+    if end < chars.len() { "…" } else { "" }
+  )
+}
+
+fn handle_show(service: &impl TodoService, id: i64, format: OutputFormat) -> Result<()> {
   let todo = service.get_todo_by_id(id)?;
 
+  if !format.is_table() {
+    return output::print_todo(format, &todo);
+  }
+
   println!("\n{}", "📋 할일 상세 정보".bold().blue());
   println!("{}", "─".repeat(50));
 
   println!("ID: {}", todo.id.unwrap_or(0).to_string().cyan());
+  println!("UUID: {}", todo.uuid.dimmed());
   println!("제목: {}", todo.title.bold());
 
   if let Some(desc) = &todo.description {
@@ -155,10 +530,30 @@ fn handle_show(service: &impl TodoService, id: i64) -> Result<()> {
   println!("상태: {} {}", todo.status.to_emoji(), todo.status.to_display_string());
   println!("우선순위: {} {}", todo.priority.to_emoji(), todo.priority.to_display_string());
 
+  if todo.tags.is_empty() {
+    println!("태그: {}", "없음".dimmed());
+  } else {
+    println!("태그: {}", format_tags(&todo.tags).cyan());
+  }
+
+  match &todo.project {
+    Some(project) => println!("프로젝트: {}", project.magenta()),
+    None => println!("프로젝트: {}", "없음".dimmed()),
+  }
+
+  if let Some(series_id) = todo.series_id {
+    match &todo.recurrence {
+      Some(rule) => println!("반복: {} (시리즈 #{})", rule.blue(), series_id),
+      None => println!("반복: {} (시리즈 #{})", "종료됨".dimmed(), series_id),
+    }
+  }
+
   if let Some(due) = todo.due_date {
-    println!("마감일: {}", utils::format_date(&due).yellow());
-    if let Some(days) = todo.days_until_due() {
-      if days == 0 {
+    println!("마감일: {}", utils::format_due(&due, todo.due_all_day).yellow());
+    if let Some(days) = todo.days_until_due(service.clock()) {
+      if todo.is_overdue(service.clock()) && days == 0 {
+        println!("⚠️  {}", "마감 시각이 지났습니다!".red().bold());
+      } else if days == 0 {
         println!("⚠️  {}", "오늘이 마감일입니다!".red().bold());
       } else if days < 0 {
         println!("⚠️  {}일 지났습니다", (-days).to_string().red().bold());
@@ -172,13 +567,239 @@ fn handle_show(service: &impl TodoService, id: i64) -> Result<()> {
 
   println!("생성일: {}", utils::format_date(&todo.created_at));
   println!("수정일: {}", utils::format_date(&todo.updated_at));
+  if let Some(archived_at) = todo.archived_at {
+    println!("보관일: {} {}", utils::format_date(&archived_at), "(tasky unarchive로 보관 해제)".dimmed());
+  }
+
+  if let Some(parent_id) = todo.parent_id {
+    let parent = service.get_todo_by_id(parent_id)?;
+    println!("상위 할일: {} {}", parent_id.to_string().cyan(), parent.title);
+  }
+
+  let blockers = service.get_blockers(id)?;
+  if !blockers.is_empty() {
+    println!("선행 할일:");
+    print_todo_refs(&blockers);
+  }
+
+  let dependents = service.get_dependents(id)?;
+  if !dependents.is_empty() {
+    println!("후행 할일:");
+    print_todo_refs(&dependents);
+  }
+
+  let subtasks = service.get_subtasks(id)?;
+  if !subtasks.is_empty() {
+    let done_count = subtasks.iter().filter(|t| t.status == Status::Done).count();
+    println!("하위 할일: {}/{} 완료", done_count.to_string().green(), subtasks.len());
+    print_todo_refs(&subtasks);
+  }
+
+  let history = service.get_history(Some(id), 5)?;
+  if !history.is_empty() {
+    println!("최근 변경:");
+    for event in &history {
+      println!("  {} {} {}",
+        utils::format_due(&event.created_at, false).dimmed(),
+        event.kind.to_emoji(),
+        describe_event(event)
+      );
+    }
+  }
 
   println!("{}", "─".repeat(50));
 
   Ok(())
 }
 
-fn handle_done (service: &impl TodoService, ids: Vec<i64>) -> Result<()> {
+fn handle_undo(service: &impl TodoService, count: usize, dry_run: bool, format: OutputFormat) -> Result<()> {
+  let operations = service.undoable_operations(count)?;
+
+  if !format.is_table() {
+    if !dry_run {
+      service.undo(count)?;
+    }
+    return output::print_todos(format, &resulting_todos(&operations, true));
+  }
+
+  if operations.is_empty() {
+    println!("{} 되돌릴 작업이 없습니다.", "📭".yellow());
+    return Ok(());
+  }
+
+  println!("{} 되돌릴 작업 {}개:", "↩️".blue(), operations.len());
+  for operation in &operations {
+    print_operation_preview(operation, true);
+  }
+
+  if dry_run {
+    println!("{}", "(--dry-run: 아무것도 되돌리지 않았습니다)".dimmed());
+    return Ok(());
+  }
+
+  let undone = service.undo(count)?;
+  println!("{} 작업 {}개를 되돌렸습니다. 다시 실행하려면 {}", "✅".green(), undone.len(), "tasky redo".cyan());
+
+  Ok(())
+}
+
+fn handle_redo(service: &impl TodoService, count: usize, dry_run: bool, format: OutputFormat) -> Result<()> {
+  let operations = service.redoable_operations(count)?;
+
+  if !format.is_table() {
+    if !dry_run {
+      service.redo(count)?;
+    }
+    return output::print_todos(format, &resulting_todos(&operations, false));
+  }
+
+  if operations.is_empty() {
+    println!("{} 다시 실행할 작업이 없습니다.", "📭".yellow());
+    return Ok(());
+  }
+
+  println!("{} 다시 실행할 작업 {}개:", "↪️".blue(), operations.len());
+  for operation in &operations {
+    print_operation_preview(operation, false);
+  }
+
+  if dry_run {
+    println!("{}", "(--dry-run: 아무것도 실행하지 않았습니다)".dimmed());
+    return Ok(());
+  }
+
+  let redone = service.redo(count)?;
+  println!("{} 작업 {}개를 다시 실행했습니다.", "✅".green(), redone.len());
+
+  Ok(())
+}
+
+/// 작업들을 되돌리거나(undo) 다시 실행(redo)한 뒤 각 할일의 상태 (영구 삭제되는 할일은 제외)
+fn resulting_todos(operations: &[Operation], undo: bool) -> Vec<Todo> {
+  let mut order = Vec::new();
+  let mut states: std::collections::HashMap<i64, Option<Todo>> = std::collections::HashMap::new();
+
+  // 적용되는 순서대로 덮어써서 마지막 상태만 남김
+  for operation in operations {
+    let changes: Vec<&OperationChange> = if undo {
+      operation.changes.iter().rev().collect()
+    } else {
+      operation.changes.iter().collect()
+    };
+
+    for change in changes {
+      let state = if undo { &change.before } else { &change.after };
+      if !states.contains_key(&change.todo_id) {
+        order.push(change.todo_id);
+      }
+      states.insert(change.todo_id, state.as_ref().map(|snapshot| snapshot.todo.clone()));
+    }
+  }
+
+  order.into_iter().filter_map(|id| states.remove(&id).flatten()).collect()
+}
+
+/// 작업과, 되돌리거나(undo) 다시 실행(redo)하면 각 할일이 어떻게 바뀌는지 표시
+fn print_operation_preview(operation: &Operation, undo: bool) {
+  println!("  {} {}",
+    utils::format_due(&operation.created_at, false).dimmed(),
+    format!("tasky {}", operation.label).bold()
+  );
+
+  let changes: Vec<&OperationChange> = if undo {
+    operation.changes.iter().rev().collect()
+  } else {
+    operation.changes.iter().collect()
+  };
+
+  for change in changes {
+    let (from, to) = if undo {
+      (change.after.as_ref(), change.before.as_ref())
+    } else {
+      (change.before.as_ref(), change.after.as_ref())
+    };
+    println!("    {} {}", format!("#{}", change.todo_id).cyan(), describe_transition(from, to));
+  }
+}
+
+fn describe_transition(from: Option<&TodoSnapshot>, to: Option<&TodoSnapshot>) -> String {
+  match (from, to) {
+    (Some(from), None) => format!("'{}' 영구 삭제", from.todo.title),
+    (None, Some(to)) => format!("'{}' 복원", to.todo.title),
+    (Some(from), Some(to)) => {
+      let mut parts: Vec<String> = field_changes(&from.todo, &to.todo)
+        .into_iter()
+        .map(|(field, old, new)| format!("{} {} → {}",
+          field_display_name(field),
+          old.unwrap_or_else(|| "없음".to_string()),
+          new.unwrap_or_else(|| "없음".to_string())
+        ))
+        .collect();
+
+      if from.todo.status != to.todo.status {
+        parts.push(format!("상태 {} → {}", from.todo.status, to.todo.status));
+      }
+      match (from.todo.archived_at, to.todo.archived_at) {
+        (None, Some(_)) => parts.push("보관".to_string()),
+        (Some(_), None) => parts.push("보관 해제".to_string()),
+        _ => {}
+      }
+      match (from.todo.deleted_at, to.todo.deleted_at) {
+        (None, Some(_)) => parts.push("휴지통으로 이동".to_string()),
+        (Some(_), None) => parts.push("휴지통에서 복원".to_string()),
+        _ => {}
+      }
+      if from.depends_on != to.depends_on {
+        parts.push(format!("선행 할일 {:?} → {:?}", from.depends_on, to.depends_on));
+      }
+
+      if parts.is_empty() {
+        format!("'{}' 변경 없음", to.todo.title)
+      } else {
+        format!("'{}' {}", to.todo.title, parts.join(", "))
+      }
+    }
+    (None, None) => String::new(),
+  }
+}
+
+fn handle_log(service: &impl TodoService, id: Option<i64>, limit: usize, format: OutputFormat) -> Result<()> {
+  let events = service.get_history(id, limit)?;
+
+  if !format.is_table() {
+    return output::print_records(format, &events);
+  }
+
+  if events.is_empty() {
+    match id {
+      Some(id) => println!("{} {}번 할일의 변경 이력이 없습니다.", "📭".yellow(), id),
+      None => println!("{} 변경 이력이 없습니다.", "📭".yellow()),
+    }
+    return Ok(());
+  }
+
+  match id {
+    Some(id) => println!("{}", format!("📜 {}번 할일 변경 이력", id).bold().blue()),
+    None => println!("{}", "📜 변경 이력".bold().blue()),
+  }
+  println!("{}", "─".repeat(50));
+
+  // 오래된 것부터 시간순으로
+  for event in events.iter().rev() {
+    let actor = event.actor.as_deref().map(|a| format!(" ({})", a)).unwrap_or_default();
+    println!("{}  {:>4}  {} {}{}",
+      utils::format_due(&event.created_at, false).dimmed(),
+      format!("#{}", event.todo_id).cyan(),
+      event.kind.to_emoji(),
+      describe_event(event),
+      actor.dimmed()
+    );
+  }
+
+  Ok(())
+}
+
+fn handle_done (service: &impl TodoService, ids: Vec<i64>, cascade: bool, format: OutputFormat) -> Result<()> {
   if ids.is_empty() {
     println!("{} 완료할 할일 ID를 입력해주세요.", "⚠️".yellow());
     return Ok(());
@@ -187,12 +808,39 @@ fn handle_done (service: &impl TodoService, ids: Vec<i64>) -> Result<()> {
   let mut completed_count = 0;
   let mut errors = Vec::new();
 
+  if !format.is_table() {
+    let mut completed = Vec::new();
+    for id in ids {
+      let result = if cascade { service.complete_todo_cascade(id) } else { service.complete_todo(id) };
+      match result {
+        Ok(todo) => completed.push(todo),
+        Err(e) => eprintln!("ID {}: {}", id, e),
+      }
+    }
+    return output::print_todos(format, &completed);
+  }
+
   for id in ids {
-    match service.complete_todo(id) {
+    let result = if cascade {
+      service.complete_todo_cascade(id)
+    } else {
+      service.complete_todo(id)
+    };
+
+    match result {
       Ok(todo) => {
         println!("{} 할일을 완료했습니다!", "✅".green());
         println!("  ID: {}, 제목: {}", id.to_string().cyan(), todo.title.strikethrough());
         completed_count += 1;
+
+        if let Some(series_id) = todo.series_id {
+          if let Some(next) = service.get_next_occurrence(series_id)? {
+            let due = next.due_date
+              .map(|d| utils::format_due(&d, next.due_all_day))
+              .unwrap_or_else(|| "-".to_string());
+            println!("  {} 다음 회차: ID {}, 마감일 {}", "🔁".blue(), next.id.unwrap_or(0).to_string().cyan(), due.yellow());
+          }
+        }
       }
       Err(e) => {
         errors.push((id, e));
@@ -214,24 +862,260 @@ fn handle_done (service: &impl TodoService, ids: Vec<i64>) -> Result<()> {
   Ok(())
 }
 
-fn handle_undone(service: &impl TodoService, id: i64) -> Result<()> {
+fn handle_undone(service: &impl TodoService, id: i64, format: OutputFormat) -> Result<()> {
   let todo = service.uncomplete_todo(id)?;
+  if !format.is_table() {
+    return output::print_todos(format, &[todo]);
+  }
   println!("{} 할일을 다시 대기 상태로 변경했습니다.", "⏳".yellow());
   println!("  제목: {}", todo.title.bold());
   Ok(())
 }
 
-fn handle_remove(service: &impl TodoService, id: i64) -> Result<()> {
-
-  let todo = service.get_todo_by_id(id)?;
-  let title = todo.title.clone();
+fn handle_archive(service: &impl TodoService, ids: Vec<i64>, done_before: Option<String>, format: OutputFormat) -> Result<()> {
+  if let Some(date) = done_before {
+    let cutoff = utils::parse_date(&date, service.clock())?;
+    let archived = service.archive_done_before(cutoff)?;
+
+    if !format.is_table() {
+      return output::print_todos(format, &archived);
+    }
+
+    if archived.is_empty() {
+      println!("{} {} 전에 완료된 할일이 없습니다.", "📭".yellow(), utils::format_date(&cutoff));
+    } else {
+      println!("{} {} 전에 완료된 할일 {}개를 보관했습니다.",
+        "📦".blue(), utils::format_date(&cutoff), archived.len().to_string().green());
+    }
+    return Ok(());
+  }
+
+  if ids.is_empty() {
+    println!("{} 보관할 할일 ID나 {} 옵션을 입력해주세요.", "⚠️".yellow(), "--done-before".cyan());
+    return Ok(());
+  }
+
+  if !format.is_table() {
+    let mut archived = Vec::new();
+    for id in ids {
+      match service.archive_todo(id) {
+        Ok(todo) => archived.push(todo),
+        Err(e) => eprintln!("ID {}: {}", id, e),
+      }
+    }
+    return output::print_todos(format, &archived);
+  }
+
+  let mut errors = Vec::new();
+  for id in ids {
+    match service.archive_todo(id) {
+      Ok(todo) => println!("{} 보관했습니다: {} {}", "📦".blue(), id.to_string().cyan(), todo.title.dimmed()),
+      Err(e) => errors.push((id, e)),
+    }
+  }
+
+  if !errors.is_empty() {
+    println!("\n{} 보관 실패한 할일:", "❌".red());
+    for (id, error) in errors {
+      println!("  ID {}: {}", id.to_string().cyan(), error);
+    }
+  }
+
+  Ok(())
+}
+
+fn handle_unarchive(service: &impl TodoService, ids: Vec<i64>, format: OutputFormat) -> Result<()> {
+  if ids.is_empty() {
+    println!("{} 보관 해제할 할일 ID를 입력해주세요.", "⚠️".yellow());
+    return Ok(());
+  }
+
+  if !format.is_table() {
+    let todos = ids.into_iter().map(|id| service.unarchive_todo(id)).collect::<Result<Vec<_>>>()?;
+    return output::print_todos(format, &todos);
+  }
+
+  for id in ids {
+    let todo = service.unarchive_todo(id)?;
+    println!("{} 보관을 해제했습니다: {} {}", "📤".blue(), id.to_string().cyan(), todo.title);
+  }
+
+  Ok(())
+}
+
+fn handle_remove(service: &impl TodoService, id: i64, format: OutputFormat) -> Result<()> {
+  if !format.is_table() {
+    // 하위 할일도 함께 옮겨지므로 휴지통 전후를 비교
+    let before = trash_ids(service)?;
+    service.delete_todo(id)?;
+    let removed: Vec<Todo> = service.list_trash()?
+      .into_iter()
+      .filter(|todo| todo.id.is_some_and(|id| !before.contains(&id)))
+      .collect();
+    return output::print_todos(format, &removed);
+  }
+
+  let todo = service.get_todo_by_id(id)?;
+  let title = todo.title.clone();
+  let subtask_count = service.get_subtasks(id)?.len();
+
+  service.delete_todo(id)?;
+  println!("{} 할일을 휴지통으로 옮겼습니다!", "🗑️".red());
+  println!("  제목: {}", title.dimmed());
+  if subtask_count > 0 {
+    println!("  하위 할일 {}개도 함께 옮겨졌습니다.", subtask_count.to_string().yellow());
+  }
+  println!("  복원하려면 {}", format!("tasky restore {}", id).cyan());
+  Ok(())
+}
+
+fn handle_trash(service: &impl TodoService, format: OutputFormat) -> Result<()> {
+  let todos = service.list_trash()?;
+
+  if !format.is_table() {
+    return output::print_todos(format, &todos);
+  }
+
+  if todos.is_empty() {
+    println!("{} 휴지통이 비어있습니다.", "📭".yellow());
+    return Ok(());
+  }
+
+  let mut table = Table::new();
+  table.add_row(Row::new(vec![
+    Cell::new("ID").style_spec("bFc"),
+    Cell::new("상태").style_spec("bFc"),
+    Cell::new("제목").style_spec("bFc"),
+    Cell::new("상위").style_spec("bFc"),
+    Cell::new("삭제됨").style_spec("bFc"),
+  ]));
+
+  for todo in &todos {
+    let parent = todo.parent_id.map(|p| p.to_string()).unwrap_or_else(|| "-".to_string());
+    let deleted = todo.deleted_at
+      .map(|d| utils::format_relative_time(&d, service.clock()))
+      .unwrap_or_else(|| "-".to_string());
+
+    table.add_row(Row::new(vec![
+      Cell::new(&todo.id.unwrap_or(0).to_string()),
+      Cell::new(todo.status.to_emoji()),
+      Cell::new(&utils::truncate_title_for_terminal(&todo.title)),
+      Cell::new(&parent),
+      Cell::new(&deleted),
+    ]));
+  }
+
+  table.printstd();
+  println!("\n휴지통에 {}개의 할일이 있습니다. 복원: {}, 영구 삭제: {}",
+    todos.len().to_string().cyan(),
+    "tasky restore <ID>".cyan(),
+    "tasky purge <ID>".cyan()
+  );
+
+  Ok(())
+}
+
+fn handle_restore(service: &impl TodoService, id: i64, format: OutputFormat) -> Result<()> {
+  if !format.is_table() {
+    let before = trash_ids(service)?;
+    service.restore_todo(id)?;
+    let after = trash_ids(service)?;
+    let mut restored: Vec<i64> = before.difference(&after).copied().collect();
+    restored.sort();
+    let todos = restored.into_iter().map(|id| service.get_todo_by_id(id)).collect::<Result<Vec<_>>>()?;
+    return output::print_todos(format, &todos);
+  }
+
+  let todo = service.restore_todo(id)?;
+  let subtask_count = service.get_subtasks(id)?.len();
+
+  println!("{} 할일을 휴지통에서 복원했습니다!", "♻️".green());
+  println!("  ID: {}, 제목: {}", id.to_string().cyan(), todo.title.bold());
+  if subtask_count > 0 {
+    println!("  하위 할일 {}개도 함께 복원되었습니다.", subtask_count.to_string().yellow());
+  }
+  Ok(())
+}
+
+fn handle_purge(service: &impl TodoService, ids: Vec<i64>, older_than: Option<String>, all: bool, format: OutputFormat) -> Result<()> {
+  // 영구 삭제된 할일은 다시 조회할 수 없으므로 미리 담아둠
+  let trash = if format.is_table() { Vec::new() } else { service.list_trash()? };
+
+  let purged = if !ids.is_empty() {
+    let mut purged = 0;
+    for id in ids {
+      purged += service.purge_todo(id)?;
+    }
+    purged
+  } else if let Some(older_than) = older_than {
+    let cutoff = service.clock().now().checked_sub_signed(utils::parse_duration(&older_than)?).ok_or_else(|| {
+      TaskyError::InvalidInput { message: format!("기간이 너무 깁니다: {}", older_than) }
+    })?;
+    service.purge_trash(Some(cutoff))?
+  } else if all {
+    service.purge_trash(None)?
+  } else {
+    println!("{} 영구 삭제할 할일 ID나 {}, {} 옵션을 입력해주세요.", "⚠️".yellow(), "--older-than".cyan(), "--all".cyan());
+    return Ok(());
+  };
+
+  if !format.is_table() {
+    let remaining = trash_ids(service)?;
+    let purged: Vec<Todo> = trash
+      .into_iter()
+      .filter(|todo| todo.id.is_some_and(|id| !remaining.contains(&id)))
+      .collect();
+    return output::print_todos(format, &purged);
+  }
+
+  if purged == 0 {
+    println!("{} 영구 삭제할 할일이 없습니다.", "📭".yellow());
+  } else {
+    println!("{} 할일 {}개를 영구 삭제했습니다. 되돌리려면 {}", "🔥".red(), purged.to_string().yellow(), "tasky undo".cyan());
+  }
+  Ok(())
+}
+
+fn handle_depend(service: &impl TodoService, id: i64, on: Vec<i64>, format: OutputFormat) -> Result<()> {
+  if !format.is_table() {
+    for depends_on_id in on {
+      service.add_dependency(id, depends_on_id)?;
+    }
+    return output::print_todos(format, &[service.get_todo_by_id(id)?]);
+  }
+
+  for depends_on_id in on {
+    if service.add_dependency(id, depends_on_id)? {
+      println!("{} {}번 할일은 이제 {}번 할일이 완료되어야 시작할 수 있습니다.",
+        "🔗".blue(), id.to_string().cyan(), depends_on_id.to_string().cyan());
+    } else {
+      println!("{} 이미 {}번 할일에 의존하고 있습니다.", "⚠️".yellow(), depends_on_id.to_string().cyan());
+    }
+  }
+  Ok(())
+}
+
+fn handle_undepend(service: &impl TodoService, id: i64, on: Vec<i64>, format: OutputFormat) -> Result<()> {
+  if !format.is_table() {
+    for depends_on_id in on {
+      service.remove_dependency(id, depends_on_id)?;
+    }
+    return output::print_todos(format, &[service.get_todo_by_id(id)?]);
+  }
 
-  service.delete_todo(id)?;
-  println!("{} 할일을 삭제했습니다!", "🗑️".red());
-  println!("  제목: {}", title.dimmed());
+  for depends_on_id in on {
+    if service.remove_dependency(id, depends_on_id)? {
+      println!("{} {}번 할일의 선행 할일에서 {}번을 제거했습니다.",
+        "✂️".blue(), id.to_string().cyan(), depends_on_id.to_string().cyan());
+    } else {
+      println!("{} {}번 할일은 {}번 할일에 의존하지 않습니다.",
+        "⚠️".yellow(), id.to_string().cyan(), depends_on_id.to_string().cyan());
+    }
+  }
   Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn handle_edit(
   service: &impl TodoService,
   id: i64,
@@ -239,10 +1123,27 @@ fn handle_edit(
   description: Option<String>,
   priority: Option<String>,
   due: Option<String>,
+  tags: Vec<String>,
+  clear_tags: bool,
+  project: Option<String>,
+  clear_project: bool,
+  format: OutputFormat,
 ) -> Result<()> {
 
   let mut update_todo = UpdateTodo::default();
 
+  if clear_project {
+    update_todo.project = Some(None);
+  } else if let Some(project) = project {
+    update_todo.project = Some(Some(project));
+  }
+
+  if clear_tags {
+    update_todo.tags = Some(Vec::new());
+  } else if !tags.is_empty() {
+    update_todo.tags = Some(tags);
+  }
+
   if let Some(title) = title {
     update_todo.title = Some(title);
   }
@@ -253,10 +1154,15 @@ fn handle_edit(
     update_todo.priority = Some(Priority::from_str(&priority_str)?);
   }
   if let Some(due_str) = due {
-    update_todo.due_date = Some(utils::parse_date(&due_str)?);
+    let due = utils::parse_due(&due_str, service.clock())?;
+    update_todo.due_date = Some(due.at);
+    update_todo.due_all_day = Some(due.all_day);
   }
 
   let todo = service.update_todo(id, update_todo)?;
+  if !format.is_table() {
+    return output::print_todos(format, &[todo]);
+  }
 
   println!("{} 할일을 수정했습니다!", "✏️".blue());
   println!("  ID: {}", todo.id.unwrap_or(0).to_string().cyan());
@@ -267,32 +1173,53 @@ fn handle_edit(
   println!("  우선순위: {} {}", todo.priority.to_emoji(), todo.priority.to_display_string());
   println!("  상태: {} {}", todo.status.to_emoji(), todo.status.to_display_string());
   if let Some(due) = todo.due_date {
-    println!("  마감일: {}", utils::format_date(&due).yellow());
+    println!("  마감일: {}", utils::format_due(&due, todo.due_all_day).yellow());
+  }
+  if !todo.tags.is_empty() {
+    println!("  태그: {}", format_tags(&todo.tags).cyan());
+  }
+  if let Some(project) = &todo.project {
+    println!("  프로젝트: {}", project.magenta());
   }
 
   Ok(())
 }
 
-fn handle_stats(service: &impl TodoService) -> Result<()> {
-  let stats = service.get_stats()?;
+fn handle_stats(service: &impl TodoService, project: Option<String>, archived: bool, format: OutputFormat) -> Result<()> {
+  let stats = service.get_stats(project.as_deref(), archived)?;
+
+  if !format.is_table() {
+    return output::print_record(format, &stats);
+  }
 
-  println!("\n{}", "📊 할일 통계".bold().blue());
+  match &project {
+    Some(project) => println!("\n{} {}", "📊 할일 통계".bold().blue(), format!("({})", project).magenta()),
+    None => println!("\n{}", "📊 할일 통계".bold().blue()),
+  }
   println!("{}", "─".repeat(40));
   
   println!("전체 할일: {}", stats.total_todos.to_string().cyan());
-  println!("대기중: {} {}", 
+  println!("대기중: {} ({}%)",
     stats.pending_todos.to_string().yellow(),
-    format!("({}%)", ((stats.pending_todos as f64 / stats.total_todos.max(1) as f64) * 100.0) as i32)
+    ((stats.pending_todos as f64 / stats.total_todos.max(1) as f64) * 100.0) as i32
   );
-  println!("완료: {} {}", 
+  println!("완료: {} ({}%)",
     stats.completed_todos.to_string().green(),
-    format!("({}%)", stats.completion_rate as i32)
+    stats.completion_rate as i32
   );
   println!("높은 우선순위: {}", stats.high_priority_todos.to_string().red());
   
   if stats.overdue_todos > 0 {
     println!("⚠️  기한 초과: {}", stats.overdue_todos.to_string().red().bold());
   }
+
+  if stats.archived_todos > 0 {
+    if archived {
+      println!("📦 보관됨: {} (포함)", stats.archived_todos.to_string().dimmed());
+    } else {
+      println!("📦 보관됨: {} {}", stats.archived_todos.to_string().dimmed(), "(--archived로 포함)".dimmed());
+    }
+  }
   
   println!("{}", "─".repeat(40));
   
@@ -306,6 +1233,399 @@ fn handle_stats(service: &impl TodoService) -> Result<()> {
   print!("{}", "░".repeat(empty).dimmed());
   println!("] {:.1}%", stats.completion_rate);
 
+  if !stats.tag_stats.is_empty() {
+    println!("\n{}", "🏷️  태그별 통계".bold().blue());
+    println!("{}", "─".repeat(40));
+
+    for tag_stat in &stats.tag_stats {
+      println!("#{}: 전체 {} / 대기중 {} / 완료 {}",
+        tag_stat.tag.cyan(),
+        tag_stat.total_todos,
+        tag_stat.pending_todos.to_string().yellow(),
+        tag_stat.completed_todos.to_string().green()
+      );
+    }
+  }
+
+  Ok(())
+}
+
+fn handle_projects(service: &impl TodoService, include_archived: bool, format: OutputFormat) -> Result<()> {
+  let projects = service.list_projects(include_archived)?;
+
+  if !format.is_table() {
+    return output::print_records(format, &projects);
+  }
+
+  if projects.is_empty() {
+    println!("{}", "프로젝트가 없습니다.".yellow());
+    return Ok(());
+  }
+
+  println!("\n{}", "📁 프로젝트 목록 (하위 프로젝트 포함)".bold().blue());
+  println!("{}", "─".repeat(40));
+
+  for project in &projects {
+    // 하위 프로젝트는 깊이만큼 들여쓰기
+    let depth = project.name.matches('.').count();
+    let short_name = project.name.rsplit('.').next().unwrap_or(&project.name);
+
+    println!("{}{}  대기중 {} / 완료 {}",
+      "  ".repeat(depth),
+      short_name.magenta().bold(),
+      project.pending_todos.to_string().yellow(),
+      project.completed_todos.to_string().green()
+    );
+  }
+
+  println!("{}", "─".repeat(40));
+  println!("총 {}개의 프로젝트", projects.len().to_string().cyan());
+
+  Ok(())
+}
+
+fn handle_series_list(service: &impl TodoService, format: OutputFormat) -> Result<()> {
+  let series = service.list_series()?;
+
+  if !format.is_table() {
+    return output::print_records(format, &series);
+  }
+
+  if series.is_empty() {
+    println!("{}", "반복 시리즈가 없습니다.".yellow());
+    return Ok(());
+  }
+
+  let mut table = Table::new();
+  table.add_row(Row::new(vec![
+    Cell::new("ID").style_spec("bFc"),
+    Cell::new("상태").style_spec("bFc"),
+    Cell::new("규칙").style_spec("bFc"),
+    Cell::new("제목").style_spec("bFc"),
+    Cell::new("다음 마감일").style_spec("bFc"),
+    Cell::new("완료/전체").style_spec("bFc"),
+  ]));
+
+  for item in &series {
+    let status_cell = if item.active {
+      Cell::new("🔁 진행중")
+    } else {
+      Cell::new("⏹️ 종료").style_spec("Fd")
+    };
+    let next_due = item.next_due.map(|d| utils::format_date(&d)).unwrap_or_else(|| "-".to_string());
+
+    table.add_row(Row::new(vec![
+      Cell::new(&item.id.to_string()),
+      status_cell,
+      Cell::new(&item.rule),
+      Cell::new(&utils::truncate_title_for_terminal(&item.title)),
+      Cell::new(&next_due),
+      Cell::new(&format!("{}/{}", item.completed_todos, item.total_todos)),
+    ]));
+  }
+
+  table.printstd();
+  println!("\n총 {}개의 반복 시리즈", series.len().to_string().cyan());
+
+  Ok(())
+}
+
+/// 내보내기 형식. 파일 형식은 옵션으로 정하므로 --format과 관계없음
+enum ExportFormat {
+  Json,
+  Csv(ColumnMapping),
+  TodoTxt,
+  Ics { events: bool },
+  Taskwarrior,
+  Markdown(Option<GroupBy>),
+  Org(Option<GroupBy>),
+}
+
+fn handle_export(service: &impl TodoService, options: ListOptions, filters: Vec<String>, export_format: ExportFormat) -> Result<()> {
+  let mut definition = view_definition(options)?;
+  definition.expressions.extend(filters);
+
+  // 문서용 목록은 list와 똑같이 (정렬, 보관된 할일은 --archived일 때만)
+  match export_format {
+    ExportFormat::Markdown(group_by) => return document::write_markdown(std::io::stdout(), &service.list_view(&definition)?, group_by),
+    ExportFormat::Org(group_by) => return document::write_org(std::io::stdout(), &service.list_view(&definition)?, group_by),
+    _ => {}
+  }
+
+  let file = service.export_todos(&definition)?;
+  match export_format {
+    ExportFormat::Csv(mapping) => spreadsheet::write_csv(std::io::stdout(), &file.todos, &mapping),
+    ExportFormat::TodoTxt => todotxt::write_todotxt(std::io::stdout(), &file.todos),
+    ExportFormat::Ics { events } => icalendar::write_ics(std::io::stdout(), &file.todos, events, file.exported_at),
+    ExportFormat::Taskwarrior => {
+      let unmapped = taskwarrior::write_taskwarrior(std::io::stdout(), &file)?;
+      print_unmapped_fields(&unmapped, true);
+      Ok(())
+    }
+    _ => {
+      println!("{}", file.to_json()?);
+      Ok(())
+    }
+  }
+}
+
+/// 다른 형식으로 옮기지 못한 필드 보고. 표준 출력이 파일이나 기계용 출력이면 표준 오류로
+fn print_unmapped_fields(fields: &[UnmappedField], stderr: bool) {
+  if fields.is_empty() {
+    return;
+  }
+
+  let lines: Vec<String> = fields.iter().map(|f| format!("  {} ({}개): {}", f.field, f.count, f.note)).collect();
+  if stderr {
+    eprintln!("옮기지 못한 필드:");
+    lines.iter().for_each(|line| eprintln!("{}", line));
+  } else {
+    println!("\n{} 옮기지 못한 필드:", "⚠️".yellow());
+    lines.iter().for_each(|line| println!("{}", line));
+  }
+}
+
+/// 가져올 파일 내용 (-이면 표준 입력)
+fn read_import_source(path: &str) -> Result<String> {
+  if path == "-" {
+    Ok(std::io::read_to_string(std::io::stdin())?)
+  } else {
+    Ok(std::fs::read_to_string(path)?)
+  }
+}
+
+fn handle_import(service: &impl TodoService, file: ExportFile, strategy: ConflictStrategy, dry_run: bool, format: OutputFormat) -> Result<()> {
+  let items = service.import_todos(&file, strategy, dry_run)?;
+
+  if !format.is_table() {
+    if dry_run {
+      return output::print_records(format, &items);
+    }
+    // 휴지통에 있는 채로 가져온 할일은 get_todo_by_id로 조회되지 않음
+    let trash: HashMap<i64, Todo> = service.list_trash()?.into_iter().filter_map(|todo| Some((todo.id?, todo))).collect();
+    let todos = items
+      .iter()
+      .filter(|item| matches!(item.action, ImportAction::Create | ImportAction::Overwrite))
+      .filter_map(|item| item.id)
+      .map(|id| trash.get(&id).cloned().map_or_else(|| service.get_todo_by_id(id), Ok))
+      .collect::<Result<Vec<_>>>()?;
+    return output::print_todos(format, &todos);
+  }
+
+  let count = |action: ImportAction| items.iter().filter(|item| item.action == action).count();
+
+  if dry_run {
+    println!("{} 가져올 할일 {}개 (내보낸 시각: {}):", "🔍".blue(), items.len(),
+      utils::format_due(&file.exported_at, false).dimmed());
+
+    if !items.is_empty() {
+      let mut table = Table::new();
+      table.add_row(Row::new(vec![
+        Cell::new("파일 ID").style_spec("bFc"),
+        Cell::new("ID").style_spec("bFc"),
+        Cell::new("제목").style_spec("bFc"),
+        Cell::new("동작").style_spec("bFc"),
+      ]));
+
+      for item in &items {
+        let id_or_dash = |id: Option<i64>| id.map(|id| id.to_string()).unwrap_or_else(|| "-".to_string());
+        let id = match item.action {
+          ImportAction::Create if item.id.is_none() => "새 ID".to_string(),
+          _ => id_or_dash(item.id),
+        };
+        let action_cell = match item.action {
+          ImportAction::Create => Cell::new(item.action.to_display_string()).style_spec("Fg"),
+          ImportAction::Overwrite => Cell::new(item.action.to_display_string()).style_spec("Fy"),
+          ImportAction::Skip => Cell::new(item.action.to_display_string()).style_spec("Fd"),
+          ImportAction::Conflict => Cell::new(item.action.to_display_string()).style_spec("Fr"),
+        };
+
+        table.add_row(Row::new(vec![
+          Cell::new(&id_or_dash(item.source_id)),
+          Cell::new(&id),
+          Cell::new(&utils::truncate_title_for_terminal(&item.title)),
+          action_cell,
+        ]));
+      }
+      table.printstd();
+    }
+
+    println!("추가 {} / 덮어쓰기 {} / 건너뜀 {}",
+      count(ImportAction::Create).to_string().green(),
+      count(ImportAction::Overwrite).to_string().yellow(),
+      count(ImportAction::Skip).to_string().dimmed()
+    );
+    let conflicts = count(ImportAction::Conflict);
+    if conflicts > 0 {
+      println!("{} 이미 있는 할일 {}개: {}, {}, {} 중 하나를 선택하세요.", "⚠️".yellow(), conflicts.to_string().red(),
+        "--skip-existing".cyan(), "--overwrite".cyan(), "--renumber".cyan());
+    }
+    println!("{}", "(--dry-run: 아무것도 가져오지 않았습니다)".dimmed());
+    return Ok(());
+  }
+
+  println!("{} 할일을 가져왔습니다: 추가 {} / 덮어쓰기 {} / 건너뜀 {}",
+    "📥".blue(),
+    count(ImportAction::Create).to_string().green(),
+    count(ImportAction::Overwrite).to_string().yellow(),
+    count(ImportAction::Skip).to_string().dimmed()
+  );
+  if count(ImportAction::Create) + count(ImportAction::Overwrite) > 0 {
+    println!("  되돌리려면 {}", "tasky undo".cyan());
+  }
+  Ok(())
+}
+
+fn handle_merge(service: &impl TodoService, file: &str, dry_run: bool, format: OutputFormat) -> Result<()> {
+  let report = service.merge_database(std::path::Path::new(file), dry_run)?;
+
+  if !format.is_table() {
+    return output::print_records(format, &report.items);
+  }
+
+  let value_or_dash = |value: &Option<String>| value.clone().unwrap_or_else(|| "-".to_string());
+  if report.items.is_empty() && report.dependencies == 0 {
+    println!("{} 두 데이터베이스가 이미 같습니다.", "✅".green());
+    return Ok(());
+  }
+
+  if !report.items.is_empty() {
+    let mut table = Table::new();
+    table.add_row(Row::new(vec![
+      Cell::new("ID").style_spec("bFc"),
+      Cell::new("제목").style_spec("bFc"),
+      Cell::new("동작").style_spec("bFc"),
+      Cell::new("바뀐 필드").style_spec("bFc"),
+    ]));
+
+    for item in &report.items {
+      let id = match item.id {
+        Some(id) => id.to_string(),
+        None => "새 ID".to_string(),
+      };
+      let action_cell = match item.action {
+        MergeAction::Create => Cell::new(item.action.to_display_string()).style_spec("Fg"),
+        MergeAction::Update => Cell::new(item.action.to_display_string()).style_spec("Fy"),
+        MergeAction::Keep => Cell::new(item.action.to_display_string()).style_spec("Fd"),
+      };
+      // 다른 쪽 값을 쓰는 필드만
+      let fields: Vec<&str> = item
+        .fields
+        .iter()
+        .filter(|field| field.winner == MergeSide::Other)
+        .map(|field| field_display_name(&field.field))
+        .collect();
+
+      table.add_row(Row::new(vec![
+        Cell::new(&id),
+        Cell::new(&utils::truncate_title_for_terminal(&item.title)),
+        action_cell,
+        Cell::new(&fields.join(", ")),
+      ]));
+    }
+    table.printstd();
+  }
+
+  let conflicts: Vec<_> = report
+    .items
+    .iter()
+    .flat_map(|item| item.fields.iter().filter(|field| field.conflict).map(move |field| (item, field)))
+    .collect();
+  if !conflicts.is_empty() {
+    println!("{} 양쪽에서 바뀐 필드 {}개 (나중에 바뀐 값을 씀):", "⚠️".yellow(), conflicts.len().to_string().red());
+    for (item, field) in conflicts {
+      println!("  {} {}: 이쪽 '{}' / 다른 쪽 '{}' → {}",
+        item.id.map(|id| format!("#{}", id)).unwrap_or_default().dimmed(),
+        field_display_name(&field.field),
+        value_or_dash(&field.local),
+        value_or_dash(&field.other),
+        field.winner.to_display_string().cyan()
+      );
+    }
+  }
+
+  println!("추가 {} / 수정 {} / 유지 {} / 충돌 {} / 의존성 {}",
+    report.count(MergeAction::Create).to_string().green(),
+    report.count(MergeAction::Update).to_string().yellow(),
+    report.count(MergeAction::Keep).to_string().dimmed(),
+    report.conflicts().to_string().red(),
+    report.dependencies
+  );
+  if dry_run {
+    println!("{}", "(--dry-run: 아무것도 합치지 않았습니다)".dimmed());
+  } else if report.count(MergeAction::Create) + report.count(MergeAction::Update) + report.dependencies > 0 {
+    println!("  되돌리려면 {}", "tasky undo".cyan());
+  }
+  Ok(())
+}
+
+/// CSV 행이나 iCalendar VTODO를 하나씩 새 할일로 추가. 잘못된 항목은 건너뛰고 줄 번호와 함께 보고
+fn handle_import_rows(service: &impl TodoService, rows: Vec<ImportRow>, label: &str, dry_run: bool, format: OutputFormat) -> Result<()> {
+  let mut reports = Vec::new();
+  let mut imported = Vec::new();
+  for row in rows {
+    let result = row.todo.and_then(|imported_todo| {
+      service.validate_create_todo(&imported_todo.todo)?;
+      if dry_run {
+        return Ok((imported_todo.todo.title, None));
+      }
+
+      let todo = service.create_todo(imported_todo.todo)?;
+      let todo = match imported_todo.done {
+        true => service.complete_todo(todo.id.unwrap_or_default())?,
+        false => todo,
+      };
+      let result = (todo.title.clone(), todo.id);
+      imported.push(todo);
+      Ok(result)
+    });
+
+    reports.push(match result {
+      Ok((title, id)) => ImportRowReport { line: row.line, title, id, error: None },
+      Err(e) => ImportRowReport { line: row.line, title: String::new(), id: None, error: Some(e.to_string()) },
+    });
+  }
+
+  let failed: Vec<&ImportRowReport> = reports.iter().filter(|report| report.error.is_some()).collect();
+
+  if !format.is_table() {
+    if dry_run {
+      return output::print_records(format, &reports);
+    }
+    for report in &failed {
+      eprintln!("{}행: {}", report.line, report.error.as_deref().unwrap_or_default());
+    }
+    return output::print_todos(format, &imported);
+  }
+
+  let succeeded = reports.len() - failed.len();
+  if dry_run {
+    println!("{} {}의 할일 {}개 중 {}개를 가져올 수 있습니다.", "🔍".blue(), label, reports.len(), succeeded.to_string().green());
+  } else {
+    println!("{} {}에서 할일 {}개를 가져왔습니다.", "📥".blue(), label, succeeded.to_string().green());
+  }
+
+  if !failed.is_empty() {
+    println!("\n{} 가져오지 못한 할일 {}개:", "❌".red(), failed.len());
+    for report in &failed {
+      println!("  {}행: {}", report.line.to_string().cyan(), report.error.as_deref().unwrap_or_default());
+    }
+  }
+
+  if dry_run {
+    println!("{}", "(--dry-run: 아무것도 가져오지 않았습니다)".dimmed());
+  } else if succeeded > 0 {
+    println!("  되돌리려면 {}", "tasky undo".cyan());
+  }
+  Ok(())
+}
+
+fn handle_series_end(service: &impl TodoService, id: i64) -> Result<()> {
+  if service.end_series(id)? {
+    println!("{} 반복 시리즈 #{}를 종료했습니다. 남은 회차는 그대로 유지됩니다.", "⏹️".blue(), id.to_string().cyan());
+  } else {
+    println!("{} 반복 시리즈 #{}는 이미 종료되었습니다.", "⚠️".yellow(), id.to_string().cyan());
+  }
   Ok(())
 }
 
@@ -374,6 +1694,7 @@ fn try_remove_database_safely(db_path: &std::path::Path) -> Result<()> {
     if let Ok(metadata) = fs::metadata(db_path) {
         if metadata.permissions().readonly() {
             let mut perms = metadata.permissions();
+            #[allow(clippy::permissions_set_readonly_false)]
             perms.set_readonly(false);
             fs::set_permissions(db_path, perms)?;
         }
@@ -454,8 +1775,19 @@ fn try_create_database_with_existing_file(db_path: &std::path::Path) -> Result<(
         Ok(db) => {
             // 테이블을 드롭하고 재생성
             let _ = db.conn().execute_batch("
+                DROP TABLE IF EXISTS todo_dependencies;
+                DROP TABLE IF EXISTS todo_tags;
+                DROP TABLE IF EXISTS todos_fts;
+                DROP TABLE IF EXISTS tags;
                 DROP TABLE IF EXISTS todos;
+                DROP TABLE IF EXISTS projects;
+                DROP TABLE IF EXISTS recurrences;
+                DROP TABLE IF EXISTS todo_events;
+                DROP TABLE IF EXISTS operation_changes;
+                DROP TABLE IF EXISTS operations;
+                DROP TABLE IF EXISTS views;
                 DROP TABLE IF EXISTS sqlite_sequence;
+                PRAGMA user_version = 0;
             ");
 
             db.initialize()?;
@@ -485,8 +1817,69 @@ fn create_database_at_path(db_path: &std::path::Path) -> Result<()> {
     Ok(())
 }
 
-fn handle_db_info() -> Result<()> {
-    use crate::database::Database;
+/// `db-info --format json` 등의 출력 레코드
+#[derive(serde::Serialize)]
+struct DbInfo {
+    path: String,
+    custom_path: Option<String>,
+    exists: bool,
+    size_bytes: Option<u64>,
+    modified: Option<chrono::DateTime<chrono::Utc>>,
+    connected: bool,
+    initialized: bool,
+    schema_version: Option<u32>,
+    latest_version: u32,
+    todo_count: Option<i64>,
+    error: Option<String>,
+}
+
+fn collect_db_info() -> DbInfo {
+    use crate::database::{migrations, Database};
+
+    let db_path = Database::default_path();
+    let metadata = std::fs::metadata(&db_path).ok();
+
+    let mut info = DbInfo {
+        path: db_path.display().to_string(),
+        custom_path: std::env::var("TASKY_DB_PATH").ok(),
+        exists: db_path.exists(),
+        size_bytes: metadata.as_ref().map(|m| m.len()),
+        modified: metadata.and_then(|m| m.modified().ok()).map(chrono::DateTime::from),
+        connected: false,
+        initialized: false,
+        schema_version: None,
+        latest_version: migrations::latest_version(),
+        todo_count: None,
+        error: None,
+    };
+
+    if !info.exists {
+        return info;
+    }
+
+    match Database::new(&db_path) {
+        Ok(db) => {
+            info.connected = true;
+            info.initialized = db.is_initialized();
+            if info.initialized {
+                info.schema_version = db.schema_version().ok();
+                info.todo_count = db.conn()
+                    .query_row("SELECT COUNT(*) FROM todos", [], |row| row.get(0))
+                    .ok();
+            }
+        }
+        Err(e) => info.error = Some(e.to_string()),
+    }
+
+    info
+}
+
+fn handle_db_info(format: OutputFormat) -> Result<()> {
+    use crate::database::{migrations, Database};
+
+    if !format.is_table() {
+        return output::print_record(format, &collect_db_info());
+    }
 
     let db_path = Database::default_path();
 
@@ -534,6 +1927,17 @@ fn handle_db_info() -> Result<()> {
             if db.is_initialized() {
                 println!("초기화: {} 완료", "✅".green());
 
+                if let Ok(version) = db.schema_version() {
+                    let latest = migrations::latest_version();
+                    if version < latest {
+                        println!("스키마 버전: {} / {} ({})", version, latest, "tasky db migrate 필요".yellow());
+                    } else if version > latest {
+                        println!("스키마 버전: {} / {} ({})", version, latest, "프로그램보다 새 버전".red());
+                    } else {
+                        println!("스키마 버전: {} / {}", version.to_string().green(), latest);
+                    }
+                }
+
                 // 테이블 정보 확인
                 match db.conn().prepare("SELECT COUNT(*) FROM todos") {
                     Ok(mut stmt) => {
@@ -584,27 +1988,165 @@ fn handle_db_info() -> Result<()> {
     Ok(())
 }
 
-fn print_todos_table(todos: &[Todo]) {
+fn handle_db_migrate(status: bool, dry_run: bool) -> Result<()> {
+    use crate::database::{migrations, Database};
+
+    let db_path = Database::default_path();
+
+    if (status || dry_run) && !db_path.exists() {
+        println!("{} 데이터베이스 파일이 존재하지 않습니다: {}", "❌".red(), db_path.display());
+        println!("{} 다음 명령어로 데이터베이스를 생성하세요:", "💡".yellow());
+        println!("  tasky init");
+        return Ok(());
+    }
+
+    let db = Database::default()?;
+    let current = db.schema_version()?;
+    let latest = migrations::latest_version();
+
+    if status {
+        println!("{}", "🗄️ 스키마 마이그레이션 상태".bold().blue());
+        println!("{}", "─".repeat(50));
+        println!("현재 버전: {} / 최신 버전: {}", current.to_string().cyan(), latest);
+        println!();
+
+        for migration in migrations::all_migrations() {
+            if migration.version <= current {
+                println!("  {} {:>3}  {}", "✅".green(), migration.version, migration.description);
+            } else {
+                println!("  {} {:>3}  {}", "⏳".yellow(), migration.version, migration.description.yellow());
+            }
+        }
+
+        if current > latest {
+            println!("\n{} 데이터베이스가 이 프로그램보다 새 버전입니다. tasky를 업데이트하세요.", "⚠️".red());
+        }
+        return Ok(());
+    }
+
+    let pending = migrations::pending_migrations(db.conn())?;
+    if pending.is_empty() {
+        println!("{} 스키마가 최신 상태입니다 (버전 {})", "✅".green(), current);
+        return Ok(());
+    }
+
+    if dry_run {
+        println!("{} 적용될 마이그레이션 {}개 (버전 {} → {}):", "🔍".blue(), pending.len(), current, latest);
+        for migration in &pending {
+            println!("  {:>3}  {}", migration.version, migration.description);
+        }
+        println!("{}", "(--dry-run: 데이터베이스를 변경하지 않았습니다)".dimmed());
+        return Ok(());
+    }
+
+    let applied = migrations::run_migrations(db.conn())?;
+    println!("{} 마이그레이션 {}개를 적용했습니다 (버전 {} → {})", "✅".green(), applied.len(), current, latest);
+    for migration in &applied {
+        println!("  {:>3}  {}", migration.version, migration.description);
+    }
+
+    Ok(())
+}
+
+/// 이력 한 건을 한 줄 설명으로
+fn describe_event(event: &TodoEvent) -> String {
+  let value = |v: &Option<String>| v.clone().unwrap_or_else(|| "없음".to_string());
+
+  match event.kind {
+    EventKind::Created => format!("생성: {}", value(&event.new_value)),
+    EventKind::Deleted => format!("삭제: {}", value(&event.old_value)),
+    EventKind::Restored => format!("복원: {}", value(&event.new_value)),
+    EventKind::Purged => format!("영구 삭제: {}", value(&event.old_value)),
+    EventKind::Updated => format!("{}: {} → {}",
+      event.field_display_name(),
+      value(&event.old_value),
+      value(&event.new_value)
+    ),
+    EventKind::Completed | EventKind::Uncompleted | EventKind::Archived | EventKind::Unarchived => {
+      event.kind.to_display_string().to_string()
+    }
+  }
+}
+
+/// 관련 할일(하위/선행/후행)을 한 줄씩 간단히 표시
+fn print_todo_refs(todos: &[Todo]) {
+  for todo in todos {
+    println!("  {} {} {}",
+      todo.status.to_emoji(),
+      todo.id.unwrap_or(0).to_string().cyan(),
+      todo.title
+    );
+  }
+}
+
+fn format_tags(tags: &[String]) -> String {
+  tags.iter().map(|t| format!("#{}", t)).collect::<Vec<_>>().join(" ")
+}
+
+/// 목록 안에서 하위 할일이 상위 할일 바로 아래 오도록 정렬하고 깊이를 함께 반환
+fn order_as_tree(todos: &[Todo]) -> Vec<(usize, &Todo)> {
+  fn visit<'a>(todo: &'a Todo, depth: usize, todos: &'a [Todo], ordered: &mut Vec<(usize, &'a Todo)>) {
+    ordered.push((depth, todo));
+    for child in todos.iter().filter(|t| t.parent_id.is_some() && t.parent_id == todo.id) {
+      visit(child, depth + 1, todos, ordered);
+    }
+  }
+
+  let ids: HashSet<i64> = todos.iter().filter_map(|t| t.id).collect();
+  let mut ordered = Vec::with_capacity(todos.len());
+
+  // 상위 할일이 목록에 없으면 최상위로 취급
+  for todo in todos.iter().filter(|t| !matches!(t.parent_id, Some(p) if ids.contains(&p))) {
+    visit(todo, 0, todos, &mut ordered);
+  }
+
+  ordered
+}
+
+fn tree_prefix(depth: usize) -> String {
+  if depth == 0 {
+    String::new()
+  } else {
+    format!("{}└─ ", "   ".repeat(depth - 1))
+  }
+}
+
+fn print_todos_table(todos: &[Todo], clock: &dyn Clock) {
   let mut table = Table::new();
-  
+
+  // 태그/프로젝트가 있는 할일이 하나라도 있을 때만 해당 컬럼 표시
+  let show_tags = todos.iter().any(|todo| !todo.tags.is_empty());
+  let show_project = todos.iter().any(|todo| todo.project.is_some());
+
   // 헤더 설정
-  table.add_row(Row::new(vec![
+  let mut header = vec![
     Cell::new("ID").style_spec("bFc"),
     Cell::new("상태").style_spec("bFc"),
     Cell::new("우선순위").style_spec("bFc"),
     Cell::new("제목").style_spec("bFc"),
-    Cell::new("마감일").style_spec("bFc"),
-    Cell::new("생성일").style_spec("bFc"),
-  ]));
+  ];
+  if show_tags {
+    header.push(Cell::new("태그").style_spec("bFc"));
+  }
+  if show_project {
+    header.push(Cell::new("프로젝트").style_spec("bFc"));
+  }
+  header.push(Cell::new("마감일").style_spec("bFc"));
+  header.push(Cell::new("생성일").style_spec("bFc"));
+  table.add_row(Row::new(header));
   
   // 데이터 행 추가
-  for todo in todos {
+  for (depth, todo) in order_as_tree(todos) {
     let id_cell = Cell::new(&todo.id.unwrap_or(0).to_string());
     
-    let status_cell = Cell::new(&format!("{} {}", 
-      todo.status.to_emoji(), 
-      todo.status.to_display_string()
-    ));
+    let status_cell = if todo.archived_at.is_some() {
+      Cell::new("📦 보관됨").style_spec("Fd")
+    } else {
+      Cell::new(&format!("{} {}",
+        todo.status.to_emoji(),
+        todo.status.to_display_string()
+      ))
+    };
     
     let priority_cell = Cell::new(&format!("{} {}", 
       todo.priority.to_emoji(), 
@@ -615,20 +2157,22 @@ fn print_todos_table(todos: &[Todo]) {
       Priority::Low => "Fg",
     });
     
-    let truncated_title = utils::truncate_title_for_terminal(&todo.title);
+    let truncated_title = format!("{}{}", tree_prefix(depth), utils::truncate_title_for_terminal(&todo.title));
     let title_cell = if todo.status == Status::Done {
       Cell::new(&truncated_title).style_spec("Fd")
-    } else if todo.is_overdue() {
+    } else if todo.is_overdue(clock) {
       Cell::new(&format!("⚠️  {}", truncated_title)).style_spec("Fr")
     } else {
       Cell::new(&truncated_title)
     };
     
     let due_cell = if let Some(due) = todo.due_date {
-      let formatted = utils::format_date(&due);
-      if let Some(days) = todo.days_until_due() {
+      let formatted = utils::format_due(&due, todo.due_all_day);
+      if let Some(days) = todo.days_until_due(clock) {
         if days < 0 {
           Cell::new(&format!("{} ({}일 전)", formatted, -days)).style_spec("Fr")
+        } else if todo.is_overdue(clock) {
+          Cell::new(&format!("{} (지남)", formatted)).style_spec("Fr")
         } else if days <= 1 {
           Cell::new(&format!("{} ({}일 후)", formatted, days)).style_spec("Fy")
         } else {
@@ -643,27 +2187,36 @@ fn print_todos_table(todos: &[Todo]) {
     
     let created_cell = Cell::new(&utils::format_date(&todo.created_at));
     
-    table.add_row(Row::new(vec![
-      id_cell,
-      status_cell,
-      priority_cell,
-      title_cell,
-      due_cell,
-      created_cell,
-    ]));
+    let mut cells = vec![id_cell, status_cell, priority_cell, title_cell];
+    if show_tags {
+      cells.push(Cell::new(&utils::truncate_text(&format_tags(&todo.tags), 20)).style_spec("Fc"));
+    }
+    if show_project {
+      cells.push(Cell::new(todo.project.as_deref().unwrap_or("-")).style_spec("Fm"));
+    }
+    cells.push(due_cell);
+    cells.push(created_cell);
+
+    table.add_row(Row::new(cells));
   }
   
   table.printstd();
 }
 
-fn print_todos_verbose(todos: &[Todo]) {
-  for (index, todo) in todos.iter().enumerate() {
+fn print_todos_verbose(todos: &[Todo], clock: &dyn Clock) {
+  for (index, (depth, todo)) in order_as_tree(todos).into_iter().enumerate() {
     if index > 0 {
       println!("{}", "─".repeat(60).dimmed());
     }
 
-    println!("ID: {} | 상태: {} {} | 우선순위: {} {}",
+    if depth > 0 {
+      print!("{}", tree_prefix(depth).dimmed());
+    }
+
+    // UUID는 명령에 쓸 수 있는 앞 8자리만
+    println!("ID: {} ({}) | 상태: {} {} | 우선순위: {} {}",
       todo.id.unwrap_or(0).to_string().cyan(),
+      todo.uuid.get(..8).unwrap_or(&todo.uuid).dimmed(),
       todo.status.to_emoji(),
       todo.status.to_display_string(),
       todo.priority.to_emoji(),
@@ -672,7 +2225,7 @@ fn print_todos_verbose(todos: &[Todo]) {
 
     if todo.status == Status::Done {
       println!("제목: {}", todo.title.strikethrough());
-    } else if todo.is_overdue() {
+    } else if todo.is_overdue(clock) {
       println!("제목: ⚠️  {}", todo.title.red());
     } else {
       println!("제목: {}", todo.title.bold());
@@ -684,11 +2237,25 @@ fn print_todos_verbose(todos: &[Todo]) {
       }
     }
 
+    if !todo.tags.is_empty() {
+      println!("태그: {}", format_tags(&todo.tags).cyan());
+    }
+
+    if let Some(project) = &todo.project {
+      println!("프로젝트: {}", project.magenta());
+    }
+
+    if let Some(rule) = &todo.recurrence {
+      println!("반복: {}", rule.blue());
+    }
+
     if let Some(due) = todo.due_date {
-      let formatted = utils::format_date(&due);
-      if let Some(days) = todo.days_until_due() {
+      let formatted = utils::format_due(&due, todo.due_all_day);
+      if let Some(days) = todo.days_until_due(clock) {
         if days < 0 {
           println!("마감일: {} {} ({}일 전)", "⚠️".red(), formatted.red(), -days);
+        } else if todo.is_overdue(clock) {
+          println!("마감일: {} {} (지남)", "⚠️".red(), formatted.red());
         } else if days <= 1 {
           println!("마감일: {} ({}일 후)", formatted.yellow(), days);
         } else {
@@ -700,5 +2267,8 @@ fn print_todos_verbose(todos: &[Todo]) {
     }
 
     println!("생성일: {}", utils::format_date(&todo.created_at).dimmed());
+    if let Some(archived_at) = todo.archived_at {
+      println!("보관일: {}", utils::format_date(&archived_at).dimmed());
+    }
   }
 }
\ No newline at end of file
@@ -1,5 +1,6 @@
 pub mod args;
 pub mod commands;
+pub mod output;
 
 // CLI 타입들을 재내보내기
 pub use args::Cli;
@@ -0,0 +1,221 @@
+//! 기계가 읽을 수 있는 출력 (`--format json|jsonl|csv|tsv|ids`)
+//!
+//! 모든 형식은 모델의 serde 직렬화를 그대로 따른다. JSON 필드 이름과 순서가 곧 CSV/TSV 열이며,
+//! 필드는 추가만 하고 이름을 바꾸거나 빼지 않는다 (docs/command-reference.md의 출력 형식 참고).
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::error::{Result, TaskyError};
+use crate::models::Todo;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+  // 사람이 읽는 표/문장 (기본)
+  #[default]
+  Table,
+  // 들여쓴 JSON. 목록은 배열, 단건은 객체
+  Json,
+  // 한 줄에 JSON 객체 하나
+  Jsonl,
+  Csv,
+  Tsv,
+  // 할일 ID만 한 줄에 하나
+  Ids
+}
+
+impl OutputFormat {
+  #[allow(clippy::should_implement_trait)]
+  pub fn from_str(source: &str) -> Result<Self> {
+    match source.to_lowercase().as_str() {
+      "table" => Ok(OutputFormat::Table),
+      "json" => Ok(OutputFormat::Json),
+      "jsonl" | "ndjson" => Ok(OutputFormat::Jsonl),
+      "csv" => Ok(OutputFormat::Csv),
+      "tsv" => Ok(OutputFormat::Tsv),
+      "ids" => Ok(OutputFormat::Ids),
+      _ => Err(TaskyError::InvalidInput {
+        message: format!("잘못된 출력 형식: {}. table, json, jsonl, csv, tsv, ids 중 하나여야 합니다", source),
+      }),
+    }
+  }
+
+  pub fn is_table(&self) -> bool {
+    *self == OutputFormat::Table
+  }
+}
+
+fn to_values<T: Serialize>(records: &[T]) -> Result<Vec<Value>> {
+  records
+    .iter()
+    .map(serde_json::to_value)
+    .collect::<serde_json::Result<Vec<_>>>()
+    .map_err(|e| TaskyError::System(e.into()))
+}
+
+fn to_json(value: &Value, pretty: bool) -> Result<String> {
+  let json = if pretty { serde_json::to_string_pretty(value) } else { serde_json::to_string(value) };
+  json.map_err(|e| TaskyError::System(e.into()))
+}
+
+/// CSV/TSV 칸. 배열은 쉼표로 잇고(태그), 중첩 객체는 JSON 문자열, null은 빈 칸
+fn to_cell(value: &Value) -> String {
+  match value {
+    Value::Null => String::new(),
+    Value::String(s) => s.clone(),
+    Value::Array(items) if items.iter().all(|item| !item.is_object() && !item.is_array()) => {
+      items.iter().map(to_cell).collect::<Vec<_>>().join(",")
+    }
+    other => other.to_string(),
+  }
+}
+
+fn write_delimited(output: impl std::io::Write, values: &[Value], delimiter: u8) -> Result<()> {
+  let csv_error = |e: csv::Error| TaskyError::System(e.into());
+
+  // 열은 첫 레코드의 필드 순서 (빈 목록이면 헤더도 없음)
+  let Some(Value::Object(first)) = values.first() else {
+    return Ok(());
+  };
+  let columns: Vec<&String> = first.keys().collect();
+
+  let mut writer = csv::WriterBuilder::new().delimiter(delimiter).from_writer(output);
+  writer.write_record(&columns).map_err(csv_error)?;
+  for value in values {
+    let row: Vec<String> = columns.iter().map(|column| value.get(column.as_str()).map(to_cell).unwrap_or_default()).collect();
+    writer.write_record(&row).map_err(csv_error)?;
+  }
+  writer.flush()?;
+
+  Ok(())
+}
+
+fn print_values(format: OutputFormat, values: &[Value], single: bool, todos: bool) -> Result<()> {
+  match format {
+    OutputFormat::Json if single => println!("{}", to_json(&values[0], true)?),
+    OutputFormat::Json => println!("{}", to_json(&Value::Array(values.to_vec()), true)?),
+    OutputFormat::Jsonl => {
+      for value in values {
+        println!("{}", to_json(value, false)?);
+      }
+    }
+    OutputFormat::Csv => write_delimited(std::io::stdout(), values, b',')?,
+    OutputFormat::Tsv => write_delimited(std::io::stdout(), values, b'\t')?,
+    OutputFormat::Ids if todos => {
+      for id in values.iter().filter_map(|value| value.get("id")) {
+        println!("{}", id);
+      }
+    }
+    OutputFormat::Ids => {
+      return Err(TaskyError::InvalidInput {
+        message: "ids 형식은 할일을 출력하는 명령에서만 쓸 수 있습니다".to_string(),
+      });
+    }
+    OutputFormat::Table => unreachable!("표 형식은 각 명령이 직접 출력"),
+  }
+
+  Ok(())
+}
+
+/// 할일 목록 출력 (JSON은 항상 배열)
+pub fn print_todos(format: OutputFormat, todos: &[Todo]) -> Result<()> {
+  print_values(format, &to_values(todos)?, false, true)
+}
+
+/// 할일 한 건 출력 (JSON은 객체)
+pub fn print_todo(format: OutputFormat, todo: &Todo) -> Result<()> {
+  print_values(format, &to_values(std::slice::from_ref(todo))?, true, true)
+}
+
+/// 할일이 아닌 목록 출력 (통계, 이력 등. ids 형식은 쓸 수 없음)
+pub fn print_records<T: Serialize>(format: OutputFormat, records: &[T]) -> Result<()> {
+  print_values(format, &to_values(records)?, false, false)
+}
+
+/// 할일이 아닌 단건 출력
+pub fn print_record<T: Serialize>(format: OutputFormat, record: &T) -> Result<()> {
+  print_values(format, &to_values(std::slice::from_ref(record))?, true, false)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::models::{TagStats, TodoStats};
+  use serde_json::json;
+
+  fn keys(value: &Value) -> Vec<&str> {
+    value.as_object().unwrap().keys().map(String::as_str).collect()
+  }
+
+  fn delimited(values: &[Value], delimiter: u8) -> String {
+    let mut buffer = Vec::new();
+    write_delimited(&mut buffer, values, delimiter).unwrap();
+    String::from_utf8(buffer).unwrap()
+  }
+
+  #[test]
+  fn test_from_str() {
+    assert_eq!(OutputFormat::from_str("JSON").unwrap(), OutputFormat::Json);
+    assert_eq!(OutputFormat::from_str("ndjson").unwrap(), OutputFormat::Jsonl);
+    assert!(OutputFormat::from_str("yaml").is_err());
+  }
+
+  #[test]
+  fn test_to_cell() {
+    assert_eq!(to_cell(&json!(null)), "");
+    assert_eq!(to_cell(&json!("a,b")), "a,b");
+    assert_eq!(to_cell(&json!(["work", "home"])), "work,home");
+    assert_eq!(to_cell(&json!(true)), "true");
+    assert_eq!(to_cell(&json!(42)), "42");
+    assert_eq!(to_cell(&json!([{"tag": "work"}])), r#"[{"tag":"work"}]"#);
+  }
+
+  #[test]
+  fn test_schema_order() {
+    // docs/command-reference.md의 할일/통계 JSON 스키마와 같은 순서
+    let mut todo = Todo::sample("보고서");
+    todo.tags = vec!["work".to_string(), "urgent".to_string()];
+    let values = to_values(&[todo]).unwrap();
+    assert_eq!(keys(&values[0]), vec![
+      "id", "title", "description", "priority", "status", "created_at", "updated_at", "due_date", "due_all_day",
+      "tags", "project", "parent_id", "series_id", "recurrence", "deleted_at", "archived_at", "uuid",
+    ]);
+    assert_eq!(values[0]["created_at"], json!("2026-10-18T00:00:00Z"));
+
+    let stats = TodoStats {
+      total_todos: 2,
+      pending_todos: 1,
+      completed_todos: 1,
+      high_priority_todos: 0,
+      overdue_todos: 0,
+      completion_rate: 50.0,
+      archived_todos: 0,
+      tag_stats: vec![TagStats { tag: "work".to_string(), total_todos: 1, pending_todos: 1, completed_todos: 0 }],
+    };
+    let values = to_values(&[stats]).unwrap();
+    assert_eq!(keys(&values[0]), vec![
+      "total_todos", "pending_todos", "completed_todos", "high_priority_todos", "overdue_todos", "completion_rate",
+      "archived_todos", "tag_stats",
+    ]);
+    assert_eq!(keys(&values[0]["tag_stats"][0]), vec!["tag", "total_todos", "pending_todos", "completed_todos"]);
+
+    // CSV 열도 같은 순서
+    let csv = delimited(&to_values(&[Todo::sample("보고서")]).unwrap(), b',');
+    assert_eq!(csv.lines().next().unwrap(),
+      "id,title,description,priority,status,created_at,updated_at,due_date,due_all_day,tags,project,parent_id,series_id,recurrence,deleted_at,archived_at,uuid");
+  }
+
+  #[test]
+  fn test_write_delimited() {
+    // 빈 목록이면 헤더도 없음
+    assert_eq!(delimited(&[], b','), "");
+
+    // 열은 첫 레코드 기준, 배열은 쉼표로 잇고 중첩 객체는 JSON, 없는 필드는 빈 칸
+    let values = vec![
+      json!({"tag": "work", "tags": ["a", "b"], "stats": {"total": 1}, "note": null}),
+      json!({"tag": "home", "tags": []}),
+    ];
+    assert_eq!(delimited(&values, b','), "tag,tags,stats,note\nwork,\"a,b\",\"{\"\"total\"\":1}\",\nhome,,,\n");
+    assert_eq!(delimited(&values, b'\t'), "tag\ttags\tstats\tnote\nwork\ta,b\t\"{\"\"total\"\":1}\"\t\nhome\t\t\t\n");
+  }
+}
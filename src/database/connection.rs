@@ -1,10 +1,12 @@
-use rusqlite::Connection;
+use rusqlite::{Connection, OpenFlags};
 use std::path::{Path, PathBuf};
 
 use crate::error::TaskyError;
+use crate::utils::{clock_from_env, Clock, SharedClock, SystemClock};
 
 pub struct Database {
   conn: Connection,
+  clock: SharedClock,
 }
 
 impl Database {
@@ -14,7 +16,16 @@ impl Database {
 
     conn.execute_batch("PRAGMA foreign_keys = ON;")?;
 
-    Ok(Database { conn })
+    // 환경변수 TASKY_NOW가 설정되어 있으면 그 시각으로 고정
+    let clock = clock_from_env()?;
+
+    Ok(Database { conn, clock })
+  }
+
+  /// 읽기 전용으로 열기. 파일을 만들거나 마이그레이션하지 않음 (`merge`가 다른 데이터베이스를 확인할 때)
+  pub fn open_read_only(path: &Path) -> crate::Result<Self> {
+    let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX)?;
+    Ok(Database { conn, clock: clock_from_env()? })
   }
 
   pub fn close(self) -> crate::Result<()> {
@@ -22,11 +33,12 @@ impl Database {
     Ok(())
   }
 
+  #[allow(clippy::should_implement_trait)]
   pub fn default() -> crate::Result<Self> {
     let db_path = Self::default_path();
 
     if let Some(parent) = db_path.parent() {
-      std::fs::create_dir_all(parent).map_err(|e| TaskyError::Io(e))?;
+      std::fs::create_dir_all(parent).map_err(TaskyError::Io)?;
     }
 
     Self::new(&db_path)
@@ -35,7 +47,13 @@ impl Database {
   pub fn in_memory() -> crate::Result<Self> {
     let conn = Connection::open_in_memory()?;
     conn.execute_batch("PRAGMA foreign_keys = ON;")?;
-    Ok(Database { conn })
+    Ok(Database { conn, clock: std::sync::Arc::new(SystemClock) })
+  }
+
+  /// 현재 시각 공급자 교체 (테스트에서 FixedClock 주입)
+  pub fn with_clock(mut self, clock: SharedClock) -> Self {
+    self.clock = clock;
+    self
   }
 
   pub fn default_path() -> PathBuf {
@@ -58,25 +76,31 @@ impl Database {
     &self.conn
   }
 
+  pub fn clock(&self) -> &dyn Clock {
+    self.clock.as_ref()
+  }
+
   pub fn conn_mut(&mut self) -> &mut Connection {
     &mut self.conn
   }
 
+  /// 대기중인 스키마 마이그레이션 적용. 데이터베이스가 바이너리보다 새 버전이면 오류
   pub fn initialize(&self) -> crate::Result<()> {
     crate::database::migrations::run_migrations(&self.conn)?;
     Ok(())
   }
 
+  pub fn schema_version(&self) -> crate::Result<u32> {
+    crate::database::migrations::current_version(&self.conn)
+  }
+
   pub fn is_initialized(&self) -> bool {
     let mut stmt = match self.conn.prepare("SELECT name FROM sqlite_master WHERE type='table' AND name='todos'") {
       Ok(stmt) => stmt,
       Err(_) => return false,
     };
 
-    match stmt.query_row([], |_| Ok(())) {
-      Ok(_) => true,
-      Err(_) => false,
-    }
+    stmt.query_row([], |_| Ok(())).is_ok()
   }
 
   pub fn transaction<F, R>(&mut self, f: F) -> crate::Result<R>
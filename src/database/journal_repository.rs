@@ -0,0 +1,148 @@
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection};
+
+use crate::error::{Result, TaskyError};
+use crate::models::{Operation, OperationChange, TodoSnapshot};
+use crate::utils::Clock;
+
+// 보관하는 최대 작업 수 (오래된 것부터 삭제)
+const MAX_OPERATIONS: i64 = 100;
+
+pub trait JournalRepository {
+  fn begin(&self, label: &str) -> Result<i64>;
+  fn add_change(&self, operation_id: i64, change: &OperationChange) -> Result<()>;
+  fn finish(&self, operation_id: i64) -> Result<()>;
+  fn find_undoable(&self, limit: usize) -> Result<Vec<Operation>>;
+  fn find_redoable(&self, limit: usize) -> Result<Vec<Operation>>;
+  fn set_undone(&self, operation_id: i64, undone: bool) -> Result<()>;
+}
+
+pub struct SqliteJournalRepository<'a> {
+  conn: &'a Connection,
+  clock: &'a dyn Clock,
+}
+
+impl<'a> SqliteJournalRepository<'a> {
+  pub fn new(conn: &'a Connection, clock: &'a dyn Clock) -> Self {
+    Self { conn, clock }
+  }
+
+  fn to_json(snapshot: &Option<TodoSnapshot>) -> Result<Option<String>> {
+    snapshot
+      .as_ref()
+      .map(serde_json::to_string)
+      .transpose()
+      .map_err(|e| TaskyError::System(e.into()))
+  }
+
+  fn from_json(json: Option<String>) -> Result<Option<TodoSnapshot>> {
+    json
+      .map(|j| serde_json::from_str(&j))
+      .transpose()
+      .map_err(|e| TaskyError::System(e.into()))
+  }
+
+  fn find_operations(&self, condition: &str, order: &str, limit: usize) -> Result<Vec<Operation>> {
+    let mut stmt = self.conn.prepare(&format!(
+      "SELECT id, label, created_at, undone_at FROM operations WHERE {} ORDER BY id {} LIMIT ?1",
+      condition, order
+    ))?;
+
+    let rows = stmt
+      .query_map(params![limit as i64], |row| {
+        Ok((
+          row.get::<_, i64>(0)?,
+          row.get::<_, String>(1)?,
+          row.get::<_, DateTime<Utc>>(2)?,
+          row.get::<_, Option<DateTime<Utc>>>(3)?,
+        ))
+      })?
+      .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    let mut operations = Vec::new();
+    for (id, label, created_at, undone_at) in rows {
+      operations.push(Operation { id, label, created_at, undone_at, changes: self.find_changes(id)? });
+    }
+
+    Ok(operations)
+  }
+
+  /// 작업의 변경 목록 (기록된 순서)
+  fn find_changes(&self, operation_id: i64) -> Result<Vec<OperationChange>> {
+    let mut stmt = self.conn.prepare(
+      "SELECT todo_id, before, after FROM operation_changes WHERE operation_id = ?1 ORDER BY id",
+    )?;
+
+    let rows = stmt
+      .query_map(params![operation_id], |row| {
+        Ok((row.get::<_, i64>(0)?, row.get::<_, Option<String>>(1)?, row.get::<_, Option<String>>(2)?))
+      })?
+      .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    rows
+      .into_iter()
+      .map(|(todo_id, before, after)| {
+        Ok(OperationChange { todo_id, before: Self::from_json(before)?, after: Self::from_json(after)? })
+      })
+      .collect()
+  }
+}
+
+impl<'a> JournalRepository for SqliteJournalRepository<'a> {
+  fn begin(&self, label: &str) -> Result<i64> {
+    self.conn.execute(
+      "INSERT INTO operations (label, created_at) VALUES (?1, ?2)",
+      params![label, self.clock.now()],
+    )?;
+    Ok(self.conn.last_insert_rowid())
+  }
+
+  fn add_change(&self, operation_id: i64, change: &OperationChange) -> Result<()> {
+    self.conn.execute(
+      "INSERT INTO operation_changes (operation_id, todo_id, before, after) VALUES (?1, ?2, ?3, ?4)",
+      params![operation_id, change.todo_id, Self::to_json(&change.before)?, Self::to_json(&change.after)?],
+    )?;
+    Ok(())
+  }
+
+  fn finish(&self, operation_id: i64) -> Result<()> {
+    let changes: i64 = self.conn.query_row(
+      "SELECT COUNT(*) FROM operation_changes WHERE operation_id = ?1",
+      params![operation_id],
+      |row| row.get(0),
+    )?;
+
+    // 아무것도 바꾸지 않은 명령은 남기지 않음
+    if changes == 0 {
+      self.conn.execute("DELETE FROM operations WHERE id = ?1", params![operation_id])?;
+      return Ok(());
+    }
+
+    // 새 작업이 생기면 다시 실행(redo)할 수 있던 작업은 버림
+    self.conn.execute("DELETE FROM operations WHERE undone_at IS NOT NULL", [])?;
+    self.conn.execute(
+      "DELETE FROM operations WHERE id <= (SELECT MAX(id) FROM operations) - ?1",
+      params![MAX_OPERATIONS],
+    )?;
+    Ok(())
+  }
+
+  fn find_undoable(&self, limit: usize) -> Result<Vec<Operation>> {
+    // 최근 작업부터
+    self.find_operations("undone_at IS NULL", "DESC", limit)
+  }
+
+  fn find_redoable(&self, limit: usize) -> Result<Vec<Operation>> {
+    // 되돌린 작업은 항상 가장 최근 작업들이므로 오래된 것부터 다시 실행
+    self.find_operations("undone_at IS NOT NULL", "ASC", limit)
+  }
+
+  fn set_undone(&self, operation_id: i64, undone: bool) -> Result<()> {
+    let undone_at = if undone { Some(self.clock.now()) } else { None };
+    self.conn.execute(
+      "UPDATE operations SET undone_at = ?1 WHERE id = ?2",
+      params![undone_at, operation_id],
+    )?;
+    Ok(())
+  }
+}
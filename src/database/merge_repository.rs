@@ -0,0 +1,116 @@
+use rusqlite::{params, Connection};
+use std::path::{Path, PathBuf};
+
+use crate::database::todo_repository::{todo_columns, SqliteTodoRepository};
+use crate::error::Result;
+use crate::models::{ExportedDependency, ExportedSeries, MergeSide, Todo, TodoEvent};
+
+/// 다른 데이터베이스 파일을 붙여서 두 쪽을 같은 연결로 읽음 (`tasky merge`)
+pub trait MergeRepository {
+  /// 지금 쓰는 데이터베이스 파일 경로 (메모리 데이터베이스면 None)
+  fn main_path(&self) -> Result<Option<PathBuf>>;
+  /// 읽기 전용으로 `other`라는 이름으로 붙임
+  fn attach(&self, path: &Path) -> Result<()>;
+  fn detach(&self) -> Result<()>;
+  /// 휴지통을 포함한 모든 할일 (ID 순)
+  fn find_todos(&self, side: MergeSide) -> Result<Vec<Todo>>;
+  fn find_dependencies(&self, side: MergeSide) -> Result<Vec<ExportedDependency>>;
+  fn find_series(&self, side: MergeSide) -> Result<Vec<ExportedSeries>>;
+  /// 모든 변경 이력 (기록 순)
+  fn find_events(&self, side: MergeSide) -> Result<Vec<TodoEvent>>;
+}
+
+pub struct SqliteMergeRepository<'a> {
+  conn: &'a Connection,
+}
+
+impl<'a> SqliteMergeRepository<'a> {
+  pub fn new(conn: &'a Connection) -> Self {
+    Self { conn }
+  }
+
+  fn schema(side: MergeSide) -> &'static str {
+    match side {
+      MergeSide::Local => "main",
+      MergeSide::Other => "other",
+    }
+  }
+}
+
+impl<'a> MergeRepository for SqliteMergeRepository<'a> {
+  fn main_path(&self) -> Result<Option<PathBuf>> {
+    let file: String = self.conn.query_row("SELECT file FROM pragma_database_list WHERE name = 'main'", [], |row| row.get(0))?;
+    Ok(Some(PathBuf::from(file)).filter(|path| !path.as_os_str().is_empty()))
+  }
+
+  fn attach(&self, path: &Path) -> Result<()> {
+    // 읽기 전용 URI로 붙여서 다른 쪽 파일은 절대 바꾸지 않음
+    let mut uri = String::from("file:");
+    for c in path.to_string_lossy().chars() {
+      match c {
+        '%' => uri.push_str("%25"),
+        '?' => uri.push_str("%3f"),
+        '#' => uri.push_str("%23"),
+        c => uri.push(c),
+      }
+    }
+    uri.push_str("?mode=ro");
+
+    self.conn.execute("ATTACH DATABASE ?1 AS other", params![uri])?;
+    Ok(())
+  }
+
+  fn detach(&self) -> Result<()> {
+    self.conn.execute("DETACH DATABASE other", [])?;
+    Ok(())
+  }
+
+  fn find_todos(&self, side: MergeSide) -> Result<Vec<Todo>> {
+    let schema = Self::schema(side);
+    let query = format!("SELECT {} FROM {}.todos ORDER BY id", todo_columns(schema), schema);
+    let mut stmt = self.conn.prepare(&query)?;
+
+    let todos = stmt
+      .query_map([], SqliteTodoRepository::row_to_todo)?
+      .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(todos)
+  }
+
+  fn find_dependencies(&self, side: MergeSide) -> Result<Vec<ExportedDependency>> {
+    let query = format!(
+      "SELECT todo_id, depends_on_id FROM {}.todo_dependencies ORDER BY todo_id, depends_on_id",
+      Self::schema(side)
+    );
+    let mut stmt = self.conn.prepare(&query)?;
+
+    let dependencies = stmt
+      .query_map([], |row| Ok(ExportedDependency { todo_id: row.get(0)?, depends_on_id: row.get(1)? }))?
+      .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(dependencies)
+  }
+
+  fn find_series(&self, side: MergeSide) -> Result<Vec<ExportedSeries>> {
+    let query = format!("SELECT id, rule, created_at, ended_at FROM {}.recurrences ORDER BY id", Self::schema(side));
+    let mut stmt = self.conn.prepare(&query)?;
+
+    let series = stmt
+      .query_map([], |row| {
+        Ok(ExportedSeries { id: row.get(0)?, rule: row.get(1)?, created_at: row.get(2)?, ended_at: row.get(3)? })
+      })?
+      .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(series)
+  }
+
+  fn find_events(&self, side: MergeSide) -> Result<Vec<TodoEvent>> {
+    let query = format!(
+      "SELECT id, todo_id, kind, field, old_value, new_value, actor, created_at FROM {}.todo_events ORDER BY id",
+      Self::schema(side)
+    );
+    let mut stmt = self.conn.prepare(&query)?;
+
+    let events = stmt
+      .query_map([], SqliteTodoRepository::row_to_event)?
+      .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(events)
+  }
+}
@@ -1,12 +1,78 @@
 use rusqlite::Connection;
+use uuid::Uuid;
 
-use crate::error::Result;
+use crate::error::{Result, TaskyError};
 
-pub fn run_migrations(conn: &Connection) -> Result<()> {
+/// 번호가 매겨진 스키마 변경 단계. 적용된 마지막 번호는 `PRAGMA user_version`에 기록된다.
+#[derive(Debug)]
+pub struct Migration {
+  pub version: u32,
+  pub description: &'static str,
+  apply: fn(&Connection) -> Result<()>,
+}
 
-  create_todos_table(conn)?;
-  create_indexes(conn)?;
-  Ok(())
+// 새 스키마 변경은 항상 끝에 추가하고 기존 단계는 수정하지 않는다.
+// 버전 관리 이전 데이터베이스(user_version = 0)에도 1번부터 다시 적용되므로 각 단계는 멱등이어야 한다.
+const MIGRATIONS: &[Migration] = &[
+  Migration { version: 1, description: "할일 테이블 생성", apply: create_todos_table },
+  Migration { version: 2, description: "태그 테이블 추가", apply: create_tags_tables },
+  Migration { version: 3, description: "프로젝트 테이블 추가", apply: create_projects_table },
+  Migration { version: 4, description: "하위 할일(parent_id) 컬럼 추가", apply: add_parent_column },
+  Migration { version: 5, description: "의존성 테이블 추가", apply: create_dependencies_table },
+  Migration { version: 6, description: "반복 시리즈 테이블 추가", apply: create_recurrences_table },
+  Migration { version: 7, description: "종일 마감(due_all_day) 컬럼 추가", apply: add_due_all_day_column },
+  Migration { version: 8, description: "조회 인덱스 생성", apply: create_indexes },
+  Migration { version: 9, description: "변경 이력(todo_events) 테이블 추가", apply: create_events_table },
+  Migration { version: 10, description: "되돌리기 저널 테이블 추가", apply: create_journal_tables },
+  Migration { version: 11, description: "휴지통(deleted_at) 컬럼 추가", apply: add_deleted_at_column },
+  Migration { version: 12, description: "보관(archived_at) 컬럼 추가", apply: add_archived_at_column },
+  Migration { version: 13, description: "전문 검색(todos_fts) 색인 추가", apply: create_search_index },
+  Migration { version: 14, description: "저장된 보기(views) 테이블 추가", apply: create_views_table },
+  Migration { version: 15, description: "UUID 컬럼 추가", apply: add_uuid_column },
+];
+
+/// 이 바이너리가 아는 최신 스키마 버전
+pub fn latest_version() -> u32 {
+  MIGRATIONS.last().map(|m| m.version).unwrap_or(0)
+}
+
+pub fn all_migrations() -> &'static [Migration] {
+  MIGRATIONS
+}
+
+/// 데이터베이스에 기록된 스키마 버전
+pub fn current_version(conn: &Connection) -> Result<u32> {
+  Ok(conn.query_row("PRAGMA user_version", [], |row| row.get(0))?)
+}
+
+/// 아직 적용되지 않은 마이그레이션. 바이너리보다 새 데이터베이스면 오류
+pub fn pending_migrations(conn: &Connection) -> Result<Vec<&'static Migration>> {
+  let current = current_version(conn)?;
+  let latest = latest_version();
+
+  if current > latest {
+    return Err(TaskyError::SchemaTooNew { found: current, supported: latest });
+  }
+
+  Ok(MIGRATIONS.iter().filter(|m| m.version > current).collect())
+}
+
+/// 대기중인 마이그레이션을 한 트랜잭션 안에서 순서대로 적용하고 적용된 목록을 반환.
+/// 중간에 실패하면 전부 롤백되어 버전도 그대로 남는다.
+pub fn run_migrations(conn: &Connection) -> Result<Vec<&'static Migration>> {
+  let pending = pending_migrations(conn)?;
+  if pending.is_empty() {
+    return Ok(pending);
+  }
+
+  let tx = conn.unchecked_transaction()?;
+  for migration in &pending {
+    (migration.apply)(&tx)?;
+    tx.pragma_update(None, "user_version", migration.version)?;
+  }
+  tx.commit()?;
+
+  Ok(pending)
 }
 
 fn create_todos_table(conn: &Connection) -> Result<()> {
@@ -27,6 +93,246 @@ fn create_todos_table(conn: &Connection) -> Result<()> {
   Ok(())
 }
 
+fn create_tags_tables(conn: &Connection) -> Result<()> {
+
+  conn.execute(
+    "CREATE TABLE IF NOT EXISTS tags (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL UNIQUE
+        )", [])?;
+
+  // 할일-태그 다대다 연결 (할일이나 태그가 삭제되면 연결도 삭제)
+  conn.execute(
+    "CREATE TABLE IF NOT EXISTS todo_tags (
+            todo_id INTEGER NOT NULL REFERENCES todos(id) ON DELETE CASCADE,
+            tag_id INTEGER NOT NULL REFERENCES tags(id) ON DELETE CASCADE,
+            PRIMARY KEY (todo_id, tag_id)
+        )", [])?;
+  Ok(())
+}
+
+fn create_projects_table(conn: &Connection) -> Result<()> {
+
+  // 이름은 `work.backend`처럼 점으로 구분된 전체 경로
+  conn.execute(
+    "CREATE TABLE IF NOT EXISTS projects (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL UNIQUE
+        )", [])?;
+
+  add_column_if_missing(
+    conn,
+    "todos",
+    "project_id",
+    "INTEGER REFERENCES projects(id) ON DELETE SET NULL",
+  )?;
+  Ok(())
+}
+
+fn add_parent_column(conn: &Connection) -> Result<()> {
+
+  // 상위 할일이 삭제되면 하위 할일도 함께 삭제
+  add_column_if_missing(
+    conn,
+    "todos",
+    "parent_id",
+    "INTEGER REFERENCES todos(id) ON DELETE CASCADE",
+  )
+}
+
+fn create_dependencies_table(conn: &Connection) -> Result<()> {
+
+  // todo_id는 depends_on_id가 완료되어야 시작할 수 있음
+  conn.execute(
+    "CREATE TABLE IF NOT EXISTS todo_dependencies (
+            todo_id INTEGER NOT NULL REFERENCES todos(id) ON DELETE CASCADE,
+            depends_on_id INTEGER NOT NULL REFERENCES todos(id) ON DELETE CASCADE,
+            PRIMARY KEY (todo_id, depends_on_id),
+            CHECK (todo_id != depends_on_id)
+        )", [])?;
+  Ok(())
+}
+
+fn create_recurrences_table(conn: &Connection) -> Result<()> {
+
+  // 반복 시리즈: 회차마다 todos 행이 하나씩 생기고 series_id로 묶임
+  conn.execute(
+    "CREATE TABLE IF NOT EXISTS recurrences (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            rule TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            ended_at TEXT
+        )", [])?;
+
+  add_column_if_missing(
+    conn,
+    "todos",
+    "series_id",
+    "INTEGER REFERENCES recurrences(id) ON DELETE SET NULL",
+  )
+}
+
+fn add_due_all_day_column(conn: &Connection) -> Result<()> {
+
+  // 기존 마감일은 모두 로컬 자정으로 저장된 종일 마감
+  add_column_if_missing(conn, "todos", "due_all_day", "INTEGER NOT NULL DEFAULT 1")
+}
+
+fn create_events_table(conn: &Connection) -> Result<()> {
+
+  // 추가만 하는 변경 이력. 할일이 삭제되어도 이력은 남도록 외래 키를 걸지 않음
+  conn.execute(
+    "CREATE TABLE IF NOT EXISTS todo_events (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            todo_id INTEGER NOT NULL,
+            kind TEXT NOT NULL,
+            field TEXT,
+            old_value TEXT,
+            new_value TEXT,
+            actor TEXT,
+            created_at TEXT NOT NULL
+        )", [])?;
+
+  conn.execute(
+    "CREATE INDEX IF NOT EXISTS idx_todo_events_todo_id ON todo_events(todo_id)",
+    [],
+  )?;
+  Ok(())
+}
+
+fn create_journal_tables(conn: &Connection) -> Result<()> {
+
+  // 명령 하나가 작업 하나. undone_at이 있으면 되돌린 상태(redo 대상)
+  conn.execute(
+    "CREATE TABLE IF NOT EXISTS operations (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            label TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            undone_at TEXT
+        )", [])?;
+
+  // 변경 전후 할일 스냅샷(JSON). 생성이면 before, 삭제면 after가 NULL
+  conn.execute(
+    "CREATE TABLE IF NOT EXISTS operation_changes (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            operation_id INTEGER NOT NULL REFERENCES operations(id) ON DELETE CASCADE,
+            todo_id INTEGER NOT NULL,
+            before TEXT,
+            after TEXT
+        )", [])?;
+
+  conn.execute(
+    "CREATE INDEX IF NOT EXISTS idx_operation_changes_operation_id ON operation_changes(operation_id)",
+    [],
+  )?;
+  Ok(())
+}
+
+fn add_deleted_at_column(conn: &Connection) -> Result<()> {
+
+  // 값이 있으면 휴지통에 있는 할일 (삭제된 시각)
+  add_column_if_missing(conn, "todos", "deleted_at", "TEXT")?;
+
+  conn.execute(
+    "CREATE INDEX IF NOT EXISTS idx_todos_deleted_at ON todos(deleted_at)",
+    [],
+  )?;
+  Ok(())
+}
+
+fn add_archived_at_column(conn: &Connection) -> Result<()> {
+
+  // 값이 있으면 보관된 할일 (보관한 시각)
+  add_column_if_missing(conn, "todos", "archived_at", "TEXT")?;
+
+  conn.execute(
+    "CREATE INDEX IF NOT EXISTS idx_todos_archived_at ON todos(archived_at)",
+    [],
+  )?;
+  Ok(())
+}
+
+fn create_search_index(conn: &Connection) -> Result<()> {
+
+  // 제목/설명 전문 검색. trigram 토크나이저는 띄어쓰기와 무관한 부분 문자열 검색이라 한국어도 찾을 수 있음
+  conn.execute(
+    "CREATE VIRTUAL TABLE IF NOT EXISTS todos_fts USING fts5(
+            title, description,
+            content = 'todos', content_rowid = 'id',
+            tokenize = 'trigram'
+        )", [])?;
+
+  // todos 변경 시 색인 갱신 (외부 콘텐츠 테이블이라 삭제에는 이전 값이 필요)
+  conn.execute(
+    "CREATE TRIGGER IF NOT EXISTS todos_fts_insert AFTER INSERT ON todos BEGIN
+            INSERT INTO todos_fts (rowid, title, description) VALUES (new.id, new.title, new.description);
+        END", [])?;
+
+  conn.execute(
+    "CREATE TRIGGER IF NOT EXISTS todos_fts_delete AFTER DELETE ON todos BEGIN
+            INSERT INTO todos_fts (todos_fts, rowid, title, description) VALUES ('delete', old.id, old.title, old.description);
+        END", [])?;
+
+  conn.execute(
+    "CREATE TRIGGER IF NOT EXISTS todos_fts_update AFTER UPDATE OF title, description ON todos BEGIN
+            INSERT INTO todos_fts (todos_fts, rowid, title, description) VALUES ('delete', old.id, old.title, old.description);
+            INSERT INTO todos_fts (rowid, title, description) VALUES (new.id, new.title, new.description);
+        END", [])?;
+
+  // 기존 할일 색인
+  conn.execute("INSERT INTO todos_fts (todos_fts) VALUES ('rebuild')", [])?;
+  Ok(())
+}
+
+fn create_views_table(conn: &Connection) -> Result<()> {
+
+  // list 필터/정렬 옵션(ViewDefinition)을 JSON으로 저장
+  conn.execute(
+    "CREATE TABLE IF NOT EXISTS views (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL UNIQUE,
+            definition TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        )", [])?;
+  Ok(())
+}
+
+fn add_uuid_column(conn: &Connection) -> Result<()> {
+
+  // 데이터베이스마다 따로 매기는 id와 달리 어디서나 겹치지 않는 식별자 (내보내기/가져오기, 병합)
+  add_column_if_missing(conn, "todos", "uuid", "TEXT")?;
+
+  // 기존 할일에 UUID 부여
+  let ids: Vec<i64> = conn
+    .prepare("SELECT id FROM todos WHERE uuid IS NULL")?
+    .query_map([], |row| row.get(0))?
+    .collect::<rusqlite::Result<_>>()?;
+  for id in ids {
+    conn.execute("UPDATE todos SET uuid = ?1 WHERE id = ?2", rusqlite::params![Uuid::new_v4().to_string(), id])?;
+  }
+
+  conn.execute(
+    "CREATE UNIQUE INDEX IF NOT EXISTS idx_todos_uuid ON todos(uuid)",
+    [],
+  )?;
+  Ok(())
+}
+
+/// 기존 데이터베이스에 없는 컬럼만 추가
+fn add_column_if_missing(conn: &Connection, table: &str, column: &str, definition: &str) -> Result<()> {
+  let exists: bool = conn.query_row(
+    &format!("SELECT COUNT(*) > 0 FROM pragma_table_info('{}') WHERE name = ?1", table),
+    [column],
+    |row| row.get(0),
+  )?;
+
+  if !exists {
+    conn.execute(&format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, definition), [])?;
+  }
+  Ok(())
+}
+
 fn create_indexes(conn: &Connection) -> Result<()> {
 
   // 상태별 조회 최적화
@@ -53,13 +359,54 @@ fn create_indexes(conn: &Connection) -> Result<()> {
     [],
   )?;
 
+  // 태그별 할일 조회 최적화
+  conn.execute(
+    "CREATE INDEX IF NOT EXISTS idx_todo_tags_tag_id ON todo_tags(tag_id)",
+    [],
+  )?;
+
+  // 하위 할일 조회 최적화
+  conn.execute(
+    "CREATE INDEX IF NOT EXISTS idx_todos_parent_id ON todos(parent_id)",
+    [],
+  )?;
+
+  // 역방향 의존성 조회 최적화
+  conn.execute(
+    "CREATE INDEX IF NOT EXISTS idx_todo_dependencies_depends_on_id ON todo_dependencies(depends_on_id)",
+    [],
+  )?;
+
+  // 시리즈별 회차 조회 최적화
+  conn.execute(
+    "CREATE INDEX IF NOT EXISTS idx_todos_series_id ON todos(series_id)",
+    [],
+  )?;
+
+  // 프로젝트별 조회 최적화
+  conn.execute(
+    "CREATE INDEX IF NOT EXISTS idx_todos_project_id ON todos(project_id)",
+    [],
+  )?;
+
   Ok(())
 }
 
 /// 데이터베이스 리셋 (테스트용)
 #[cfg(test)]
 pub fn reset_database(conn: &Connection) -> Result<()> {
+    conn.execute("DROP TABLE IF EXISTS todo_dependencies", [])?;
+    conn.execute("DROP TABLE IF EXISTS todo_tags", [])?;
+    conn.execute("DROP TABLE IF EXISTS todos_fts", [])?;
+    conn.execute("DROP TABLE IF EXISTS tags", [])?;
     conn.execute("DROP TABLE IF EXISTS todos", [])?;
+    conn.execute("DROP TABLE IF EXISTS projects", [])?;
+    conn.execute("DROP TABLE IF EXISTS recurrences", [])?;
+    conn.execute("DROP TABLE IF EXISTS todo_events", [])?;
+    conn.execute("DROP TABLE IF EXISTS operation_changes", [])?;
+    conn.execute("DROP TABLE IF EXISTS operations", [])?;
+    conn.execute("DROP TABLE IF EXISTS views", [])?;
+    conn.pragma_update(None, "user_version", 0)?;
     run_migrations(conn)?;
     Ok(())
 }
@@ -86,14 +433,106 @@ mod tests {
     assert_eq!(count, 1);
   }
 
+  #[test]
+  fn test_create_tags_tables() {
+    let conn = Connection::open_in_memory().unwrap();
+    run_migrations(&conn).unwrap();
+
+    let count: i32 = conn
+      .query_row(
+        "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name IN ('tags', 'todo_tags')",
+        [],
+        |row| row.get(0),
+      )
+      .unwrap();
+    assert_eq!(count, 2);
+  }
+
+  #[test]
+  fn test_add_project_column_to_existing_table() {
+    let conn = Connection::open_in_memory().unwrap();
+    create_todos_table(&conn).unwrap();
+    run_migrations(&conn).unwrap();
+
+    let count: i32 = conn
+      .query_row(
+        "SELECT COUNT(*) FROM pragma_table_info('todos') WHERE name = 'project_id'",
+        [],
+        |row| row.get(0),
+      )
+      .unwrap();
+    assert_eq!(count, 1);
+  }
+
   #[test]
   fn test_run_migrations() {
     let conn = Connection::open_in_memory().unwrap();
-    let result = run_migrations(&conn);
-    assert!(result.is_ok());
+    let applied = run_migrations(&conn).unwrap();
+    assert_eq!(applied.len(), MIGRATIONS.len());
+    assert_eq!(current_version(&conn).unwrap(), latest_version());
+
+    // 다시 실행하면 적용할 것이 없어야 함
+    let applied = run_migrations(&conn).unwrap();
+    assert!(applied.is_empty());
+  }
+
+  #[test]
+  fn test_migration_versions_are_sequential() {
+    for (index, migration) in MIGRATIONS.iter().enumerate() {
+      assert_eq!(migration.version, index as u32 + 1);
+    }
+  }
+
+  #[test]
+  fn test_legacy_database_is_upgraded() {
+    // 버전 관리 이전: 테이블은 있지만 user_version = 0
+    let conn = Connection::open_in_memory().unwrap();
+    create_todos_table(&conn).unwrap();
+    create_tags_tables(&conn).unwrap();
+    conn.execute(
+      "INSERT INTO todos (title, created_at, updated_at) VALUES ('기존', '2024-01-01', '2024-01-01')",
+      [],
+    ).unwrap();
+
+    run_migrations(&conn).unwrap();
+    assert_eq!(current_version(&conn).unwrap(), latest_version());
+
+    let count: i64 = conn.query_row("SELECT COUNT(*) FROM todos WHERE due_all_day = 1", [], |row| row.get(0)).unwrap();
+    assert_eq!(count, 1);
+    let count: i64 = conn.query_row("SELECT COUNT(*) FROM todos WHERE length(uuid) = 36", [], |row| row.get(0)).unwrap();
+    assert_eq!(count, 1);
+  }
+
+  #[test]
+  fn test_pending_migrations_from_partial_version() {
+    let conn = Connection::open_in_memory().unwrap();
+    run_migrations(&conn).unwrap();
+    conn.pragma_update(None, "user_version", 6).unwrap();
+
+    let pending: Vec<u32> = pending_migrations(&conn).unwrap().iter().map(|m| m.version).collect();
+    assert_eq!(pending, vec![7, 8, 9, 10, 11, 12, 13, 14, 15]);
+  }
+
+  #[test]
+  fn test_refuse_newer_database() {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.pragma_update(None, "user_version", latest_version() + 1).unwrap();
 
-    // 마이그레이션을 다시 실행해도 오류가 없어야 함
     let result = run_migrations(&conn);
-    assert!(result.is_ok());
+    assert!(matches!(result.unwrap_err(), TaskyError::SchemaTooNew { .. }));
+  }
+
+  #[test]
+  fn test_failed_migration_rolls_back() {
+    let conn = Connection::open_in_memory().unwrap();
+    // todos가 테이블이 아닌 뷰면 2번 이후 단계가 실패함
+    conn.execute("CREATE VIEW todos AS SELECT 1 AS id", []).unwrap();
+
+    assert!(run_migrations(&conn).is_err());
+    assert_eq!(current_version(&conn).unwrap(), 0);
+    let tags: i64 = conn
+      .query_row("SELECT COUNT(*) FROM sqlite_master WHERE name = 'tags'", [], |row| row.get(0))
+      .unwrap();
+    assert_eq!(tags, 0);
   }
 }
\ No newline at end of file
@@ -1,7 +1,13 @@
 pub mod connection;
+pub mod journal_repository;
+pub mod merge_repository;
 pub mod migrations;
 pub mod todo_repository;
+pub mod view_repository;
 
 // 데이터베이스 관련 타입들을 재내보내기
 pub use connection::Database;
-pub use todo_repository::{TodoRepository, SqliteTodoRepository};
\ No newline at end of file
+pub use journal_repository::{JournalRepository, SqliteJournalRepository};
+pub use merge_repository::{MergeRepository, SqliteMergeRepository};
+pub use todo_repository::{TodoRepository, SqliteTodoRepository};
+pub use view_repository::{ViewRepository, SqliteViewRepository};
\ No newline at end of file
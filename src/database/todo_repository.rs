@@ -1,31 +1,93 @@
-use chrono::{Utc};
+use chrono::Utc;
 use rusqlite::{params, Connection, OptionalExtension, Row};
+use uuid::Uuid;
 
-use crate::error::{Result};
+use crate::error::{Result, TaskyError};
+use crate::utils::Clock;
 use crate::models::{
-  CreateTodo, Priority, SortBy, SortOrder, Status, Todo, TodoFilter, TodoStats, UpdateTodo,
+  field_changes, project_ancestors, CreateTodo, EventKind, ExportedSeries, TodoEvent, TodoSnapshot, OverdueCutoff, Priority, ProjectStats, SortBy, SortOrder, Status, TagStats,
+  FilterCondition, FilterExpr, RecurrenceSeries, SearchQuery, Todo, TodoFilter, TodoState, TodoStats, UpdateTodo,
 };
 
+/// 할일 조회 열 (태그는 쉼표로 연결해 한 열로 가져옴). `schema`는 `main`이나 `merge`에서 붙인 데이터베이스 이름
+pub(crate) fn todo_columns(schema: &str) -> String {
+  format!(
+    "id, title, description, priority, status, created_at, updated_at, due_date,
+  (SELECT GROUP_CONCAT(t.name, ',') FROM {0}.todo_tags tt JOIN {0}.tags t ON t.id = tt.tag_id
+    WHERE tt.todo_id = todos.id) AS tags,
+  (SELECT p.name FROM {0}.projects p WHERE p.id = todos.project_id) AS project,
+  parent_id, series_id,
+  (SELECT r.rule FROM {0}.recurrences r WHERE r.id = todos.series_id AND r.ended_at IS NULL) AS recurrence,
+  due_all_day, deleted_at, archived_at, uuid",
+    schema
+  )
+}
+
+// 태그 이름으로 할일 ID를 찾는 서브쿼리
+const TAGGED_TODO_IDS: &str =
+  "SELECT tt.todo_id FROM todo_tags tt JOIN tags t ON t.id = tt.tag_id WHERE t.name = ?";
+
+// 완료되지 않은 선행 할일이 있는 할일 ID (휴지통의 선행 할일은 막지 않음)
+const BLOCKED_TODO_IDS: &str =
+  "SELECT d.todo_id FROM todo_dependencies d JOIN todos b ON b.id = d.depends_on_id
+    WHERE b.status = 0 AND b.deleted_at IS NULL";
+
+// 검색어가 색인(trigram)으로 일치하는 할일 ID
+const FTS_MATCH_IDS: &str = "SELECT rowid FROM todos_fts WHERE todos_fts MATCH ?";
+
+// 색인으로 찾을 수 없는 짧은 검색어(두 글자 이하)가 제목/설명에 포함된 할일 ID
+const FTS_LIKE_IDS: &str =
+  "SELECT rowid FROM todos_fts WHERE title LIKE ? ESCAPE '\\' OR description LIKE ? ESCAPE '\\'";
+
+// 프로젝트와 그 하위 프로젝트의 ID (파라미터: 이름, 이름 길이 + 1, 이름 + '.')
+const PROJECT_SCOPE_IDS: &str =
+  "SELECT p.id FROM projects p WHERE p.name = ? OR substr(p.name, 1, ?) = ?";
+
 pub trait TodoRepository {
   fn create(&self, todo: &CreateTodo) -> Result<Todo>;
   fn find_by_id(&self, id: i64) -> Result<Option<Todo>>;
   fn find_all(&self, filter: &TodoFilter, sort_by: SortBy, sort_order: SortOrder) -> Result<Vec<Todo>>;
+  fn search(&self, query: &SearchQuery, filter: &TodoFilter, limit: usize) -> Result<Vec<Todo>>;
   fn update(&self, id: i64, todo: &UpdateTodo) -> Result<Option<Todo>>;
   fn delete(&self, id: i64) -> Result<bool>;
-  fn get_stats(&self) -> Result<TodoStats>;
+  fn find_in_trash(&self, id: i64) -> Result<Option<Todo>>;
+  fn find_trashed_descendant_ids(&self, id: i64) -> Result<Vec<i64>>;
+  fn restore_from_trash(&self, id: i64) -> Result<bool>;
+  fn purge(&self, id: i64) -> Result<bool>;
+  fn get_stats(&self, project: Option<&str>, include_archived: bool) -> Result<TodoStats>;
+  fn get_project_stats(&self, include_archived: bool) -> Result<Vec<ProjectStats>>;
+  fn find_children(&self, parent_id: i64) -> Result<Vec<Todo>>;
+  fn find_descendant_ids(&self, id: i64) -> Result<Vec<i64>>;
+  fn add_dependency(&self, todo_id: i64, depends_on_id: i64) -> Result<bool>;
+  fn remove_dependency(&self, todo_id: i64, depends_on_id: i64) -> Result<bool>;
+  fn depends_on(&self, todo_id: i64, depends_on_id: i64) -> Result<bool>;
+  fn find_blockers(&self, todo_id: i64) -> Result<Vec<Todo>>;
+  fn find_dependents(&self, todo_id: i64) -> Result<Vec<Todo>>;
+  fn find_pending_in_series(&self, series_id: i64) -> Result<Option<Todo>>;
+  fn list_series(&self) -> Result<Vec<RecurrenceSeries>>;
+  fn end_series(&self, series_id: i64) -> Result<bool>;
+  fn find_events(&self, todo_id: Option<i64>, limit: usize) -> Result<Vec<TodoEvent>>;
+  fn snapshot(&self, id: i64) -> Result<Option<TodoSnapshot>>;
+  fn restore(&self, snapshot: &TodoSnapshot) -> Result<()>;
+  fn insert(&self, todo: &Todo) -> Result<i64>;
+  fn import_series(&self, series: &ExportedSeries, keep_id: bool) -> Result<i64>;
+  fn find_id_by_uuid(&self, uuid: &str) -> Result<Option<i64>>;
+  fn find_ids_by_uuid_prefix(&self, prefix: &str) -> Result<Vec<i64>>;
+  fn next_id(&self) -> Result<i64>;
 }
 
 pub struct SqliteTodoRepository<'a> {
 
   conn: &'a Connection,
+  clock: &'a dyn Clock,
 }
 
 impl<'a> SqliteTodoRepository<'a> {
-  pub fn new(conn: &'a Connection) -> Self {
-    Self { conn }
+  pub fn new(conn: &'a Connection, clock: &'a dyn Clock) -> Self {
+    Self { conn, clock }
   }
 
-  fn row_to_todo(row: &Row) -> rusqlite::Result<Todo> {
+  pub(crate) fn row_to_todo(row: &Row) -> rusqlite::Result<Todo> {
     Ok(Todo {
       id: Some(row.get(0)?),
       title: row.get(1)?,
@@ -44,14 +106,199 @@ impl<'a> SqliteTodoRepository<'a> {
       created_at: row.get(5)?,
       updated_at: row.get(6)?,
       due_date: row.get(7)?,
+      tags: Self::split_tags(row.get(8)?),
+      project: row.get(9)?,
+      parent_id: row.get(10)?,
+      series_id: row.get(11)?,
+      recurrence: row.get(12)?,
+      due_all_day: row.get(13)?,
+      deleted_at: row.get(14)?,
+      archived_at: row.get(15)?,
+      uuid: row.get::<_, Option<String>>(16)?.unwrap_or_default(),
     })
   }
 
-  fn build_filter_clause(filter: &TodoFilter) -> (String, Vec<Box<dyn rusqlite::ToSql>>) {
+  /// id, todo_id, kind, field, old_value, new_value, actor, created_at 순서의 행
+  pub(crate) fn row_to_event(row: &Row) -> rusqlite::Result<TodoEvent> {
+    let kind: String = row.get(2)?;
+    Ok(TodoEvent {
+      id: row.get(0)?,
+      todo_id: row.get(1)?,
+      kind: EventKind::from_str(&kind).unwrap_or(EventKind::Updated),
+      field: row.get(3)?,
+      old_value: row.get(4)?,
+      new_value: row.get(5)?,
+      actor: row.get(6)?,
+      created_at: row.get(7)?,
+    })
+  }
+
+  /// 휴지통 여부와 관계없이 조회
+  fn find_any(&self, id: i64) -> Result<Option<Todo>> {
+    let query = format!("SELECT {} FROM todos WHERE id = ?1", todo_columns("main"));
+    let mut stmt = self.conn.prepare(&query)?;
+
+    stmt.query_row(params![id], Self::row_to_todo)
+        .optional()
+        .map_err(|e| e.into())
+  }
+
+  /// 의존성 테이블에서 직접 가져온 ID 목록 (휴지통의 할일 포함)
+  fn dependency_ids(&self, query: &str, id: i64) -> Result<Vec<i64>> {
+    let mut stmt = self.conn.prepare(query)?;
+
+    let ids = stmt
+        .query_map(params![id], |row| row.get(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    Ok(ids)
+  }
+
+  fn project_scope_params(project: &str) -> Vec<Box<dyn rusqlite::ToSql>> {
+    vec![
+      Box::new(project.to_string()),
+      Box::new(project.chars().count() as i64 + 1),
+      Box::new(format!("{}.", project)),
+    ]
+  }
+
+  /// 프로젝트(와 상위 프로젝트)를 없으면 만들고 ID 반환
+  fn ensure_project(&self, name: &str) -> Result<i64> {
+    for ancestor in project_ancestors(name) {
+      self.conn.execute("INSERT OR IGNORE INTO projects (name) VALUES (?1)", params![ancestor])?;
+    }
+
+    let id = self.conn.query_row(
+      "SELECT id FROM projects WHERE name = ?1",
+      params![name],
+      |row| row.get(0),
+    )?;
+    Ok(id)
+  }
+
+  fn resolve_project_id(&self, project: Option<&String>) -> Result<Option<i64>> {
+    project.map(|name| self.ensure_project(name)).transpose()
+  }
+
+  fn split_tags(joined: Option<String>) -> Vec<String> {
+    let mut tags: Vec<String> = joined
+      .map(|s| s.split(',').map(|t| t.to_string()).collect())
+      .unwrap_or_default();
+    tags.sort();
+    tags
+  }
+
+  /// 변경 이력 한 건 추가
+  fn record_event(
+    &self,
+    todo_id: i64,
+    kind: EventKind,
+    field: Option<&str>,
+    old_value: Option<&str>,
+    new_value: Option<&str>,
+  ) -> Result<()> {
+    let actor = std::env::var("USER").or_else(|_| std::env::var("USERNAME")).ok();
+
+    self.conn.execute(
+      "INSERT INTO todo_events (todo_id, kind, field, old_value, new_value, actor, created_at)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+      params![todo_id, kind.as_str(), field, old_value, new_value, actor, self.clock.now()],
+    )?;
+    Ok(())
+  }
+
+  /// 수정 전후를 비교해 바뀐 필드와 상태 변경을 이력에 기록
+  fn record_update_events(&self, before: &Todo, after: &Todo) -> Result<()> {
+    let id = after.id.unwrap_or_default();
+
+    for (field, old_value, new_value) in field_changes(before, after) {
+      self.record_event(id, EventKind::Updated, Some(field), old_value.as_deref(), new_value.as_deref())?;
+    }
+
+    match (before.status, after.status) {
+      (Status::Pending, Status::Done) => self.record_event(id, EventKind::Completed, None, None, None)?,
+      (Status::Done, Status::Pending) => self.record_event(id, EventKind::Uncompleted, None, None, None)?,
+      _ => {}
+    }
+
+    match (before.archived_at, after.archived_at) {
+      (None, Some(_)) => self.record_event(id, EventKind::Archived, None, None, None)?,
+      (Some(_), None) => self.record_event(id, EventKind::Unarchived, None, None, None)?,
+      _ => {}
+    }
+
+    match (before.deleted_at, after.deleted_at) {
+      (None, Some(_)) => self.record_event(id, EventKind::Deleted, None, Some(&after.title), None),
+      (Some(_), None) => self.record_event(id, EventKind::Restored, None, None, Some(&after.title)),
+      _ => Ok(()),
+    }
+  }
+
+  /// 할일의 태그 목록을 통째로 교체
+  fn set_tags(&self, todo_id: i64, tags: &[String]) -> Result<()> {
+    self.conn.execute("DELETE FROM todo_tags WHERE todo_id = ?1", params![todo_id])?;
+
+    for tag in tags {
+      self.conn.execute("INSERT OR IGNORE INTO tags (name) VALUES (?1)", params![tag])?;
+      self.conn.execute(
+        "INSERT OR IGNORE INTO todo_tags (todo_id, tag_id)
+          SELECT ?1, id FROM tags WHERE name = ?2",
+        params![todo_id, tag],
+      )?;
+    }
+
+    Ok(())
+  }
+
+  fn get_tag_stats(&self, scope: &str, scope_params: &[&dyn rusqlite::ToSql]) -> Result<Vec<TagStats>> {
+    let mut stmt = self.conn.prepare(&format!(
+      "SELECT t.name, COUNT(*),
+              SUM(CASE WHEN todos.status = 0 THEN 1 ELSE 0 END),
+              SUM(CASE WHEN todos.status = 1 THEN 1 ELSE 0 END)
+        FROM tags t
+        JOIN todo_tags tt ON tt.tag_id = t.id
+        JOIN todos ON todos.id = tt.todo_id
+        WHERE {}
+        GROUP BY t.name
+        ORDER BY COUNT(*) DESC, t.name ASC", scope
+    ))?;
+
+    let stats = stmt
+      .query_map(scope_params, |row| {
+        Ok(TagStats {
+          tag: row.get(0)?,
+          total_todos: row.get(1)?,
+          pending_todos: row.get(2)?,
+          completed_todos: row.get(3)?,
+        })
+      })?
+      .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    Ok(stats)
+  }
+
+  fn build_filter_clause(&self, filter: &TodoFilter) -> (String, Vec<Box<dyn rusqlite::ToSql>>) {
 
     let mut conditions = Vec::new();
     let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
 
+    if filter.trashed {
+      conditions.push("deleted_at IS NOT NULL".to_string());
+    } else {
+      conditions.push("deleted_at IS NULL".to_string());
+    }
+
+    if !filter.include_archived {
+      conditions.push("archived_at IS NULL".to_string());
+    }
+
+    if let Some(completed_before) = filter.completed_before {
+      conditions.push("status = 1 AND COALESCE(
+        (SELECT MAX(e.created_at) FROM todo_events e WHERE e.todo_id = todos.id AND e.kind = 'completed'),
+        updated_at) < ?".to_string());
+      params.push(Box::new(completed_before));
+    }
+
     if let Some(status) = filter.status {
       conditions.push("status = ?".to_string());
       params.push(Box::new(status as i32));
@@ -82,13 +329,124 @@ impl<'a> SqliteTodoRepository<'a> {
       params.push(Box::new(due_after));
     }
 
-    let where_clause = if conditions.is_empty() {
-      String::new()
-    } else {
-      format!("WHERE {}", conditions.join(" AND "))
-    };
+    for tag in &filter.tags {
+      conditions.push(format!("id IN ({})", TAGGED_TODO_IDS));
+      params.push(Box::new(tag.clone()));
+    }
+
+    for tag in &filter.without_tags {
+      conditions.push(format!("id NOT IN ({})", TAGGED_TODO_IDS));
+      params.push(Box::new(tag.clone()));
+    }
+
+    if let Some(project) = &filter.project {
+      conditions.push(format!("project_id IN ({})", PROJECT_SCOPE_IDS));
+      params.extend(Self::project_scope_params(project));
+    }
+
+    if filter.overdue {
+      let cutoff = OverdueCutoff::at(self.clock);
+      conditions.push(OverdueCutoff::SQL.to_string());
+      params.push(Box::new(cutoff.today_start));
+      params.push(Box::new(cutoff.now));
+    }
+
+    if let Some(query) = &filter.text {
+      for term in &query.terms {
+        let operator = if term.excluded { "NOT IN" } else { "IN" };
+        if term.uses_index() {
+          conditions.push(format!("id {} ({})", operator, FTS_MATCH_IDS));
+          params.push(Box::new(term.fts_phrase()));
+        } else {
+          conditions.push(format!("id {} ({})", operator, FTS_LIKE_IDS));
+          params.push(Box::new(term.like_pattern()));
+          params.push(Box::new(term.like_pattern()));
+        }
+      }
+    }
+
+    if let Some(expression) = &filter.expression {
+      conditions.push(self.compile_expression(expression, &mut params));
+    }
+
+    match filter.blocked {
+      Some(true) => conditions.push(format!("id IN ({})", BLOCKED_TODO_IDS)),
+      Some(false) => conditions.push(format!("status = 0 AND id NOT IN ({})", BLOCKED_TODO_IDS)),
+      None => {}
+    }
+
+    (format!("WHERE {}", conditions.join(" AND ")), params)
+  }
 
-    (where_clause, params)
+  /// 필터 식을 SQL 조건으로 변환. NOT이 기대대로 동작하도록 각 조건은 NULL이 되지 않게 만든다
+  fn compile_expression(&self, expr: &FilterExpr, params: &mut Vec<Box<dyn rusqlite::ToSql>>) -> String {
+    match expr {
+      FilterExpr::Condition(condition) => self.compile_condition(condition, params),
+      FilterExpr::Not(inner) => format!("NOT {}", self.compile_expression(inner, params)),
+      FilterExpr::And(left, right) => {
+        let left = self.compile_expression(left, params);
+        let right = self.compile_expression(right, params);
+        format!("({} AND {})", left, right)
+      }
+      FilterExpr::Or(left, right) => {
+        let left = self.compile_expression(left, params);
+        let right = self.compile_expression(right, params);
+        format!("({} OR {})", left, right)
+      }
+    }
+  }
+
+  fn compile_condition(&self, condition: &FilterCondition, params: &mut Vec<Box<dyn rusqlite::ToSql>>) -> String {
+    match condition {
+      FilterCondition::Status(status) => {
+        params.push(Box::new(*status as i32));
+        "(status = ?)".to_string()
+      }
+      FilterCondition::Priority(comparison, priority) => {
+        params.push(Box::new(*priority as i32));
+        format!("(priority {} ?)", comparison.to_sql())
+      }
+      FilterCondition::Date(field, comparison, value) => {
+        let column = field.column();
+        let mut parts = vec![format!("{} IS NOT NULL", column)];
+        for (operator, at) in value.bounds(*comparison) {
+          parts.push(format!("{} {} ?", column, operator));
+          params.push(Box::new(at));
+        }
+        format!("({})", parts.join(" AND "))
+      }
+      FilterCondition::NoDueDate => "(due_date IS NULL)".to_string(),
+      FilterCondition::Tag(tag) => {
+        params.push(Box::new(tag.clone()));
+        format!("(id IN ({}))", TAGGED_TODO_IDS)
+      }
+      FilterCondition::Project(project) => {
+        params.extend(Self::project_scope_params(project));
+        format!("(project_id IS NOT NULL AND project_id IN ({}))", PROJECT_SCOPE_IDS)
+      }
+      FilterCondition::Text(term) => {
+        if term.uses_index() {
+          params.push(Box::new(term.fts_phrase()));
+          format!("(id IN ({}))", FTS_MATCH_IDS)
+        } else {
+          params.push(Box::new(term.like_pattern()));
+          params.push(Box::new(term.like_pattern()));
+          format!("(id IN ({}))", FTS_LIKE_IDS)
+        }
+      }
+      FilterCondition::Is(TodoState::Overdue) => {
+        let cutoff = OverdueCutoff::at(self.clock);
+        params.push(Box::new(cutoff.today_start));
+        params.push(Box::new(cutoff.now));
+        OverdueCutoff::SQL.to_string()
+      }
+      FilterCondition::Is(TodoState::Blocked) => format!("(id IN ({}))", BLOCKED_TODO_IDS),
+      FilterCondition::Is(TodoState::Ready) => format!("(status = 0 AND id NOT IN ({}))", BLOCKED_TODO_IDS),
+      FilterCondition::Is(TodoState::Recurring) => {
+        "(series_id IS NOT NULL AND series_id IN (SELECT id FROM recurrences WHERE ended_at IS NULL))".to_string()
+      }
+      FilterCondition::Is(TodoState::Subtask) => "(parent_id IS NOT NULL)".to_string(),
+    }
   }
 
   fn build_order_clause(sort_by: SortBy, sort_order: SortOrder) -> String {
@@ -111,11 +469,33 @@ impl<'a> SqliteTodoRepository<'a> {
 
 impl<'a> TodoRepository for SqliteTodoRepository<'a> {
   fn create(&self, todo: &CreateTodo) -> Result<Todo> {
-    let now = Utc::now();
+    let now = self.clock.now();
+    let project_id = self.resolve_project_id(todo.project.as_ref())?;
+
+    // 새 반복 규칙이면 시리즈부터 생성
+    let series_id = match &todo.recurrence {
+      Some(rule) => {
+        self.conn.execute(
+          "INSERT INTO recurrences (rule, created_at) VALUES (?1, ?2)",
+          params![rule.to_string(), now],
+        )?;
+        Some(self.conn.last_insert_rowid())
+      }
+      None => todo.series_id,
+    };
+    let recurrence = match series_id {
+      Some(series_id) => self.conn.query_row(
+        "SELECT rule FROM recurrences WHERE id = ?1 AND ended_at IS NULL",
+        params![series_id],
+        |row| row.get(0),
+      ).optional()?,
+      None => None,
+    };
 
+    let uuid = Uuid::new_v4().to_string();
     self.conn.execute(
-        "INSERT INTO todos (title, description, priority, status, created_at, updated_at, due_date)
-          VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        "INSERT INTO todos (title, description, priority, status, created_at, updated_at, due_date, project_id, parent_id, series_id, due_all_day, uuid)
+          VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
         params![
             todo.title,
             todo.description,
@@ -124,10 +504,21 @@ impl<'a> TodoRepository for SqliteTodoRepository<'a> {
             now,
             now,
             todo.due_date,
+            project_id,
+            todo.parent_id,
+            series_id,
+            todo.due_all_day,
+            uuid,
         ],
     )?;
 
     let id = self.conn.last_insert_rowid();
+    self.set_tags(id, &todo.tags)?;
+    self.record_event(id, EventKind::Created, None, None, Some(&todo.title))?;
+
+    let mut tags = todo.tags.clone();
+    tags.sort();
+
     Ok(Todo {
       id: Some(id),
       title: todo.title.clone(),
@@ -137,14 +528,21 @@ impl<'a> TodoRepository for SqliteTodoRepository<'a> {
       created_at: now,
       updated_at: now,
       due_date: todo.due_date,
+      due_all_day: todo.due_all_day,
+      tags,
+      project: todo.project.clone(),
+      parent_id: todo.parent_id,
+      series_id,
+      recurrence,
+      deleted_at: None,
+      archived_at: None,
+      uuid,
     })
   }
 
   fn find_by_id(&self, id: i64) -> Result<Option<Todo>> {
-    let mut stmt = self.conn.prepare(
-      "SELECT id, title, description, priority, status, created_at, updated_at, due_date 
-            FROM todos WHERE id = ?1"
-    )?;
+    let query = format!("SELECT {} FROM todos WHERE id = ?1 AND deleted_at IS NULL", todo_columns("main"));
+    let mut stmt = self.conn.prepare(&query)?;
 
     stmt.query_row(params![id], Self::row_to_todo)
         .optional()
@@ -152,29 +550,67 @@ impl<'a> TodoRepository for SqliteTodoRepository<'a> {
   }
 
   fn find_all(&self, filter: &TodoFilter, sort_by: SortBy, sort_order: SortOrder) -> Result<Vec<Todo>> {
-    let (where_clause, params) = Self::build_filter_clause(filter);
+    let (where_clause, params) = self.build_filter_clause(filter);
     let order_clause = Self::build_order_clause(sort_by, sort_order);
 
     let query = format!(
-      "SELECT id, title, description, priority, status, created_at, updated_at, due_date 
-            FROM todos {} {}", where_clause, order_clause
+      "SELECT {} FROM todos {} {}", todo_columns("main"), where_clause, order_clause
     );
 
     let mut stmt = self.conn.prepare(&query)?;
     let param_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
 
-    let todos = stmt
+    let mut todos = stmt
         .query_map(&param_refs[..], Self::row_to_todo)?
         .collect::<rusqlite::Result<Vec<_>>>()?;
 
+    // 색인은 부분 문자열로 찾으므로 접두 검색어(단어 시작)는 여기서 한 번 더 거름
+    if let Some(query) = &filter.text {
+      todos.retain(|todo| query.matches(todo));
+    }
+
     Ok(todos)
   }
 
+  /// 검색어와 필터를 모두 만족하는 할일을 관련도 순으로. 제목 일치가 설명 일치보다 앞섬
+  fn search(&self, query: &SearchQuery, filter: &TodoFilter, limit: usize) -> Result<Vec<Todo>> {
+    let mut filter = filter.clone();
+    filter.text = Some(query.clone());
+    let (where_clause, filter_params) = self.build_filter_clause(&filter);
+
+    let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+    let rank = match query.match_expression() {
+      Some(expression) => {
+        params.push(Box::new(expression));
+        "(SELECT bm25(todos_fts, 10.0, 1.0) FROM todos_fts WHERE todos_fts MATCH ? AND rowid = todos.id)"
+      }
+      // 짧은 검색어만 있으면 순위 없이 최근 수정 순
+      None => "0.0",
+    };
+    params.extend(filter_params);
+
+    let query_sql = format!(
+      "SELECT {}, {} AS rank FROM todos {} ORDER BY rank ASC, updated_at DESC",
+      todo_columns("main"), rank, where_clause
+    );
+
+    let mut stmt = self.conn.prepare(&query_sql)?;
+    let param_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
+
+    let todos = stmt
+        .query_map(&param_refs[..], Self::row_to_todo)?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    Ok(todos.into_iter().filter(|todo| query.matches(todo)).take(limit).collect())
+  }
+
   fn update(&self, id: i64, todo: &UpdateTodo) -> Result<Option<Todo>> {
       
     let existing = self.find_by_id(id)?;
 
     if let Some(mut existing_todo) = existing {
+      let before = existing_todo.clone();
+
       if let Some(title) = &todo.title {
         existing_todo.title = title.clone();
       }
@@ -187,16 +623,34 @@ impl<'a> TodoRepository for SqliteTodoRepository<'a> {
       if let Some(due_date) = todo.due_date {
         existing_todo.due_date = Some(due_date);
       }
+      if let Some(due_all_day) = todo.due_all_day {
+        existing_todo.due_all_day = due_all_day;
+      }
       if let Some(status) = todo.status {
         existing_todo.status = status;
       }
+      if let Some(tags) = &todo.tags {
+        let mut tags = tags.clone();
+        tags.sort();
+        existing_todo.tags = tags;
+      }
+      if let Some(project) = &todo.project {
+        existing_todo.project = project.clone();
+      }
+      match todo.archived {
+        Some(true) if existing_todo.archived_at.is_none() => existing_todo.archived_at = Some(self.clock.now()),
+        Some(false) => existing_todo.archived_at = None,
+        _ => {}
+      }
+      let project_id = self.resolve_project_id(existing_todo.project.as_ref())?;
 
-      existing_todo.updated_at = Utc::now();
+      existing_todo.updated_at = self.clock.now();
 
       // 데이터베이스 업데이트
       self.conn.execute(
-        "UPDATE todos SET title = ?1, description = ?2, priority = ?3, 
-          status = ?4, due_date = ?5, updated_at = ?6 WHERE id = ?7",
+        "UPDATE todos SET title = ?1, description = ?2, priority = ?3,
+          status = ?4, due_date = ?5, updated_at = ?6, project_id = ?7, due_all_day = ?8, archived_at = ?9
+          WHERE id = ?10",
         params![
           existing_todo.title,
           existing_todo.description,
@@ -204,50 +658,156 @@ impl<'a> TodoRepository for SqliteTodoRepository<'a> {
           existing_todo.status as i32,
           existing_todo.due_date,
           existing_todo.updated_at,
+          project_id,
+          existing_todo.due_all_day,
+          existing_todo.archived_at,
           id
         ],
       )?;
 
+      if todo.tags.is_some() {
+        self.set_tags(id, &existing_todo.tags)?;
+      }
+
+      self.record_update_events(&before, &existing_todo)?;
+
       Ok(Some(existing_todo))
     } else {
       Ok(None)
     }
   }
 
+  /// 하위 할일과 함께 휴지통으로 옮김 (행은 purge 전까지 남음)
   fn delete(&self, id: i64) -> Result<bool> {
+    let Some(todo) = self.find_by_id(id)? else {
+      return Ok(false);
+    };
+
+    let mut deleted = vec![todo];
+    for descendant_id in self.find_descendant_ids(id)? {
+      deleted.extend(self.find_by_id(descendant_id)?);
+    }
+
+    let now = self.clock.now();
+    for todo in &deleted {
+      let todo_id = todo.id.unwrap_or(id);
+      self.conn.execute("UPDATE todos SET deleted_at = ?1 WHERE id = ?2", params![now, todo_id])?;
+      self.record_event(todo_id, EventKind::Deleted, None, Some(&todo.title), None)?;
+    }
+
+    Ok(true)
+  }
+
+  fn find_in_trash(&self, id: i64) -> Result<Option<Todo>> {
+    Ok(self.find_any(id)?.filter(|todo| todo.deleted_at.is_some()))
+  }
+
+  fn find_trashed_descendant_ids(&self, id: i64) -> Result<Vec<i64>> {
+    let mut stmt = self.conn.prepare(
+      "WITH RECURSIVE descendants(id) AS (
+          SELECT id FROM todos WHERE parent_id = ?1 AND deleted_at IS NOT NULL
+          UNION
+          SELECT t.id FROM todos t JOIN descendants d ON t.parent_id = d.id WHERE t.deleted_at IS NOT NULL
+        )
+        SELECT id FROM descendants"
+    )?;
+
+    let ids = stmt
+        .query_map(params![id], |row| row.get(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    Ok(ids)
+  }
+
+  /// 휴지통의 할일을 휴지통에 있는 하위 할일과 함께 되살림
+  fn restore_from_trash(&self, id: i64) -> Result<bool> {
+    let Some(todo) = self.find_in_trash(id)? else {
+      return Ok(false);
+    };
+
+    let mut restored = vec![todo];
+    for descendant_id in self.find_trashed_descendant_ids(id)? {
+      restored.extend(self.find_in_trash(descendant_id)?);
+    }
+
+    for todo in &restored {
+      let todo_id = todo.id.unwrap_or(id);
+      self.conn.execute("UPDATE todos SET deleted_at = NULL WHERE id = ?1", params![todo_id])?;
+      self.record_event(todo_id, EventKind::Restored, None, None, Some(&todo.title))?;
+    }
+
+    Ok(true)
+  }
+
+  /// 휴지통의 할일을 영구 삭제. 하위 할일, 태그, 의존성은 외래 키로 함께 삭제됨
+  fn purge(&self, id: i64) -> Result<bool> {
+    let Some(todo) = self.find_in_trash(id)? else {
+      return Ok(false);
+    };
+
+    let mut purged = vec![todo];
+    for descendant_id in self.find_trashed_descendant_ids(id)? {
+      purged.extend(self.find_in_trash(descendant_id)?);
+    }
+
     let affected = self.conn.execute("DELETE FROM todos WHERE id = ?1", params![id])?;
+
+    for todo in &purged {
+      self.record_event(todo.id.unwrap_or(id), EventKind::Purged, None, Some(&todo.title), None)?;
+    }
+
     Ok(affected > 0)
   }
 
-  fn get_stats(&self) -> Result<TodoStats> {
-    let total_todos: i64 = self.conn.query_row(
-      "SELECT COUNT(*) FROM todos",
-      [],
-      |row| row.get(0),
-    )?;
+  fn get_stats(&self, project: Option<&str>, include_archived: bool) -> Result<TodoStats> {
+    // 프로젝트가 지정되면 하위 프로젝트를 포함한 범위로 한정
+    let (scope, scope_params) = match project {
+      Some(name) => {
+        let exists: bool = self.conn.query_row(
+          "SELECT COUNT(*) > 0 FROM projects WHERE name = ?1",
+          params![name],
+          |row| row.get(0),
+        )?;
+        if !exists {
+          return Err(TaskyError::ProjectNotFound { name: name.to_string() });
+        }
+        (format!("project_id IN ({})", PROJECT_SCOPE_IDS), Self::project_scope_params(name))
+      }
+      None => ("1 = 1".to_string(), Vec::new()),
+    };
+    // 휴지통의 할일은 통계에서 제외
+    let scope = format!("{} AND todos.deleted_at IS NULL", scope);
+    let scope_refs: Vec<&dyn rusqlite::ToSql> = scope_params.iter().map(|p| p.as_ref()).collect();
 
-    let pending_todos: i64 = self.conn.query_row(
-      "SELECT COUNT(*) FROM todos WHERE status = 0",
-      [],
+    let archived_todos: i64 = self.conn.query_row(
+      &format!("SELECT COUNT(*) FROM todos WHERE {} AND archived_at IS NOT NULL", scope),
+      &scope_refs[..],
       |row| row.get(0),
     )?;
+    let scope = if include_archived {
+      scope
+    } else {
+      format!("{} AND todos.archived_at IS NULL", scope)
+    };
 
-    let completed_todos: i64 = self.conn.query_row(
-      "SELECT COUNT(*) FROM todos WHERE status = 1",
-      [],
-      |row| row.get(0),
-    )?;
+    let count = |condition: &str| -> Result<i64> {
+      let query = format!("SELECT COUNT(*) FROM todos WHERE {} AND {}", scope, condition);
+      Ok(self.conn.query_row(&query, &scope_refs[..], |row| row.get(0))?)
+    };
 
-    let high_priority_todos: i64 = self.conn.query_row(
-      "SELECT COUNT(*) FROM todos WHERE priority = 2",
-      [],
-      |row| row.get(0),
-    )?;
+    let total_todos = count("1 = 1")?;
+    let pending_todos = count("status = 0")?;
+    let completed_todos = count("status = 1")?;
+    let high_priority_todos = count("priority = 2")?;
 
-    let now = Utc::now();
+    // 목록의 기한 초과 표시와 같은 기준 사용
+    let cutoff = OverdueCutoff::at(self.clock);
+    let mut overdue_params = scope_refs.clone();
+    overdue_params.push(&cutoff.today_start);
+    overdue_params.push(&cutoff.now);
     let overdue_todos: i64 = self.conn.query_row(
-      "SELECT COUNT(*) FROM todos WHERE status = 0 AND due_date < ?1 AND due_date IS NOT NULL",
-      params![now],
+      &format!("SELECT COUNT(*) FROM todos WHERE {} AND {}", scope, OverdueCutoff::SQL),
+      &overdue_params[..],
       |row| row.get(0),
     )?;
 
@@ -264,20 +824,434 @@ impl<'a> TodoRepository for SqliteTodoRepository<'a> {
       high_priority_todos,
       overdue_todos,
       completion_rate,
+      archived_todos,
+      tag_stats: self.get_tag_stats(&scope, &scope_refs)?,
     })
   }
+
+  fn get_project_stats(&self, include_archived: bool) -> Result<Vec<ProjectStats>> {
+    // 각 프로젝트에 하위 프로젝트의 할일까지 합산
+    let archived = if include_archived { "" } else { " AND t.archived_at IS NULL" };
+    let mut stmt = self.conn.prepare(&format!(
+      "SELECT p.name,
+              COALESCE(SUM(CASE WHEN t.status = 0 THEN 1 ELSE 0 END), 0),
+              COALESCE(SUM(CASE WHEN t.status = 1 THEN 1 ELSE 0 END), 0)
+        FROM projects p
+        LEFT JOIN projects sub
+          ON sub.name = p.name OR substr(sub.name, 1, length(p.name) + 1) = p.name || '.'
+        LEFT JOIN todos t ON t.project_id = sub.id AND t.deleted_at IS NULL{}
+        GROUP BY p.name
+        ORDER BY p.name ASC",
+      archived
+    ))?;
+
+    let stats = stmt
+      .query_map([], |row| {
+        Ok(ProjectStats {
+          name: row.get(0)?,
+          pending_todos: row.get(1)?,
+          completed_todos: row.get(2)?,
+        })
+      })?
+      .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    Ok(stats)
+  }
+
+  fn find_children(&self, parent_id: i64) -> Result<Vec<Todo>> {
+    let query = format!(
+      "SELECT {} FROM todos WHERE parent_id = ?1 AND deleted_at IS NULL ORDER BY created_at ASC", todo_columns("main")
+    );
+    let mut stmt = self.conn.prepare(&query)?;
+
+    let todos = stmt
+        .query_map(params![parent_id], Self::row_to_todo)?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    Ok(todos)
+  }
+
+  fn find_descendant_ids(&self, id: i64) -> Result<Vec<i64>> {
+    let mut stmt = self.conn.prepare(
+      "WITH RECURSIVE descendants(id) AS (
+          SELECT id FROM todos WHERE parent_id = ?1 AND deleted_at IS NULL
+          UNION
+          SELECT t.id FROM todos t JOIN descendants d ON t.parent_id = d.id WHERE t.deleted_at IS NULL
+        )
+        SELECT id FROM descendants"
+    )?;
+
+    let ids = stmt
+        .query_map(params![id], |row| row.get(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    Ok(ids)
+  }
+
+  fn add_dependency(&self, todo_id: i64, depends_on_id: i64) -> Result<bool> {
+    let affected = self.conn.execute(
+      "INSERT OR IGNORE INTO todo_dependencies (todo_id, depends_on_id) VALUES (?1, ?2)",
+      params![todo_id, depends_on_id],
+    )?;
+    Ok(affected > 0)
+  }
+
+  fn remove_dependency(&self, todo_id: i64, depends_on_id: i64) -> Result<bool> {
+    let affected = self.conn.execute(
+      "DELETE FROM todo_dependencies WHERE todo_id = ?1 AND depends_on_id = ?2",
+      params![todo_id, depends_on_id],
+    )?;
+    Ok(affected > 0)
+  }
+
+  /// todo_id가 depends_on_id에 직접 또는 간접적으로 의존하는지 확인
+  fn depends_on(&self, todo_id: i64, depends_on_id: i64) -> Result<bool> {
+    let found = self.conn.query_row(
+      "WITH RECURSIVE upstream(id) AS (
+          SELECT depends_on_id FROM todo_dependencies WHERE todo_id = ?1
+          UNION
+          SELECT d.depends_on_id FROM todo_dependencies d JOIN upstream u ON d.todo_id = u.id
+        )
+        SELECT COUNT(*) > 0 FROM upstream WHERE id = ?2",
+      params![todo_id, depends_on_id],
+      |row| row.get(0),
+    )?;
+    Ok(found)
+  }
+
+  fn find_blockers(&self, todo_id: i64) -> Result<Vec<Todo>> {
+    let query = format!(
+      "SELECT {} FROM todos WHERE deleted_at IS NULL AND id IN
+        (SELECT depends_on_id FROM todo_dependencies WHERE todo_id = ?1) ORDER BY id ASC",
+      todo_columns("main")
+    );
+    let mut stmt = self.conn.prepare(&query)?;
+
+    let todos = stmt
+        .query_map(params![todo_id], Self::row_to_todo)?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    Ok(todos)
+  }
+
+  fn find_dependents(&self, todo_id: i64) -> Result<Vec<Todo>> {
+    let query = format!(
+      "SELECT {} FROM todos WHERE deleted_at IS NULL AND id IN
+        (SELECT todo_id FROM todo_dependencies WHERE depends_on_id = ?1) ORDER BY id ASC",
+      todo_columns("main")
+    );
+    let mut stmt = self.conn.prepare(&query)?;
+
+    let todos = stmt
+        .query_map(params![todo_id], Self::row_to_todo)?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    Ok(todos)
+  }
+
+  fn find_pending_in_series(&self, series_id: i64) -> Result<Option<Todo>> {
+    let query = format!(
+      "SELECT {} FROM todos WHERE series_id = ?1 AND status = 0 AND deleted_at IS NULL ORDER BY due_date ASC LIMIT 1",
+      todo_columns("main")
+    );
+    let mut stmt = self.conn.prepare(&query)?;
+
+    stmt.query_row(params![series_id], Self::row_to_todo)
+        .optional()
+        .map_err(|e| e.into())
+  }
+
+  fn list_series(&self) -> Result<Vec<RecurrenceSeries>> {
+    let mut stmt = self.conn.prepare(
+      "SELECT r.id, r.rule, r.created_at, r.ended_at,
+              COUNT(t.id),
+              COALESCE(SUM(CASE WHEN t.status = 1 THEN 1 ELSE 0 END), 0),
+              (SELECT title FROM todos WHERE series_id = r.id ORDER BY id DESC LIMIT 1),
+              (SELECT MIN(due_date) FROM todos WHERE series_id = r.id AND status = 0 AND deleted_at IS NULL)
+        FROM recurrences r
+        LEFT JOIN todos t ON t.series_id = r.id AND t.deleted_at IS NULL
+        GROUP BY r.id
+        ORDER BY r.ended_at IS NOT NULL, r.id ASC"
+    )?;
+
+    let series = stmt
+      .query_map([], |row| {
+        let ended_at: Option<chrono::DateTime<Utc>> = row.get(3)?;
+        Ok(RecurrenceSeries {
+          id: row.get(0)?,
+          rule: row.get(1)?,
+          created_at: row.get(2)?,
+          active: ended_at.is_none(),
+          ended_at,
+          total_todos: row.get(4)?,
+          completed_todos: row.get(5)?,
+          title: row.get::<_, Option<String>>(6)?.unwrap_or_default(),
+          next_due: row.get(7)?,
+        })
+      })?
+      .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    Ok(series)
+  }
+
+  fn end_series(&self, series_id: i64) -> Result<bool> {
+    let affected = self.conn.execute(
+      "UPDATE recurrences SET ended_at = ?1 WHERE id = ?2 AND ended_at IS NULL",
+      params![self.clock.now(), series_id],
+    )?;
+    Ok(affected > 0)
+  }
+
+  fn snapshot(&self, id: i64) -> Result<Option<TodoSnapshot>> {
+    let Some(todo) = self.find_any(id)? else {
+      return Ok(None);
+    };
+
+    Ok(Some(TodoSnapshot {
+      todo,
+      depends_on: self.dependency_ids(
+        "SELECT depends_on_id FROM todo_dependencies WHERE todo_id = ?1 ORDER BY depends_on_id", id,
+      )?,
+      dependents: self.dependency_ids(
+        "SELECT todo_id FROM todo_dependencies WHERE depends_on_id = ?1 ORDER BY todo_id", id,
+      )?,
+    }))
+  }
+
+  /// 스냅샷 상태로 되돌림. 행이 없으면 같은 ID로 다시 만들고, 의존성은 지금 남아있는 할일과의 관계만 복원
+  fn restore(&self, snapshot: &TodoSnapshot) -> Result<()> {
+    let todo = &snapshot.todo;
+    let id = todo.id.ok_or_else(|| TaskyError::InvalidInput { message: "ID가 없는 스냅샷입니다.".to_string() })?;
+    let before = self.find_any(id)?;
+    let project_id = self.resolve_project_id(todo.project.as_ref())?;
+
+    let values = params![
+      id,
+      todo.title,
+      todo.description,
+      todo.priority as i32,
+      todo.status as i32,
+      todo.created_at,
+      todo.updated_at,
+      todo.due_date,
+      project_id,
+      todo.parent_id,
+      todo.series_id,
+      todo.due_all_day,
+      todo.deleted_at,
+      todo.archived_at,
+      // UUID가 없는 예전 스냅샷이면 기존 UUID를 유지하거나 새로 만듦
+      Some(todo.uuid.clone()).filter(|uuid| !uuid.is_empty()),
+      Uuid::new_v4().to_string(),
+    ];
+
+    if before.is_some() {
+      self.conn.execute(
+        "UPDATE todos SET title = ?2, description = ?3, priority = ?4, status = ?5, created_at = ?6,
+          updated_at = ?7, due_date = ?8, project_id = ?9, parent_id = ?10, series_id = ?11, due_all_day = ?12,
+          deleted_at = ?13, archived_at = ?14, uuid = COALESCE(?15, uuid, ?16)
+          WHERE id = ?1",
+        values,
+      )?;
+    } else {
+      self.conn.execute(
+        "INSERT INTO todos (id, title, description, priority, status, created_at, updated_at, due_date,
+          project_id, parent_id, series_id, due_all_day, deleted_at, archived_at, uuid)
+          VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, COALESCE(?15, ?16))",
+        values,
+      )?;
+    }
+
+    self.set_tags(id, &todo.tags)?;
+
+    self.conn.execute(
+      "DELETE FROM todo_dependencies WHERE todo_id = ?1 OR depends_on_id = ?1",
+      params![id],
+    )?;
+    for depends_on_id in &snapshot.depends_on {
+      self.conn.execute(
+        "INSERT OR IGNORE INTO todo_dependencies (todo_id, depends_on_id) SELECT ?1, id FROM todos WHERE id = ?2",
+        params![id, depends_on_id],
+      )?;
+    }
+    for dependent_id in &snapshot.dependents {
+      self.conn.execute(
+        "INSERT OR IGNORE INTO todo_dependencies (todo_id, depends_on_id) SELECT id, ?1 FROM todos WHERE id = ?2",
+        params![id, dependent_id],
+      )?;
+    }
+
+    match before {
+      Some(before) => self.record_update_events(&before, todo),
+      None => self.record_event(id, EventKind::Created, None, None, Some(&todo.title)),
+    }
+  }
+
+  /// 모든 필드를 그대로 저장 (가져오기용). ID나 UUID가 없으면 새로 부여
+  fn insert(&self, todo: &Todo) -> Result<i64> {
+    let project_id = self.resolve_project_id(todo.project.as_ref())?;
+    let uuid = match todo.uuid.is_empty() {
+      true => Uuid::new_v4().to_string(),
+      false => todo.uuid.clone(),
+    };
+
+    self.conn.execute(
+      "INSERT INTO todos (id, title, description, priority, status, created_at, updated_at, due_date,
+        project_id, parent_id, series_id, due_all_day, deleted_at, archived_at, uuid)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)",
+      params![
+        todo.id,
+        todo.title,
+        todo.description,
+        todo.priority as i32,
+        todo.status as i32,
+        todo.created_at,
+        todo.updated_at,
+        todo.due_date,
+        project_id,
+        todo.parent_id,
+        todo.series_id,
+        todo.due_all_day,
+        todo.deleted_at,
+        todo.archived_at,
+        uuid,
+      ],
+    )?;
+
+    let id = self.conn.last_insert_rowid();
+    self.set_tags(id, &todo.tags)?;
+    self.record_event(id, EventKind::Created, None, None, Some(&todo.title))?;
+    Ok(id)
+  }
+
+  /// 시리즈를 가져옴. `keep_id`이면 같은 ID의 시리즈가 있을 때 그것을 그대로 씀
+  fn import_series(&self, series: &ExportedSeries, keep_id: bool) -> Result<i64> {
+    if keep_id {
+      let exists: bool = self.conn.query_row(
+        "SELECT COUNT(*) > 0 FROM recurrences WHERE id = ?1",
+        params![series.id],
+        |row| row.get(0),
+      )?;
+      if exists {
+        return Ok(series.id);
+      }
+    }
+
+    self.conn.execute(
+      "INSERT INTO recurrences (id, rule, created_at, ended_at) VALUES (?1, ?2, ?3, ?4)",
+      params![keep_id.then_some(series.id), series.rule, series.created_at, series.ended_at],
+    )?;
+    Ok(self.conn.last_insert_rowid())
+  }
+
+  /// UUID가 같은 할일 (휴지통 포함)
+  fn find_id_by_uuid(&self, uuid: &str) -> Result<Option<i64>> {
+    let id = self
+      .conn
+      .query_row("SELECT id FROM todos WHERE uuid = ?1", params![uuid], |row| row.get(0))
+      .optional()?;
+    Ok(id)
+  }
+
+  /// UUID가 `prefix`로 시작하는 할일 (휴지통 포함). `prefix`는 소문자 16진수와 `-`만
+  fn find_ids_by_uuid_prefix(&self, prefix: &str) -> Result<Vec<i64>> {
+    let mut stmt = self.conn.prepare("SELECT id FROM todos WHERE substr(uuid, 1, length(?1)) = ?1 ORDER BY id")?;
+    let ids = stmt
+      .query_map(params![prefix], |row| row.get(0))?
+      .collect::<rusqlite::Result<Vec<i64>>>()?;
+    Ok(ids)
+  }
+
+  /// 다음에 자동으로 부여될 ID (영구 삭제된 ID도 다시 쓰지 않음)
+  fn next_id(&self) -> Result<i64> {
+    let id = self.conn.query_row(
+      "SELECT MAX(COALESCE((SELECT seq FROM sqlite_sequence WHERE name = 'todos'), 0),
+        COALESCE((SELECT MAX(id) FROM todos), 0)) + 1",
+      [],
+      |row| row.get(0),
+    )?;
+    Ok(id)
+  }
+
+  fn find_events(&self, todo_id: Option<i64>, limit: usize) -> Result<Vec<TodoEvent>> {
+    let mut stmt = self.conn.prepare(
+      "SELECT id, todo_id, kind, field, old_value, new_value, actor, created_at
+        FROM todo_events
+        WHERE ?1 IS NULL OR todo_id = ?1
+        ORDER BY id DESC
+        LIMIT ?2",
+    )?;
+
+    let events = stmt
+      .query_map(params![todo_id, limit as i64], Self::row_to_event)?
+      .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    Ok(events)
+  }
 }
 
 #[cfg(test)]
+#[allow(clippy::field_reassign_with_default)]
 mod tests {
     use super::*;
     use crate::database::Database;
+    use crate::models::RecurrenceRule;
+
+    #[test]
+    fn test_mutations_are_recorded() {
+        let db = Database::in_memory().unwrap();
+        db.initialize().unwrap();
+        let repo = SqliteTodoRepository::new(db.conn(), db.clock());
+
+        let parent = repo.create(&CreateTodo::new("이사".to_string())).unwrap().id.unwrap();
+        let child = repo.create(&CreateTodo::new("짐 싸기".to_string()).with_parent(parent)).unwrap().id.unwrap();
+
+        let mut update = UpdateTodo::default();
+        update.priority = Some(Priority::High);
+        update.tags = Some(vec!["home".to_string()]);
+        repo.update(parent, &update).unwrap();
+
+        let mut update = UpdateTodo::default();
+        update.status = Some(Status::Done);
+        repo.update(child, &update).unwrap();
+        // 바뀐 것이 없으면 기록하지 않음
+        repo.update(child, &update).unwrap();
+
+        repo.delete(parent).unwrap();
+
+        let kinds = |id| -> Vec<(EventKind, Option<String>)> {
+            let mut events = repo.find_events(Some(id), 100).unwrap();
+            events.reverse();
+            events.into_iter().map(|e| (e.kind, e.field)).collect()
+        };
+
+        assert_eq!(kinds(parent), vec![
+            (EventKind::Created, None),
+            (EventKind::Updated, Some("priority".to_string())),
+            (EventKind::Updated, Some("tags".to_string())),
+            (EventKind::Deleted, None),
+        ]);
+        assert_eq!(kinds(child), vec![
+            (EventKind::Created, None),
+            (EventKind::Completed, None),
+            (EventKind::Deleted, None),
+        ]);
+
+        let latest = repo.find_events(None, 2).unwrap();
+        assert_eq!(latest.len(), 2);
+        assert!(latest.iter().all(|e| e.kind == EventKind::Deleted));
+
+        let priority = repo.find_events(Some(parent), 100).unwrap()
+            .into_iter().find(|e| e.field.as_deref() == Some("priority")).unwrap();
+        assert_eq!(priority.old_value.as_deref(), Some("보통"));
+        assert_eq!(priority.new_value.as_deref(), Some("높음"));
+    }
 
     #[test]
     fn test_create_and_find() {
         let db = Database::in_memory().unwrap();
         db.initialize().unwrap();
-        let repo = SqliteTodoRepository::new(db.conn());
+        let repo = SqliteTodoRepository::new(db.conn(), db.clock());
 
         let create_todo = CreateTodo::new("테스트 할일".to_string());
         let created = repo.create(&create_todo).unwrap();
@@ -294,7 +1268,7 @@ mod tests {
     fn test_update() {
         let db = Database::in_memory().unwrap();
         db.initialize().unwrap();
-        let repo = SqliteTodoRepository::new(db.conn());
+        let repo = SqliteTodoRepository::new(db.conn(), db.clock());
 
         let create_todo = CreateTodo::new("원래 제목".to_string());
         let created = repo.create(&create_todo).unwrap();
@@ -308,11 +1282,356 @@ mod tests {
         assert_eq!(updated.unwrap().title, "수정된 제목");
     }
 
+    #[test]
+    fn test_tags_and_filter() {
+        let db = Database::in_memory().unwrap();
+        db.initialize().unwrap();
+        let repo = SqliteTodoRepository::new(db.conn(), db.clock());
+
+        let work = repo.create(&CreateTodo::new("업무".to_string())
+            .with_tags(vec!["work".to_string(), "urgent".to_string()])).unwrap();
+        repo.create(&CreateTodo::new("개인".to_string())
+            .with_tags(vec!["home".to_string()])).unwrap();
+        repo.create(&CreateTodo::new("태그 없음".to_string())).unwrap();
+
+        let found = repo.find_by_id(work.id.unwrap()).unwrap().unwrap();
+        assert_eq!(found.tags, vec!["urgent".to_string(), "work".to_string()]);
+
+        let mut filter = TodoFilter::default();
+        filter.tags = vec!["work".to_string()];
+        let todos = repo.find_all(&filter, SortBy::default(), SortOrder::default()).unwrap();
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].title, "업무");
+
+        let mut filter = TodoFilter::default();
+        filter.without_tags = vec!["work".to_string()];
+        let todos = repo.find_all(&filter, SortBy::default(), SortOrder::default()).unwrap();
+        assert_eq!(todos.len(), 2);
+
+        let mut update = UpdateTodo::default();
+        update.tags = Some(vec!["home".to_string()]);
+        repo.update(work.id.unwrap(), &update).unwrap();
+
+        let stats = repo.get_stats(None, false).unwrap();
+        assert_eq!(stats.tag_stats.len(), 1);
+        assert_eq!(stats.tag_stats[0].tag, "home");
+        assert_eq!(stats.tag_stats[0].total_todos, 2);
+    }
+
+    #[test]
+    fn test_projects_and_scoped_stats() {
+        let db = Database::in_memory().unwrap();
+        db.initialize().unwrap();
+        let repo = SqliteTodoRepository::new(db.conn(), db.clock());
+
+        repo.create(&CreateTodo::new("API".to_string())
+            .with_project("work.backend".to_string())).unwrap();
+        let done = repo.create(&CreateTodo::new("회의".to_string())
+            .with_project("work".to_string())).unwrap();
+        repo.create(&CreateTodo::new("워크숍".to_string())
+            .with_project("workshop".to_string())).unwrap();
+        repo.create(&CreateTodo::new("미분류".to_string())).unwrap();
+
+        let mut update = UpdateTodo::default();
+        update.status = Some(Status::Done);
+        repo.update(done.id.unwrap(), &update).unwrap();
+
+        let mut filter = TodoFilter::default();
+        filter.project = Some("work".to_string());
+        let todos = repo.find_all(&filter, SortBy::default(), SortOrder::default()).unwrap();
+        assert_eq!(todos.len(), 2);
+
+        let stats = repo.get_stats(Some("work"), false).unwrap();
+        assert_eq!(stats.total_todos, 2);
+        assert_eq!(stats.completed_todos, 1);
+        assert!(repo.get_stats(Some("없는프로젝트"), false).is_err());
+
+        let projects = repo.get_project_stats(false).unwrap();
+        let names: Vec<&str> = projects.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["work", "work.backend", "workshop"]);
+        assert_eq!(projects[0].pending_todos, 1);
+        assert_eq!(projects[0].completed_todos, 1);
+
+        // 보관된 할일은 --archived 없이는 세지 않음
+        let mut update = UpdateTodo::default();
+        update.archived = Some(true);
+        repo.update(done.id.unwrap(), &update).unwrap();
+        let projects = repo.get_project_stats(false).unwrap();
+        assert_eq!(projects[0].completed_todos, 0);
+        let projects = repo.get_project_stats(true).unwrap();
+        assert_eq!(projects[0].completed_todos, 1);
+    }
+
+    #[test]
+    fn test_subtasks_cascade_on_delete() {
+        let db = Database::in_memory().unwrap();
+        db.initialize().unwrap();
+        let repo = SqliteTodoRepository::new(db.conn(), db.clock());
+
+        let parent = repo.create(&CreateTodo::new("상위".to_string())).unwrap();
+        let parent_id = parent.id.unwrap();
+        let child = repo.create(&CreateTodo::new("하위".to_string()).with_parent(parent_id)).unwrap();
+        let grandchild = repo.create(&CreateTodo::new("손자".to_string())
+            .with_parent(child.id.unwrap())).unwrap();
+
+        let children = repo.find_children(parent_id).unwrap();
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].parent_id, Some(parent_id));
+
+        let mut descendants = repo.find_descendant_ids(parent_id).unwrap();
+        descendants.sort();
+        assert_eq!(descendants, vec![child.id.unwrap(), grandchild.id.unwrap()]);
+
+        repo.delete(parent_id).unwrap();
+        assert!(repo.find_by_id(grandchild.id.unwrap()).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_trash_hides_todos() {
+        let db = Database::in_memory().unwrap();
+        db.initialize().unwrap();
+        let repo = SqliteTodoRepository::new(db.conn(), db.clock());
+
+        let parent = repo.create(&CreateTodo::new("상위".to_string())
+            .with_tags(vec!["home".to_string()])).unwrap().id.unwrap();
+        let child = repo.create(&CreateTodo::new("하위".to_string()).with_parent(parent)).unwrap().id.unwrap();
+        let blocked = repo.create(&CreateTodo::new("대기".to_string())).unwrap().id.unwrap();
+        repo.add_dependency(blocked, child).unwrap();
+
+        assert!(repo.delete(parent).unwrap());
+        assert!(!repo.delete(parent).unwrap());
+        assert!(repo.find_by_id(child).unwrap().is_none());
+        assert!(repo.find_in_trash(child).unwrap().unwrap().deleted_at.is_some());
+
+        let all = repo.find_all(&TodoFilter::default(), SortBy::default(), SortOrder::default()).unwrap();
+        assert_eq!(all.len(), 1);
+        let stats = repo.get_stats(None, false).unwrap();
+        assert_eq!(stats.total_todos, 1);
+        assert!(stats.tag_stats.is_empty());
+
+        // 휴지통의 선행 할일은 막지 않음
+        let mut filter = TodoFilter::default();
+        filter.blocked = Some(false);
+        assert_eq!(repo.find_all(&filter, SortBy::default(), SortOrder::default()).unwrap().len(), 1);
+
+        let mut filter = TodoFilter::default();
+        filter.trashed = true;
+        assert_eq!(repo.find_all(&filter, SortBy::default(), SortOrder::default()).unwrap().len(), 2);
+
+        assert!(repo.restore_from_trash(parent).unwrap());
+        assert!(repo.find_by_id(child).unwrap().is_some());
+        assert_eq!(repo.find_blockers(blocked).unwrap()[0].id, Some(child));
+
+        // 영구 삭제는 휴지통에 있는 할일만
+        assert!(!repo.purge(parent).unwrap());
+        repo.delete(parent).unwrap();
+        assert!(repo.purge(parent).unwrap());
+        assert!(repo.find_in_trash(child).unwrap().is_none());
+        assert!(repo.snapshot(blocked).unwrap().unwrap().depends_on.is_empty());
+
+        let kinds: Vec<EventKind> = repo.find_events(Some(child), 100).unwrap().into_iter().map(|e| e.kind).collect();
+        assert_eq!(kinds, vec![
+            EventKind::Purged, EventKind::Deleted, EventKind::Restored, EventKind::Deleted, EventKind::Created,
+        ]);
+    }
+
+    #[test]
+    fn test_filter_expression() {
+        let clock = std::sync::Arc::new(crate::utils::FixedClock::parse("2026-10-18 09:00").unwrap());
+        let db = Database::in_memory().unwrap().with_clock(clock.clone());
+        db.initialize().unwrap();
+        let repo = SqliteTodoRepository::new(db.conn(), db.clock());
+
+        let soon = repo.create(&CreateTodo::new("보고서 제출".to_string())
+            .with_priority(Priority::High)
+            .with_due_date(clock.today_start() + chrono::Duration::days(3))).unwrap().id.unwrap();
+        let work = repo.create(&CreateTodo::new("코드 리뷰".to_string())
+            .with_tags(vec!["work".to_string()])
+            .with_project("회사.개발".to_string())).unwrap().id.unwrap();
+        let later = repo.create(&CreateTodo::new("여행 계획".to_string())
+            .with_priority(Priority::Low)
+            .with_due_date(clock.today_start() + chrono::Duration::days(30))).unwrap().id.unwrap();
+        let done = repo.create(&CreateTodo::new("장보기".to_string())
+            .with_priority(Priority::High)).unwrap().id.unwrap();
+        let mut update = UpdateTodo::default();
+        update.status = Some(Status::Done);
+        repo.update(done, &update).unwrap();
+
+        let ids = |source: &str| -> Vec<i64> {
+            let mut filter = TodoFilter::default();
+            filter.expression = Some(FilterExpr::parse(source, &*clock).unwrap());
+            let mut ids: Vec<i64> = repo.find_all(&filter, SortBy::default(), SortOrder::default()).unwrap()
+                .into_iter().filter_map(|t| t.id).collect();
+            ids.sort();
+            ids
+        };
+
+        assert_eq!(ids("priority>=medium and (due<+7d or tag:work) and not status:done"), vec![soon, work]);
+        assert_eq!(ids("priority<medium"), vec![later]);
+        // 마감일이 없는 할일도 부정 조건에 포함
+        assert_eq!(ids("not due<+7d"), vec![work, later, done]);
+        assert_eq!(ids("due:none"), vec![work, done]);
+        assert_eq!(ids("project:회사 or text:여행"), vec![work, later]);
+        assert_eq!(ids("tag!=work status:pending"), vec![soon, later]);
+        assert_eq!(ids("created:today and due>+2w"), vec![later]);
+    }
+
+    #[test]
+    fn test_full_text_search() {
+        let db = Database::in_memory().unwrap();
+        db.initialize().unwrap();
+        let repo = SqliteTodoRepository::new(db.conn(), db.clock());
+
+        let in_description = repo.create(&CreateTodo::new("체크리스트 검토".to_string())
+            .with_description("배포 전 회의록 확인".to_string())).unwrap().id.unwrap();
+        let in_title = repo.create(&CreateTodo::new("주간 회의록 작성".to_string())).unwrap().id.unwrap();
+        let draft = repo.create(&CreateTodo::new("회의록 초안".to_string())
+            .with_tags(vec!["draft".to_string()])).unwrap().id.unwrap();
+
+        let search = |query: &str| -> Vec<i64> {
+            let query = SearchQuery::parse(query).unwrap();
+            repo.search(&query, &TodoFilter::default(), 10).unwrap().into_iter().filter_map(|t| t.id).collect()
+        };
+
+        // 제목 일치가 설명 일치보다 앞섬
+        let found = search("회의록");
+        assert_eq!(found.len(), 3);
+        assert_eq!(found.last(), Some(&in_description));
+        assert_eq!(search("회의록 -초안").len(), 2);
+        // 색인 최소 길이(3자)보다 짧은 검색어
+        assert_eq!(search("배포"), vec![in_description]);
+
+        // 수정/삭제가 색인에 반영됨
+        let mut update = UpdateTodo::default();
+        update.title = Some("월간 보고서 작성".to_string());
+        repo.update(in_title, &update).unwrap();
+        assert_eq!(search("회의록").len(), 2);
+        assert_eq!(search("보고서"), vec![in_title]);
+
+        repo.delete(draft).unwrap();
+        assert_eq!(search("초안"), Vec::<i64>::new());
+        repo.purge(draft).unwrap();
+        let indexed: i64 = db.conn().query_row(
+            "SELECT COUNT(*) FROM todos_fts WHERE todos_fts MATCH '\"회의록\"'", [], |row| row.get(0)
+        ).unwrap();
+        assert_eq!(indexed, 1);
+
+        // 목록 필터와 함께 사용
+        let mut filter = TodoFilter::default();
+        filter.text = Some(SearchQuery::parse("작성").unwrap());
+        filter.status = Some(Status::Pending);
+        let todos = repo.find_all(&filter, SortBy::default(), SortOrder::default()).unwrap();
+        assert_eq!(todos.len(), 1);
+        assert_eq!(todos[0].id, Some(in_title));
+    }
+
+    #[test]
+    fn test_dependencies_and_blocked_filter() {
+        let db = Database::in_memory().unwrap();
+        db.initialize().unwrap();
+        let repo = SqliteTodoRepository::new(db.conn(), db.clock());
+
+        let a = repo.create(&CreateTodo::new("A".to_string())).unwrap().id.unwrap();
+        let b = repo.create(&CreateTodo::new("B".to_string())).unwrap().id.unwrap();
+        let c = repo.create(&CreateTodo::new("C".to_string())).unwrap().id.unwrap();
+
+        // C -> B -> A
+        assert!(repo.add_dependency(b, a).unwrap());
+        assert!(repo.add_dependency(c, b).unwrap());
+        assert!(!repo.add_dependency(c, b).unwrap());
+        assert!(repo.depends_on(c, a).unwrap());
+        assert!(!repo.depends_on(a, c).unwrap());
+
+        assert_eq!(repo.find_blockers(b).unwrap()[0].id, Some(a));
+        assert_eq!(repo.find_dependents(b).unwrap()[0].id, Some(c));
+
+        let mut filter = TodoFilter::default();
+        filter.blocked = Some(false);
+        let ready = repo.find_all(&filter, SortBy::default(), SortOrder::default()).unwrap();
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].id, Some(a));
+
+        // A를 완료하면 B가 시작 가능
+        let mut update = UpdateTodo::default();
+        update.status = Some(Status::Done);
+        repo.update(a, &update).unwrap();
+
+        filter.blocked = Some(true);
+        let blocked = repo.find_all(&filter, SortBy::default(), SortOrder::default()).unwrap();
+        assert_eq!(blocked.len(), 1);
+        assert_eq!(blocked[0].id, Some(c));
+
+        assert!(repo.remove_dependency(c, b).unwrap());
+        assert!(repo.find_blockers(c).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_recurrence_series() {
+        let db = Database::in_memory().unwrap();
+        db.initialize().unwrap();
+        let repo = SqliteTodoRepository::new(db.conn(), db.clock());
+
+        let first = repo.create(&CreateTodo::new("청소".to_string())
+            .with_recurrence(RecurrenceRule::Days(1))).unwrap();
+        let series_id = first.series_id.unwrap();
+        assert_eq!(first.recurrence.as_deref(), Some("daily"));
+
+        let pending = repo.find_pending_in_series(series_id).unwrap().unwrap();
+        assert_eq!(pending.id, first.id);
+
+        let series = repo.list_series().unwrap();
+        assert_eq!(series.len(), 1);
+        assert!(series[0].active);
+        assert_eq!(series[0].title, "청소");
+
+        assert!(repo.end_series(series_id).unwrap());
+        assert!(!repo.end_series(series_id).unwrap());
+        let found = repo.find_by_id(first.id.unwrap()).unwrap().unwrap();
+        assert_eq!(found.recurrence, None);
+        assert_eq!(found.series_id, Some(series_id));
+    }
+
+    #[test]
+    fn test_overdue_matches_model() {
+        // 자정 직전 고정 시각
+        let clock = std::sync::Arc::new(crate::utils::FixedClock::parse("2026-10-15 23:59:30").unwrap());
+        let db = Database::in_memory().unwrap().with_clock(clock.clone());
+        db.initialize().unwrap();
+        let repo = SqliteTodoRepository::new(db.conn(), db.clock());
+
+        let today_start = clock.today_start();
+        let now = clock.now();
+
+        // 오늘 종일 마감: 아직 기한 초과 아님
+        repo.create(&CreateTodo::new("오늘 종일".to_string()).with_due_date(today_start)).unwrap();
+        // 어제 종일 마감: 기한 초과
+        repo.create(&CreateTodo::new("어제 종일".to_string())
+            .with_due_date(today_start - chrono::Duration::days(1))).unwrap();
+        // 1분 전 시각 마감: 기한 초과
+        repo.create(&CreateTodo::new("방금 지남".to_string())
+            .with_due_time(now - chrono::Duration::minutes(1))).unwrap();
+        // 1시간 후 시각 마감: 기한 초과 아님
+        repo.create(&CreateTodo::new("곧 마감".to_string())
+            .with_due_time(now + chrono::Duration::hours(1))).unwrap();
+
+        let all = repo.find_all(&TodoFilter::default(), SortBy::default(), SortOrder::default()).unwrap();
+        let model_overdue = all.iter().filter(|t| t.is_overdue(db.clock())).count();
+
+        let mut filter = TodoFilter::default();
+        filter.overdue = true;
+        let filtered = repo.find_all(&filter, SortBy::default(), SortOrder::default()).unwrap();
+
+        let stats = repo.get_stats(None, false).unwrap();
+        assert_eq!(model_overdue, 2);
+        assert_eq!(filtered.len(), 2);
+        assert_eq!(stats.overdue_todos, 2);
+    }
+
     #[test]
     fn test_delete() {
         let db = Database::in_memory().unwrap();
         db.initialize().unwrap();
-        let repo = SqliteTodoRepository::new(db.conn());
+        let repo = SqliteTodoRepository::new(db.conn(), db.clock());
 
         let create_todo = CreateTodo::new("삭제될 할일".to_string());
         let created = repo.create(&create_todo).unwrap();
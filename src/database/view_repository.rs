@@ -0,0 +1,79 @@
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::error::{Result, TaskyError};
+use crate::models::{SavedView, ViewDefinition};
+use crate::utils::Clock;
+
+pub trait ViewRepository {
+  /// 저장. 같은 이름이 있으면 덮어쓰고 false, 새로 만들었으면 true
+  fn save(&self, name: &str, definition: &ViewDefinition) -> Result<bool>;
+  fn find_by_name(&self, name: &str) -> Result<Option<SavedView>>;
+  fn find_all(&self) -> Result<Vec<SavedView>>;
+  fn delete(&self, name: &str) -> Result<bool>;
+}
+
+pub struct SqliteViewRepository<'a> {
+  conn: &'a Connection,
+  clock: &'a dyn Clock,
+}
+
+impl<'a> SqliteViewRepository<'a> {
+  pub fn new(conn: &'a Connection, clock: &'a dyn Clock) -> Self {
+    Self { conn, clock }
+  }
+
+  fn to_view(name: String, definition: String, updated_at: DateTime<Utc>) -> Result<SavedView> {
+    let definition = serde_json::from_str(&definition).map_err(|e| TaskyError::System(e.into()))?;
+    Ok(SavedView { name, definition, updated_at: Some(updated_at) })
+  }
+}
+
+impl<'a> ViewRepository for SqliteViewRepository<'a> {
+  fn save(&self, name: &str, definition: &ViewDefinition) -> Result<bool> {
+    let json = serde_json::to_string(definition).map_err(|e| TaskyError::System(e.into()))?;
+    let now = self.clock.now();
+
+    let updated = self.conn.execute(
+      "UPDATE views SET definition = ?1, updated_at = ?2 WHERE name = ?3",
+      params![json, now, name],
+    )?;
+    if updated > 0 {
+      return Ok(false);
+    }
+
+    self.conn.execute(
+      "INSERT INTO views (name, definition, created_at, updated_at) VALUES (?1, ?2, ?3, ?3)",
+      params![name, json, now],
+    )?;
+    Ok(true)
+  }
+
+  fn find_by_name(&self, name: &str) -> Result<Option<SavedView>> {
+    let row = self.conn.query_row(
+      "SELECT name, definition, updated_at FROM views WHERE name = ?1",
+      params![name],
+      |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+    ).optional()?;
+
+    row.map(|(name, definition, updated_at)| Self::to_view(name, definition, updated_at)).transpose()
+  }
+
+  fn find_all(&self) -> Result<Vec<SavedView>> {
+    let mut stmt = self.conn.prepare("SELECT name, definition, updated_at FROM views ORDER BY name")?;
+
+    let rows = stmt
+      .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+      .collect::<rusqlite::Result<Vec<(String, String, DateTime<Utc>)>>>()?;
+
+    rows
+      .into_iter()
+      .map(|(name, definition, updated_at)| Self::to_view(name, definition, updated_at))
+      .collect()
+  }
+
+  fn delete(&self, name: &str) -> Result<bool> {
+    let deleted = self.conn.execute("DELETE FROM views WHERE name = ?1", params![name])?;
+    Ok(deleted > 0)
+  }
+}
@@ -8,6 +8,24 @@ pub enum TaskyError {
     #[error("할일을 찾을 수 없습니다 (ID: {id})")]
     TodoNotFound { id: i64 },
 
+    #[error("UUID가 {prefix}(으)로 시작하는 할일을 찾을 수 없습니다")]
+    UuidPrefixNotFound { prefix: String },
+
+    #[error("UUID가 {prefix}(으)로 시작하는 할일이 {count}개 있습니다. 더 길게 입력하세요")]
+    AmbiguousUuidPrefix { prefix: String, count: usize },
+
+    #[error("완료되지 않은 하위 할일이 {count}개 있습니다 (ID: {id}). --cascade 옵션으로 함께 완료할 수 있습니다")]
+    PendingSubtasks { id: i64, count: usize },
+
+    #[error("순환 의존성이 생깁니다: {on}번 할일이 이미 {id}번 할일에 (간접적으로) 의존합니다")]
+    DependencyCycle { id: i64, on: i64 },
+
+    #[error("프로젝트를 찾을 수 없습니다: {name}")]
+    ProjectNotFound { name: String },
+
+    #[error("보기를 찾을 수 없습니다: {name}")]
+    ViewNotFound { name: String },
+
     #[error("잘못된 우선순위: {priority}. low, medium, high 중 하나여야 합니다")]
     InvalidPriority { priority: String },
 
@@ -17,12 +35,30 @@ pub enum TaskyError {
     #[error("잘못된 날짜 형식: {date}. 지원되는 형식: YYYY-MM-DD, YYYY/MM/DD, MM/DD/YYYY, DD/MM/YYYY, Dec 31, 2024, 31 Dec 2024")]
     InvalidDateFormat { date: String },
 
+    #[error("잘못된 반복 규칙: {rule}. 예: daily, weekly, weekly:mon,thu, monthly, monthly:15, monthly:last, every 3 days")]
+    InvalidRecurrence { rule: String },
+
+    #[error("반복 시리즈를 찾을 수 없습니다 (ID: {id})")]
+    SeriesNotFound { id: i64 },
+
+    #[error("데이터베이스 스키마(버전 {found})가 이 프로그램이 지원하는 버전({supported})보다 새롭습니다. tasky를 업데이트하세요")]
+    SchemaTooNew { found: u32, supported: u32 },
+
     #[error("잘못된 정렬 기준: {sort_by}")]
     InvalidSortBy { sort_by: String },
 
     #[error("할일 제목은 비어있을 수 없습니다")]
     EmptyTitle,
     
+    #[error("잘못된 필터 식: {message}\n  {expression}\n  {marker}")]
+    InvalidFilter { message: String, expression: String, marker: String },
+
+    #[error("가져올 수 없는 파일: {message}")]
+    InvalidImport { message: String },
+
+    #[error("가져올 할일 {count}개가 이미 있습니다. --skip-existing, --overwrite, --renumber 중 하나를 선택하세요")]
+    ImportConflict { count: usize },
+
     #[error("잘못된 입력: {message}")]
     InvalidInput { message: String },
 
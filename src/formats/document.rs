@@ -0,0 +1,255 @@
+//! 문서에 붙여 넣는 할일 목록 (`export --markdown`, `export --org`)
+//!
+//! 목록 순서는 `list`와 같고, 묶을 때는 묶음 안에서 그 순서를 유지한다.
+//! Markdown은 GitHub 체크박스, Org는 TODO/DONE 제목에 DEADLINE과 우선순위 쿠키를 쓴다.
+
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use std::io::Write;
+
+use crate::error::{Result, TaskyError};
+use crate::models::{Priority, Status, Todo};
+use crate::utils;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupBy {
+  Priority,
+  Due
+}
+
+impl GroupBy {
+  #[allow(clippy::should_implement_trait)]
+  pub fn from_str(source: &str) -> Result<Self> {
+    match source.to_lowercase().as_str() {
+      "priority" | "우선순위" => Ok(GroupBy::Priority),
+      "due" | "마감일" => Ok(GroupBy::Due),
+      _ => Err(TaskyError::InvalidInput {
+        message: format!("알 수 없는 묶음 기준: {}. priority, due 중 하나여야 합니다", source),
+      }),
+    }
+  }
+}
+
+/// 묶음 제목과 할일. 우선순위는 높음부터, 마감일은 이른 날부터이고 마감일 없는 할일은 마지막
+fn group_todos(todos: &[Todo], group_by: GroupBy) -> Vec<(String, Vec<&Todo>)> {
+  match group_by {
+    GroupBy::Priority => [Priority::High, Priority::Medium, Priority::Low]
+      .into_iter()
+      .map(|priority| {
+        let members = todos.iter().filter(|todo| todo.priority == priority).collect();
+        (priority.to_display_string().to_string(), members)
+      })
+      .filter(|(_, members): &(String, Vec<&Todo>)| !members.is_empty())
+      .collect(),
+    GroupBy::Due => {
+      let day = |todo: &Todo| todo.due_date.map(|due| due.with_timezone(&Local).date_naive());
+      let mut days: Vec<Option<NaiveDate>> = todos.iter().map(day).collect();
+      // None이 마지막에 오도록
+      days.sort_by_key(|day| (day.is_none(), *day));
+      days.dedup();
+      days
+        .into_iter()
+        .map(|group| {
+          let title = group.map_or("마감일 없음".to_string(), |day| day.format("%Y-%m-%d").to_string());
+          (title, todos.iter().filter(|todo| day(todo) == group).collect())
+        })
+        .collect()
+    }
+  }
+}
+
+fn escape_markdown(text: &str) -> String {
+  let mut escaped = String::new();
+  for c in text.chars() {
+    if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|') {
+      escaped.push('\\');
+    }
+    escaped.push(c);
+  }
+  escaped
+}
+
+fn markdown_item(todo: &Todo, group_by: Option<GroupBy>) -> Vec<String> {
+  let check = if todo.status == Status::Done { "x" } else { " " };
+  let mut line = format!("- [{}] {}", check, escape_markdown(&todo.title));
+
+  let mut details = Vec::new();
+  if group_by != Some(GroupBy::Priority) {
+    details.push(format!("우선순위 {}", todo.priority.to_display_string()));
+  }
+  // 마감일로 묶으면 날짜는 묶음 제목에 있으므로 시각만
+  match (todo.due_date, group_by, todo.due_all_day) {
+    (Some(_), Some(GroupBy::Due), true) | (None, _, _) => {}
+    (Some(due), Some(GroupBy::Due), false) => details.push(format!("마감 {}", due.with_timezone(&Local).format("%H:%M"))),
+    (Some(due), _, all_day) => details.push(format!("마감 {}", utils::format_due(&due, all_day))),
+  }
+  if let Some(project) = &todo.project {
+    details.push(escape_markdown(project));
+  }
+  details.extend(todo.tags.iter().map(|tag| format!("`#{}`", tag)));
+  if !details.is_empty() {
+    line.push_str(&format!(" ({})", details.join(", ")));
+  }
+
+  // 설명은 항목 안의 들여쓴 글로. 줄바꿈은 \로 유지
+  let mut lines = vec![line];
+  if let Some(description) = &todo.description {
+    let description: Vec<&str> = description.lines().filter(|line| !line.trim().is_empty()).collect();
+    for (index, text) in description.iter().enumerate() {
+      let hard_break = if index + 1 < description.len() { "\\" } else { "" };
+      lines.push(format!("  {}{}", escape_markdown(text.trim()), hard_break));
+    }
+  }
+  lines
+}
+
+/// GitHub 체크박스 목록. 묶으면 묶음마다 `##` 제목
+pub fn write_markdown(mut writer: impl Write, todos: &[Todo], group_by: Option<GroupBy>) -> Result<()> {
+  let groups = match group_by {
+    Some(group_by) => group_todos(todos, group_by),
+    None => vec![(String::new(), todos.iter().collect())],
+  };
+
+  for (index, (title, members)) in groups.iter().enumerate() {
+    if !title.is_empty() {
+      if index > 0 {
+        writeln!(writer)?;
+      }
+      writeln!(writer, "## {} ({})\n", title, members.len())?;
+    }
+    for todo in members {
+      for line in markdown_item(todo, group_by) {
+        writeln!(writer, "{}", line)?;
+      }
+    }
+  }
+  Ok(())
+}
+
+fn org_priority(priority: Priority) -> char {
+  match priority {
+    Priority::High => 'A',
+    Priority::Medium => 'B',
+    Priority::Low => 'C',
+  }
+}
+
+/// Org 태그에는 글자, 숫자, `_@#%`만 쓸 수 있음
+fn org_tag(tag: &str) -> String {
+  tag.chars().map(|c| if c.is_alphanumeric() || "_@#%".contains(c) { c } else { '_' }).collect()
+}
+
+fn org_timestamp(at: &DateTime<Utc>, all_day: bool) -> String {
+  let local = at.with_timezone(&Local);
+  match all_day {
+    true => local.format("<%Y-%m-%d %a>").to_string(),
+    false => local.format("<%Y-%m-%d %a %H:%M>").to_string(),
+  }
+}
+
+fn org_entry(todo: &Todo, level: usize) -> Vec<String> {
+  let keyword = if todo.status == Status::Done { "DONE" } else { "TODO" };
+  let mut headline = format!("{} {} [#{}] {}", "*".repeat(level), keyword, org_priority(todo.priority), todo.title);
+  if !todo.tags.is_empty() {
+    let tags: Vec<String> = todo.tags.iter().map(|tag| org_tag(tag)).collect();
+    headline.push_str(&format!(" :{}:", tags.join(":")));
+  }
+
+  // 본문은 제목 단계만큼 들여써서 *로 시작하는 줄이 제목이 되지 않게 함
+  let indent = " ".repeat(level + 1);
+  let mut lines = vec![headline];
+  if let Some(due) = &todo.due_date {
+    lines.push(format!("{}DEADLINE: {}", indent, org_timestamp(due, todo.due_all_day)));
+  }
+  if let Some(project) = &todo.project {
+    lines.push(format!("{}:PROPERTIES:", indent));
+    lines.push(format!("{}:PROJECT: {}", indent, project));
+    lines.push(format!("{}:END:", indent));
+  }
+  if let Some(description) = &todo.description {
+    lines.extend(description.lines().map(|line| format!("{}{}", indent, line).trim_end().to_string()));
+  }
+  lines
+}
+
+/// Org 제목 목록. 묶으면 묶음이 1단계 제목, 할일은 2단계
+pub fn write_org(mut writer: impl Write, todos: &[Todo], group_by: Option<GroupBy>) -> Result<()> {
+  let groups = match group_by {
+    Some(group_by) => group_todos(todos, group_by),
+    None => vec![(String::new(), todos.iter().collect())],
+  };
+
+  for (title, members) in &groups {
+    let level = if title.is_empty() { 1 } else { 2 };
+    if !title.is_empty() {
+      writeln!(writer, "* {}", title)?;
+    }
+    for todo in members {
+      for line in org_entry(todo, level) {
+        writeln!(writer, "{}", line)?;
+      }
+    }
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn sample_todos() -> Vec<Todo> {
+    let day = |day: u32| NaiveDate::from_ymd_opt(2026, 10, day).unwrap();
+
+    let mut meeting = Todo::sample("회의 준비");
+    meeting.due_date = Some(utils::date::local_to_utc(day(21).and_hms_opt(14, 30, 0).unwrap(), "").unwrap());
+    meeting.due_all_day = false;
+
+    let mut report = Todo::sample("보고서 *초안* 제출");
+    report.priority = Priority::High;
+    report.due_date = Some(utils::day_start(day(20)));
+    report.project = Some("work".to_string());
+    report.tags = vec!["urgent".to_string()];
+    report.description = Some("1장 요약\n* 2장 검토".to_string());
+
+    let mut research = Todo::sample("자료 수집");
+    research.priority = Priority::High;
+    research.status = Status::Done;
+
+    vec![meeting, report, research]
+  }
+
+  #[test]
+  fn test_write_markdown() {
+    let mut buffer = Vec::new();
+    write_markdown(&mut buffer, &sample_todos(), Some(GroupBy::Priority)).unwrap();
+    let output = String::from_utf8(buffer).unwrap();
+
+    let expected = "## 높음 (2)\n\n\
+      - [ ] 보고서 \\*초안\\* 제출 (마감 2026-10-20, work, `#urgent`)\n  1장 요약\\\n  \\* 2장 검토\n\
+      - [x] 자료 수집\n\
+      \n## 보통 (1)\n\n\
+      - [ ] 회의 준비 (마감 2026-10-21 14:30)\n";
+    assert_eq!(output, expected);
+
+    let mut buffer = Vec::new();
+    write_markdown(&mut buffer, &sample_todos(), Some(GroupBy::Due)).unwrap();
+    let output = String::from_utf8(buffer).unwrap();
+    let headings: Vec<&str> = output.lines().filter(|line| line.starts_with("## ")).collect();
+    assert_eq!(headings, vec!["## 2026-10-20 (1)", "## 2026-10-21 (1)", "## 마감일 없음 (1)"]);
+    assert!(output.contains("- [ ] 회의 준비 (우선순위 보통, 마감 14:30)\n"));
+  }
+
+  #[test]
+  fn test_write_org() {
+    let mut buffer = Vec::new();
+    write_org(&mut buffer, &sample_todos(), None).unwrap();
+    let output = String::from_utf8(buffer).unwrap();
+
+    let expected = "* TODO [#B] 회의 준비\n  DEADLINE: <2026-10-21 Wed 14:30>\n\
+      * TODO [#A] 보고서 *초안* 제출 :urgent:\n  DEADLINE: <2026-10-20 Tue>\n  :PROPERTIES:\n  :PROJECT: work\n  :END:\n  1장 요약\n  * 2장 검토\n\
+      * DONE [#A] 자료 수집\n";
+    assert_eq!(output, expected);
+
+    assert_eq!(GroupBy::from_str("마감일").unwrap(), GroupBy::Due);
+    assert!(GroupBy::from_str("tag").is_err());
+  }
+}
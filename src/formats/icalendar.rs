@@ -0,0 +1,435 @@
+//! iCalendar (RFC 5545) 형식 (`export --ics`, `import --ics`)
+//!
+//! 할일 하나가 VTODO 하나이고, `--events`를 주면 마감일을 캘린더 앱에서 볼 수 있도록
+//! 마감일이 있는 할일마다 VEVENT를 함께 쓴다. UID는 할일 UUID로 만들어서 다시 내보내도, 다른 데이터베이스에서 내보내도 같다.
+//! 가져오기는 VTODO만 읽으며 접힌 줄, TZID 매개변수, 종일 날짜(VALUE=DATE)를 처리한다.
+
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use std::collections::HashMap;
+use std::io::Write;
+
+use super::{ImportRow, ImportedTodo};
+use crate::error::{Result, TaskyError};
+use crate::models::{CreateTodo, Priority, Status, Todo};
+use crate::utils;
+
+const PRODID: &str = "-//tasky//tasky//KO";
+// 접은 줄의 최대 길이 (바이트, 줄 끝 CRLF 제외)
+const LINE_LIMIT: usize = 75;
+
+fn escape_text(value: &str) -> String {
+  value
+    .replace('\\', "\\\\")
+    .replace(';', "\\;")
+    .replace(',', "\\,")
+    .replace("\r\n", "\\n")
+    .replace('\n', "\\n")
+}
+
+fn unescape_text(value: &str) -> String {
+  let mut result = String::new();
+  let mut chars = value.chars();
+  while let Some(c) = chars.next() {
+    if c != '\\' {
+      result.push(c);
+      continue;
+    }
+    match chars.next() {
+      Some('n' | 'N') => result.push('\n'),
+      Some(other) => result.push(other),
+      None => result.push('\\'),
+    }
+  }
+  result
+}
+
+/// 이스케이프되지 않은 쉼표로 나눔 (CATEGORIES)
+fn split_list(value: &str) -> Vec<String> {
+  let mut items = Vec::new();
+  let mut current = String::new();
+  let mut escaped = false;
+  for c in value.chars() {
+    match c {
+      ',' if !escaped => items.push(std::mem::take(&mut current)),
+      _ => current.push(c),
+    }
+    escaped = c == '\\' && !escaped;
+  }
+  items.push(current);
+  items.iter().map(|item| unescape_text(item.trim())).filter(|item| !item.is_empty()).collect()
+}
+
+/// 75바이트를 넘는 줄을 글자 경계에서 접어서 CRLF로 씀
+fn write_line(writer: &mut impl Write, line: &str) -> Result<()> {
+  let mut rest = line;
+  let mut limit = LINE_LIMIT;
+  while rest.len() > limit {
+    let mut split = limit;
+    while !rest.is_char_boundary(split) {
+      split -= 1;
+    }
+    write!(writer, "{}\r\n ", &rest[..split])?;
+    rest = &rest[split..];
+    // 이어지는 줄은 앞의 공백 한 칸을 포함
+    limit = LINE_LIMIT - 1;
+  }
+  write!(writer, "{}\r\n", rest)?;
+  Ok(())
+}
+
+fn format_utc(at: &DateTime<Utc>) -> String {
+  at.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn format_local_day(at: &DateTime<Utc>) -> String {
+  at.with_timezone(&Local).format("%Y%m%d").to_string()
+}
+
+fn ics_priority(priority: Priority) -> u8 {
+  match priority {
+    Priority::High => 1,
+    Priority::Medium => 5,
+    Priority::Low => 9,
+  }
+}
+
+/// 1~4 높음, 5 보통, 6~9 낮음. 0(지정 안 함)은 보통
+fn parse_ics_priority(value: &str) -> Result<Priority> {
+  match value.trim().parse::<u8>() {
+    Ok(1..=4) => Ok(Priority::High),
+    Ok(0 | 5) => Ok(Priority::Medium),
+    Ok(6..=9) => Ok(Priority::Low),
+    _ => Err(TaskyError::InvalidPriority { priority: value.to_string() }),
+  }
+}
+
+/// UUID가 없는 할일(저장 전)은 ID로
+fn todo_uid(todo: &Todo, suffix: &str) -> String {
+  match todo.uuid.is_empty() {
+    true => format!("tasky-{}{}@tasky", todo.id.unwrap_or_default(), suffix),
+    false => format!("{}{}@tasky", todo.uuid, suffix),
+  }
+}
+
+/// 마감일 속성 (`DUE`, `DTSTART` 등). 종일이면 로컬 날짜, 아니면 UTC 시각
+fn due_property(name: &str, due: &DateTime<Utc>, all_day: bool) -> String {
+  match all_day {
+    true => format!("{};VALUE=DATE:{}", name, format_local_day(due)),
+    false => format!("{}:{}", name, format_utc(due)),
+  }
+}
+
+fn todo_lines(todo: &Todo, stamp: &DateTime<Utc>) -> Vec<String> {
+  let mut lines = vec![
+    "BEGIN:VTODO".to_string(),
+    format!("UID:{}", todo_uid(todo, "")),
+    format!("DTSTAMP:{}", format_utc(stamp)),
+    format!("CREATED:{}", format_utc(&todo.created_at)),
+    format!("LAST-MODIFIED:{}", format_utc(&todo.updated_at)),
+    format!("SUMMARY:{}", escape_text(&todo.title)),
+  ];
+  if let Some(description) = &todo.description {
+    lines.push(format!("DESCRIPTION:{}", escape_text(description)));
+  }
+  lines.push(format!("PRIORITY:{}", ics_priority(todo.priority)));
+  match todo.status {
+    Status::Pending => lines.push("STATUS:NEEDS-ACTION".to_string()),
+    Status::Done => {
+      lines.push("STATUS:COMPLETED".to_string());
+      // 완료 시각은 따로 없으므로 마지막 수정 시각
+      lines.push(format!("COMPLETED:{}", format_utc(&todo.updated_at)));
+    }
+  }
+  if let Some(due) = &todo.due_date {
+    lines.push(due_property("DUE", due, todo.due_all_day));
+  }
+  if !todo.tags.is_empty() {
+    let tags: Vec<String> = todo.tags.iter().map(|tag| escape_text(tag)).collect();
+    lines.push(format!("CATEGORIES:{}", tags.join(",")));
+  }
+  if let Some(project) = &todo.project {
+    lines.push(format!("X-TASKY-PROJECT:{}", escape_text(project)));
+  }
+  lines.push("END:VTODO".to_string());
+  lines
+}
+
+/// 마감일을 캘린더에 표시하는 VEVENT. 종일 마감은 하루짜리 종일 일정, 시각이 있는 마감은 그 시각의 일정
+fn deadline_lines(todo: &Todo, due: &DateTime<Utc>, stamp: &DateTime<Utc>) -> Vec<String> {
+  let mut lines = vec![
+    "BEGIN:VEVENT".to_string(),
+    format!("UID:{}", todo_uid(todo, "-due")),
+    format!("DTSTAMP:{}", format_utc(stamp)),
+    format!("SUMMARY:{}", escape_text(&todo.title)),
+    due_property("DTSTART", due, todo.due_all_day),
+  ];
+  if todo.due_all_day {
+    lines.push(due_property("DTEND", &(*due + Duration::days(1)), true));
+  }
+  if let Some(description) = &todo.description {
+    lines.push(format!("DESCRIPTION:{}", escape_text(description)));
+  }
+  // 마감일은 일정이 아니므로 바쁨으로 표시하지 않음
+  lines.push("TRANSP:TRANSPARENT".to_string());
+  lines.push(format!("RELATED-TO:{}", todo_uid(todo, "")));
+  lines.push("END:VEVENT".to_string());
+  lines
+}
+
+/// 할일을 VCALENDAR 하나로 씀. `events`면 마감일 VEVENT도 함께
+pub fn write_ics(mut writer: impl Write, todos: &[Todo], events: bool, stamp: DateTime<Utc>) -> Result<()> {
+  let mut lines = vec![
+    "BEGIN:VCALENDAR".to_string(),
+    "VERSION:2.0".to_string(),
+    format!("PRODID:{}", PRODID),
+    "CALSCALE:GREGORIAN".to_string(),
+  ];
+  for todo in todos {
+    lines.extend(todo_lines(todo, &stamp));
+  }
+  if events {
+    for todo in todos {
+      if let Some(due) = &todo.due_date {
+        lines.extend(deadline_lines(todo, due, &stamp));
+      }
+    }
+  }
+  lines.push("END:VCALENDAR".to_string());
+
+  for line in &lines {
+    write_line(&mut writer, line)?;
+  }
+  Ok(())
+}
+
+/// 접힌 줄을 펴고 (줄 번호, 줄)로 나눔
+fn unfold(source: &str) -> Vec<(u64, String)> {
+  let mut lines: Vec<(u64, String)> = Vec::new();
+  for (index, line) in source.lines().enumerate() {
+    let line = line.trim_end_matches('\r');
+    match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+      (Some(rest), Some((_, previous))) => previous.push_str(rest),
+      _ if line.is_empty() => {}
+      _ => lines.push((index as u64 + 1, line.to_string())),
+    }
+  }
+  lines
+}
+
+/// 내용 줄 하나: `이름;매개변수=값:내용`
+struct Property {
+  name: String,
+  params: HashMap<String, String>,
+  value: String
+}
+
+impl Property {
+  fn parse(line: &str) -> Option<Self> {
+    // 따옴표 안의 : 와 ; 는 구분자가 아님
+    let mut in_quotes = false;
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut value_start = None;
+    for (index, c) in line.char_indices() {
+      match c {
+        '"' => in_quotes = !in_quotes,
+        ';' if !in_quotes => {
+          parts.push(&line[start..index]);
+          start = index + 1;
+        }
+        ':' if !in_quotes => {
+          parts.push(&line[start..index]);
+          value_start = Some(index + 1);
+          break;
+        }
+        _ => {}
+      }
+    }
+
+    let value = line[value_start?..].to_string();
+    let mut parts = parts.into_iter();
+    let name = parts.next()?.to_uppercase();
+    let params = parts
+      .filter_map(|param| param.split_once('='))
+      .map(|(key, value)| (key.to_uppercase(), value.trim_matches('"').to_string()))
+      .collect();
+    Some(Property { name, params, value })
+  }
+
+  /// 날짜나 시각 값. 종일 날짜면 (로컬 자정, true)
+  fn datetime(&self) -> Result<(DateTime<Utc>, bool)> {
+    let invalid = || TaskyError::InvalidDateFormat { date: self.value.clone() };
+    let value = self.value.trim();
+
+    if self.params.get("VALUE").map(String::as_str) == Some("DATE") || value.len() == 8 {
+      let date = NaiveDate::parse_from_str(value, "%Y%m%d").map_err(|_| invalid())?;
+      return Ok((utils::day_start(date), true));
+    }
+
+    if let Some(utc) = value.strip_suffix('Z') {
+      let naive = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").map_err(|_| invalid())?;
+      return Ok((Utc.from_utc_datetime(&naive), false));
+    }
+
+    let naive = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").map_err(|_| invalid())?;
+    match self.params.get("TZID") {
+      // 일부 프로그램은 TZID 앞에 /를 붙임
+      Some(tzid) => {
+        let tz: Tz = tzid.trim_start_matches('/').parse().map_err(|_| TaskyError::InvalidInput {
+          message: format!("알 수 없는 시간대: {}", tzid),
+        })?;
+        let at = tz.from_local_datetime(&naive).earliest().ok_or_else(invalid)?;
+        Ok((at.with_timezone(&Utc), false))
+      }
+      // 시간대가 없는 시각은 로컬 시각
+      None => Ok((utils::date::local_to_utc(naive, value)?, false)),
+    }
+  }
+}
+
+fn apply_property(property: &Property, todo: &mut ImportedTodo) -> Result<()> {
+  match property.name.as_str() {
+    "SUMMARY" => todo.todo.title = unescape_text(&property.value),
+    "DESCRIPTION" => todo.todo.description = Some(unescape_text(&property.value)).filter(|d| !d.is_empty()),
+    "PRIORITY" => todo.todo.priority = parse_ics_priority(&property.value)?,
+    "STATUS" => todo.done = property.value.eq_ignore_ascii_case("COMPLETED"),
+    "COMPLETED" => todo.done = true,
+    "DUE" => {
+      let (at, all_day) = property.datetime()?;
+      todo.todo.due_date = Some(at);
+      todo.todo.due_all_day = all_day;
+    }
+    "CATEGORIES" => todo.todo.tags.extend(split_list(&property.value)),
+    "X-TASKY-PROJECT" => todo.todo.project = Some(unescape_text(&property.value)),
+    _ => {}
+  }
+  Ok(())
+}
+
+/// VCALENDAR에서 VTODO를 읽어 할일로 바꿈. VTODO마다 따로 검사해서 잘못된 VTODO는 그 항목에만 오류를 담음
+pub fn read_ics(source: &str) -> Result<Vec<ImportRow>> {
+  let lines = unfold(source);
+  if !lines.iter().any(|(_, line)| line.eq_ignore_ascii_case("BEGIN:VCALENDAR")) {
+    return Err(TaskyError::InvalidImport { message: "iCalendar 파일이 아닙니다 (BEGIN:VCALENDAR 없음)".to_string() });
+  }
+
+  let mut rows = Vec::new();
+  // (BEGIN:VTODO 줄 번호, 읽는 중인 할일, 첫 오류)
+  let mut current: Option<(u64, ImportedTodo, Option<TaskyError>)> = None;
+  // VTODO 안의 VALARM 같은 하위 구성 요소 깊이
+  let mut nested = 0;
+
+  for (number, line) in &lines {
+    let Some(property) = Property::parse(line) else {
+      continue;
+    };
+    let component = property.value.to_uppercase();
+
+    match (property.name.as_str(), current.as_mut()) {
+      ("BEGIN", None) if component == "VTODO" => {
+        current = Some((*number, ImportedTodo { todo: CreateTodo::new(String::new()), done: false }, None));
+      }
+      ("BEGIN", Some(_)) => nested += 1,
+      ("END", Some(_)) if nested > 0 => nested -= 1,
+      ("END", Some(_)) if component == "VTODO" => {
+        if let Some((line, todo, error)) = current.take() {
+          rows.push(ImportRow { line, todo: error.map_or(Ok(todo), Err) });
+        }
+      }
+      (_, Some((_, todo, error))) if nested == 0 && error.is_none() => {
+        *error = apply_property(&property, todo).err();
+      }
+      _ => {}
+    }
+  }
+
+  if let Some((line, _, _)) = current {
+    let message = "END:VTODO 없이 파일이 끝났습니다".to_string();
+    rows.push(ImportRow { line, todo: Err(TaskyError::InvalidImport { message }) });
+  }
+
+  Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn sample_todo() -> Todo {
+    let mut todo = Todo::sample("보고서 제출");
+    todo.id = Some(7);
+    todo.description = Some("1장; 요약\n2장".to_string());
+    todo.priority = Priority::High;
+    todo.due_date = Some(utils::day_start(NaiveDate::from_ymd_opt(2026, 10, 20).unwrap()));
+    todo.tags = vec!["office".to_string(), "a,b".to_string()];
+    todo.project = Some("work".to_string());
+    todo
+  }
+
+  #[test]
+  fn test_write_ics() {
+    let mut todo = sample_todo();
+    todo.title = "아주 긴 제목 ".repeat(10);
+    todo.uuid = "3f6c1a2e-9b4d-4e7a-8c5f-0d1e2f3a4b5c".to_string();
+    let stamp = Utc.with_ymd_and_hms(2026, 10, 18, 0, 0, 0).unwrap();
+
+    let mut buffer = Vec::new();
+    write_ics(&mut buffer, &[todo], true, stamp).unwrap();
+    let output = String::from_utf8(buffer).unwrap();
+
+    assert!(output.lines().all(|line| line.len() <= LINE_LIMIT + 1));
+    assert!(output.contains("UID:3f6c1a2e-9b4d-4e7a-8c5f-0d1e2f3a4b5c@tasky\r\n"));
+    assert!(output.contains("UID:3f6c1a2e-9b4d-4e7a-8c5f-0d1e2f3a4b5c-due@tasky\r\n"));
+    assert!(output.contains("DESCRIPTION:1장\\; 요약\\n2장\r\n"));
+    assert!(output.contains("PRIORITY:1\r\n"));
+    assert!(output.contains("DUE;VALUE=DATE:20261020\r\n"));
+    assert!(output.contains("CATEGORIES:office,a\\,b\r\n"));
+    assert!(output.contains("DTEND;VALUE=DATE:20261021\r\n"));
+    assert_eq!(output.matches("BEGIN:VEVENT").count(), 1);
+  }
+
+  #[test]
+  fn test_read_ics() {
+    let todo = sample_todo();
+    let mut buffer = Vec::new();
+    write_ics(&mut buffer, std::slice::from_ref(&todo), false, Utc::now()).unwrap();
+    let rows = read_ics(&String::from_utf8(buffer).unwrap()).unwrap();
+    let imported = rows[0].todo.as_ref().unwrap();
+    assert_eq!(imported.todo.title, todo.title);
+    assert_eq!(imported.todo.description, todo.description);
+    assert_eq!(imported.todo.priority, todo.priority);
+    assert_eq!(imported.todo.due_date, todo.due_date);
+    assert!(imported.todo.due_all_day);
+    assert_eq!(imported.todo.tags, todo.tags);
+    assert_eq!(imported.todo.project, todo.project);
+    assert!(!imported.done);
+
+    // 접힌 줄, TZID, 하위 VALARM, 잘못된 VTODO
+    let source = "BEGIN:VCALENDAR\r\n\
+      BEGIN:VTODO\r\n\
+      SUMMARY:접힌\r\n  제목\r\n\
+      DUE;TZID=America/New_York:20261020T090000\r\n\
+      STATUS:COMPLETED\r\n\
+      BEGIN:VALARM\r\n\
+      SUMMARY:알림\r\n\
+      END:VALARM\r\n\
+      END:VTODO\r\n\
+      BEGIN:VTODO\r\n\
+      SUMMARY:잘못된 마감일\r\n\
+      DUE;TZID=Nowhere/City:20261020T090000\r\n\
+      END:VTODO\r\n\
+      END:VCALENDAR\r\n";
+    let rows = read_ics(source).unwrap();
+    assert_eq!(rows.len(), 2);
+    let imported = rows[0].todo.as_ref().unwrap();
+    assert_eq!(imported.todo.title, "접힌 제목");
+    assert_eq!(imported.todo.due_date, Some(Utc.with_ymd_and_hms(2026, 10, 20, 13, 0, 0).unwrap()));
+    assert!(!imported.todo.due_all_day);
+    assert!(imported.done);
+    assert_eq!(rows[1].line, 11);
+    assert!(rows[1].todo.is_err());
+
+    assert!(read_ics("BEGIN:VTODO\nEND:VTODO").is_err());
+  }
+}
@@ -0,0 +1,39 @@
+//! 다른 도구와 주고받는 파일 형식 (`export`/`import`의 형식 옵션)
+
+pub mod document;
+pub mod icalendar;
+pub mod spreadsheet;
+pub mod taskwarrior;
+pub mod todotxt;
+
+use serde::Serialize;
+
+use crate::error::Result;
+use crate::models::CreateTodo;
+
+pub use spreadsheet::{ColumnMapping, CsvField, DateOptions};
+
+/// CSV 행이나 iCalendar VTODO 하나에서 읽은 할일
+#[derive(Debug, Clone)]
+pub struct ImportedTodo {
+  pub todo: CreateTodo,
+  pub done: bool
+}
+
+/// 가져올 항목 하나. 읽지 못한 항목은 오류를 담음
+#[derive(Debug)]
+pub struct ImportRow {
+  // 파일의 줄 번호 (CSV는 헤더가 1, iCalendar는 BEGIN:VTODO 줄)
+  pub line: u64,
+  pub todo: Result<ImportedTodo>
+}
+
+/// 가져오기 보고서의 한 행
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportRowReport {
+  pub line: u64,
+  pub title: String,
+  // 가져온 할일 ID (--dry-run이거나 실패하면 None)
+  pub id: Option<i64>,
+  pub error: Option<String>
+}
@@ -0,0 +1,303 @@
+//! 스프레드시트와 주고받는 CSV (`export --csv`, `import --csv`)
+//!
+//! 열 이름은 `--column title=제목`처럼 필드마다 바꿀 수 있다. 가져오기는 행마다 따로 검사해서
+//! 잘못된 행이 있어도 나머지 행은 가져온다.
+
+use chrono::Local;
+use csv::StringRecord;
+use std::collections::HashMap;
+use std::io::Write;
+
+use super::{ImportRow, ImportedTodo};
+use crate::error::{Result, TaskyError};
+use crate::models::{CreateTodo, Priority, Status, Todo};
+use crate::utils::{self, Clock};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CsvField {
+  Title,
+  Description,
+  Priority,
+  Status,
+  Due,
+  Tags,
+  Project
+}
+
+/// 열을 지정하지 않았을 때 내보내는 열 (이 순서대로)
+pub const DEFAULT_FIELDS: &[CsvField] = &[
+  CsvField::Title,
+  CsvField::Description,
+  CsvField::Priority,
+  CsvField::Status,
+  CsvField::Due,
+  CsvField::Tags,
+  CsvField::Project,
+];
+
+impl CsvField {
+  #[allow(clippy::should_implement_trait)]
+  pub fn from_str(source: &str) -> Result<Self> {
+    match source.trim().to_lowercase().as_str() {
+      "title" | "제목" => Ok(CsvField::Title),
+      "description" | "설명" => Ok(CsvField::Description),
+      "priority" | "우선순위" => Ok(CsvField::Priority),
+      "status" | "상태" => Ok(CsvField::Status),
+      "due" | "마감일" => Ok(CsvField::Due),
+      "tags" | "태그" => Ok(CsvField::Tags),
+      "project" | "프로젝트" => Ok(CsvField::Project),
+      _ => Err(TaskyError::InvalidInput {
+        message: format!("알 수 없는 CSV 필드: {}. title, description, priority, status, due, tags, project 중 하나여야 합니다", source),
+      }),
+    }
+  }
+
+  /// 기본 열 이름
+  pub fn name(&self) -> &'static str {
+    match self {
+      CsvField::Title => "title",
+      CsvField::Description => "description",
+      CsvField::Priority => "priority",
+      CsvField::Status => "status",
+      CsvField::Due => "due",
+      CsvField::Tags => "tags",
+      CsvField::Project => "project",
+    }
+  }
+}
+
+/// 필드와 CSV 열 이름의 대응. 지정하지 않은 필드는 기본 이름(영문 또는 한글)의 열을 씀
+#[derive(Debug, Clone, Default)]
+pub struct ColumnMapping {
+  columns: Vec<(CsvField, String)>
+}
+
+impl ColumnMapping {
+  /// `필드=열 이름` 목록을 읽음
+  pub fn parse(specs: &[String]) -> Result<Self> {
+    let mut mapping = ColumnMapping::default();
+
+    for spec in specs {
+      let (field, header) = spec.split_once('=').ok_or_else(|| TaskyError::InvalidInput {
+        message: format!("열 지정은 '필드=열 이름' 형식이어야 합니다: {}", spec),
+      })?;
+      let field = CsvField::from_str(field)?;
+
+      if mapping.columns.iter().any(|(mapped, _)| *mapped == field) {
+        return Err(TaskyError::InvalidInput { message: format!("'{}' 필드의 열을 두 번 지정했습니다", field.name()) });
+      }
+      mapping.columns.push((field, header.trim().to_string()));
+    }
+
+    Ok(mapping)
+  }
+
+  /// 내보낼 열. 지정한 열만 지정한 순서로, 지정하지 않았으면 기본 열 전부
+  fn export_columns(&self) -> Vec<(CsvField, String)> {
+    if self.columns.is_empty() {
+      DEFAULT_FIELDS.iter().map(|field| (*field, field.name().to_string())).collect()
+    } else {
+      self.columns.clone()
+    }
+  }
+
+  /// 헤더에서 필드마다 열 위치를 찾음 (대소문자 무시)
+  fn locate(&self, headers: &StringRecord) -> Result<HashMap<CsvField, usize>> {
+    let position = |name: &str| headers.iter().position(|header| header.trim().eq_ignore_ascii_case(name.trim()));
+    let mut positions = HashMap::new();
+
+    for (field, header) in &self.columns {
+      let index = position(header).ok_or_else(|| TaskyError::InvalidInput {
+        message: format!("CSV에 '{}' 열이 없습니다 ({} 필드)", header, field.name()),
+      })?;
+      positions.insert(*field, index);
+    }
+
+    for field in DEFAULT_FIELDS {
+      if positions.contains_key(field) {
+        continue;
+      }
+      let Some(index) = headers.iter().position(|header| CsvField::from_str(header).ok() == Some(*field)) else {
+        continue;
+      };
+      positions.insert(*field, index);
+    }
+
+    if !positions.contains_key(&CsvField::Title) {
+      return Err(TaskyError::InvalidInput {
+        message: "CSV에 제목 열이 없습니다. --column title=<열 이름>으로 지정하세요".to_string(),
+      });
+    }
+
+    Ok(positions)
+  }
+}
+
+/// 날짜 읽기 설정
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DateOptions {
+  // 31/12/2026처럼 일이 먼저 오는 날짜 (기본은 12/31/2026처럼 월이 먼저)
+  pub day_first: bool
+}
+
+/// `31/12/2026`, `31.12.2026` 같은 일-월-연 날짜를 `2026-12-31`로 바꿈
+fn reorder_day_first(value: &str) -> Option<String> {
+  let parts: Vec<&str> = value.split(['/', '.', '-']).map(str::trim).collect();
+  match parts.as_slice() {
+    [day, month, year] if year.len() == 4 && [day, month, year].iter().all(|p| p.chars().all(|c| c.is_ascii_digit())) => {
+      Some(format!("{}-{:0>2}-{:0>2}", year, month, day))
+    }
+    _ => None,
+  }
+}
+
+fn parse_cell(field: CsvField, value: &str, options: DateOptions, clock: &dyn Clock, todo: &mut ImportedTodo) -> Result<()> {
+  match field {
+    CsvField::Title => todo.todo.title = value.to_string(),
+    CsvField::Description => todo.todo.description = Some(value.to_string()),
+    CsvField::Priority => todo.todo.priority = Priority::from_str(value)?,
+    CsvField::Status => todo.done = Status::from_str(value)? == Status::Done,
+    CsvField::Due => {
+      let value = match options.day_first {
+        true => reorder_day_first(value).unwrap_or_else(|| value.to_string()),
+        false => value.to_string(),
+      };
+      let due = utils::parse_due(&value, clock)?;
+      todo.todo.due_date = Some(due.at);
+      todo.todo.due_all_day = due.all_day;
+    }
+    CsvField::Tags => {
+      todo.todo.tags = value
+        .split([',', ' '])
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+        .map(str::to_string)
+        .collect();
+    }
+    CsvField::Project => todo.todo.project = Some(value.to_string()),
+  }
+  Ok(())
+}
+
+/// CSV를 읽어 행마다 할일로 바꿈. 헤더가 잘못되면 오류, 행의 오류는 그 행에만 담김. 빈 행은 건너뜀
+pub fn read_csv(source: &str, mapping: &ColumnMapping, options: DateOptions, clock: &dyn Clock) -> Result<Vec<ImportRow>> {
+  let csv_error = |e: csv::Error| TaskyError::InvalidInput { message: format!("CSV를 읽을 수 없습니다: {}", e) };
+
+  let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(source.as_bytes());
+  let positions = mapping.locate(reader.headers().map_err(csv_error)?)?;
+
+  let mut rows = Vec::new();
+  for record in reader.records() {
+    let record = record.map_err(csv_error)?;
+    if record.iter().all(|cell| cell.trim().is_empty()) {
+      continue;
+    }
+    // csv의 줄 번호와 위치는 건너뛴 빈 줄을 포함하지 않으므로 직접 계산
+    let bytes = source.as_bytes();
+    let mut offset = record.position().map(|p| p.byte() as usize).unwrap_or_default();
+    while matches!(bytes.get(offset), Some(b'\n' | b'\r')) {
+      offset += 1;
+    }
+    let line = bytes[..offset].iter().filter(|&&b| b == b'\n').count() as u64 + 1;
+
+    let mut todo = ImportedTodo { todo: CreateTodo::new(String::new()), done: false };
+    let result = DEFAULT_FIELDS
+      .iter()
+      .filter_map(|field| {
+        let value = positions.get(field).and_then(|&index| record.get(index)).map(str::trim)?;
+        (!value.is_empty()).then_some((*field, value))
+      })
+      .try_for_each(|(field, value)| parse_cell(field, value, options, clock, &mut todo));
+
+    rows.push(ImportRow { line, todo: result.map(|_| todo) });
+  }
+
+  Ok(rows)
+}
+
+fn format_cell(field: CsvField, todo: &Todo) -> String {
+  match field {
+    CsvField::Title => todo.title.clone(),
+    CsvField::Description => todo.description.clone().unwrap_or_default(),
+    CsvField::Priority => todo.priority.as_str().to_string(),
+    CsvField::Status => todo.status.as_str().to_string(),
+    // 다시 가져올 수 있도록 로컬 시각으로
+    CsvField::Due => match todo.due_date {
+      Some(due) if todo.due_all_day => due.with_timezone(&Local).format("%Y-%m-%d").to_string(),
+      Some(due) => due.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string(),
+      None => String::new(),
+    },
+    CsvField::Tags => todo.tags.join(","),
+    CsvField::Project => todo.project.clone().unwrap_or_default(),
+  }
+}
+
+pub fn write_csv(writer: impl Write, todos: &[Todo], mapping: &ColumnMapping) -> Result<()> {
+  let csv_error = |e: csv::Error| TaskyError::System(e.into());
+  let columns = mapping.export_columns();
+
+  let mut writer = csv::Writer::from_writer(writer);
+  writer.write_record(columns.iter().map(|(_, header)| header)).map_err(csv_error)?;
+  for todo in todos {
+    writer.write_record(columns.iter().map(|(field, _)| format_cell(*field, todo))).map_err(csv_error)?;
+  }
+  writer.flush()?;
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::utils::FixedClock;
+
+  #[test]
+  fn test_read_csv() {
+    let clock = FixedClock::parse("2026-10-18 09:00").unwrap();
+    let source = "\
+할 일,메모,중요도,마감,상태
+보고서 작성,\"1, 2장\",high,31/12/2026,done
+
+회의 준비,,보통,tomorrow 14:00,
+,제목 없음,low,,
+장보기,,urgent,,
+";
+    let mapping = ColumnMapping::parse(&[
+      "title=할 일".to_string(),
+      "description=메모".to_string(),
+      "priority=중요도".to_string(),
+      "due=마감".to_string(),
+    ]).unwrap();
+    let rows = read_csv(source, &mapping, DateOptions { day_first: true }, &clock).unwrap();
+
+    assert_eq!(rows.iter().map(|row| row.line).collect::<Vec<_>>(), vec![2, 4, 5, 6]);
+
+    // 지정하지 않은 '상태' 열은 한글 기본 이름으로 찾음
+    let first = rows[0].todo.as_ref().unwrap();
+    assert_eq!(first.todo.title, "보고서 작성");
+    assert_eq!(first.todo.description.as_deref(), Some("1, 2장"));
+    assert_eq!(first.todo.priority, Priority::High);
+    assert_eq!(first.todo.due_date, Some(utils::parse_date("2026-12-31", &clock).unwrap()));
+    assert!(first.done);
+
+    let second = rows[1].todo.as_ref().unwrap();
+    assert_eq!(second.todo.priority, Priority::Medium);
+    assert!(!second.todo.due_all_day);
+
+    // 제목 검사는 가져올 때 (서비스의 검증)
+    assert!(rows[2].todo.as_ref().unwrap().todo.title.is_empty());
+    assert!(matches!(rows[3].todo, Err(TaskyError::InvalidPriority { .. })));
+
+    let missing = ColumnMapping::parse(&["title=Name".to_string()]).unwrap();
+    assert!(read_csv(source, &missing, DateOptions::default(), &clock).is_err());
+    assert!(ColumnMapping::parse(&["owner=담당자".to_string()]).is_err());
+  }
+
+  #[test]
+  fn test_reorder_day_first() {
+    assert_eq!(reorder_day_first("31/12/2026").as_deref(), Some("2026-12-31"));
+    assert_eq!(reorder_day_first("1.2.2026").as_deref(), Some("2026-02-01"));
+    assert_eq!(reorder_day_first("2026-12-31"), None);
+    assert_eq!(reorder_day_first("tomorrow"), None);
+  }
+}
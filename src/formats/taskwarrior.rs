@@ -0,0 +1,366 @@
+//! Taskwarrior `task export` JSON (`export --taskwarrior`, `import --taskwarrior`)
+//!
+//! uuid, description, status, priority, due, entry, modified, end, tags, project, annotations, depends를
+//! Tasky 필드로 옮긴다. 옮길 곳이 없는 필드는 버리지 않고 필드별 보고서(`UnmappedField`)로 알려준다.
+
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone, Timelike, Utc};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::io::Write;
+use uuid::Uuid;
+
+use crate::error::{Result, TaskyError};
+use crate::models::{normalize_tag, ExportFile, ExportedDependency, Priority, Status, Todo};
+
+const TIMESTAMP_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+/// Tasky나 Taskwarrior 한쪽에만 있어서 옮기지 못한 필드
+#[derive(Debug, Clone, Serialize)]
+pub struct UnmappedField {
+  pub field: String,
+  // 이 필드가 있던 할일 수
+  pub count: usize,
+  pub note: String
+}
+
+/// 필드별로 옮기지 못한 할일 수를 모음
+#[derive(Debug, Default)]
+struct Report {
+  fields: BTreeMap<String, (usize, &'static str)>
+}
+
+impl Report {
+  fn add(&mut self, field: &str, note: &'static str) {
+    self.fields.entry(field.to_string()).or_insert((0, note)).0 += 1;
+  }
+
+  fn into_fields(self) -> Vec<UnmappedField> {
+    self
+      .fields
+      .into_iter()
+      .map(|(field, (count, note))| UnmappedField { field, count, note: note.to_string() })
+      .collect()
+  }
+}
+
+/// Taskwarrior에서 가져온 할일. 할일 ID는 파일 안의 순서 (1부터)이고 의존성도 그 ID로 연결됨
+#[derive(Debug)]
+pub struct TaskwarriorImport {
+  pub file: ExportFile,
+  pub unmapped: Vec<UnmappedField>
+}
+
+fn parse_timestamp(value: &Value) -> Option<DateTime<Utc>> {
+  let value = value.as_str()?;
+  NaiveDateTime::parse_from_str(value, TIMESTAMP_FORMAT)
+    .map(|naive| Utc.from_utc_datetime(&naive))
+    .or_else(|_| DateTime::parse_from_rfc3339(value).map(|at| at.with_timezone(&Utc)))
+    .ok()
+}
+
+fn format_timestamp(at: &DateTime<Utc>) -> String {
+  at.format(TIMESTAMP_FORMAT).to_string()
+}
+
+/// 배열 하나(`task export`) 또는 한 줄에 객체 하나(예전 버전)
+fn parse_tasks(source: &str) -> Result<Vec<Map<String, Value>>> {
+  let invalid = |message: String| TaskyError::InvalidImport { message };
+
+  let values: Vec<Value> = if source.trim_start().starts_with('[') {
+    serde_json::from_str(source).map_err(|e| invalid(format!("JSON이 아닙니다 ({})", e)))?
+  } else {
+    source
+      .lines()
+      .map(|line| line.trim().trim_end_matches(','))
+      .filter(|line| !line.is_empty())
+      .map(|line| serde_json::from_str(line).map_err(|e| invalid(format!("JSON이 아닙니다 ({})", e))))
+      .collect::<Result<_>>()?
+  };
+
+  values
+    .into_iter()
+    .enumerate()
+    .map(|(index, value)| match value {
+      Value::Object(task) => Ok(task),
+      _ => Err(invalid(format!("{}번째 항목이 Taskwarrior 할일이 아닙니다", index + 1))),
+    })
+    .collect()
+}
+
+fn parse_priority(value: &Value) -> Option<Priority> {
+  match value.as_str()? {
+    "H" => Some(Priority::High),
+    "M" => Some(Priority::Medium),
+    "L" => Some(Priority::Low),
+    _ => None,
+  }
+}
+
+fn priority_letter(priority: Priority) -> &'static str {
+  match priority {
+    Priority::High => "H",
+    Priority::Medium => "M",
+    Priority::Low => "L",
+  }
+}
+
+/// depends는 버전에 따라 쉼표로 이은 문자열이거나 배열
+fn depends_uuids(value: &Value) -> Vec<String> {
+  match value {
+    Value::String(uuids) => uuids.split(',').map(|uuid| uuid.trim().to_string()).filter(|uuid| !uuid.is_empty()).collect(),
+    Value::Array(uuids) => uuids.iter().filter_map(Value::as_str).map(str::to_string).collect(),
+    _ => Vec::new(),
+  }
+}
+
+/// 옮기지 못하는 Taskwarrior 필드의 설명. 계산되는 값(id, urgency)은 None
+fn unmapped_note(field: &str) -> Option<&'static str> {
+  match field {
+    "id" | "urgency" => None,
+    "uuid" => Some("UUID 형식이 아니라서 의존성 연결에만 사용하고 새 UUID를 부여함"),
+    "wait" => Some("대기 날짜가 없어서 대기중 할일로 가져옴"),
+    "scheduled" => Some("예정일이 없어서 버림"),
+    "until" => Some("만료일이 없어서 버림"),
+    "start" => Some("시작 표시가 없어서 버림"),
+    "recur" | "mask" | "imask" | "parent" | "rtype" => Some("반복 규칙은 옮기지 않고 이미 만들어진 회차만 일반 할일로 가져옴"),
+    _ => Some("알 수 없는 필드 (UDA)라서 버림"),
+  }
+}
+
+/// `task export` 출력을 읽음. `now`는 entry가 없는 할일의 생성 시각
+pub fn read_taskwarrior(source: &str, now: DateTime<Utc>) -> Result<TaskwarriorImport> {
+  let tasks = parse_tasks(source)?;
+  let mut report = Report::default();
+  let mut file = ExportFile::new(now);
+  let mut ids_by_uuid = HashMap::new();
+  let mut depends = Vec::new();
+
+  for (index, task) in tasks.iter().enumerate() {
+    let invalid = |message: String| TaskyError::InvalidImport { message: format!("{}번째 할일: {}", index + 1, message) };
+    let timestamp = |field: &str| -> Result<Option<DateTime<Utc>>> {
+      task.get(field).map(|value| parse_timestamp(value).ok_or_else(|| invalid(format!("잘못된 {}: {}", field, value)))).transpose()
+    };
+
+    let status = task.get("status").and_then(Value::as_str).unwrap_or("pending");
+    // 반복 템플릿은 할일이 아니라 회차를 만드는 규칙
+    if status == "recurring" {
+      report.add("recur", unmapped_note("recur").unwrap_or_default());
+      continue;
+    }
+
+    let id = file.todos.len() as i64 + 1;
+    let entry = timestamp("entry")?.unwrap_or(now);
+    let modified = timestamp("modified")?.unwrap_or(entry);
+    let end = timestamp("end")?;
+    let due = timestamp("due")?;
+
+    let uuid = task.get("uuid").and_then(Value::as_str);
+    let mut todo = Todo {
+      id: Some(id),
+      title: task.get("description").and_then(Value::as_str).unwrap_or_default().to_string(),
+      description: None,
+      priority: match task.get("priority") {
+        Some(value) => parse_priority(value).ok_or_else(|| invalid(format!("잘못된 priority: {}", value)))?,
+        None => Priority::default(),
+      },
+      status: if status == "completed" { Status::Done } else { Status::Pending },
+      created_at: entry,
+      updated_at: modified,
+      due_date: due,
+      // Taskwarrior는 날짜만 준 마감일을 로컬 자정으로 저장함
+      due_all_day: due.is_some_and(|due| due.with_timezone(&Local).num_seconds_from_midnight() == 0),
+      tags: Vec::new(),
+      project: task.get("project").and_then(Value::as_str).map(str::to_string),
+      parent_id: None,
+      series_id: None,
+      recurrence: None,
+      deleted_at: if status == "deleted" { Some(end.unwrap_or(modified)) } else { None },
+      archived_at: None,
+      uuid: uuid.and_then(|uuid| Uuid::parse_str(uuid).ok()).map(|uuid| uuid.to_string()).unwrap_or_default(),
+    };
+
+    if let Some(tags) = task.get("tags").and_then(Value::as_array) {
+      todo.tags = tags.iter().filter_map(Value::as_str).map(normalize_tag).collect();
+    }
+
+    // 주석은 한 줄에 하나씩 설명으로
+    if let Some(annotations) = task.get("annotations").and_then(Value::as_array) {
+      let lines: Vec<&str> = annotations.iter().filter_map(|a| a.get("description").and_then(Value::as_str)).collect();
+      if !lines.is_empty() {
+        todo.description = Some(lines.join("\n"));
+        report.add("annotations", "주석 시각은 버리고 내용만 한 줄씩 설명으로 가져옴");
+      }
+    }
+
+    if let Some(uuid) = uuid {
+      ids_by_uuid.insert(uuid.to_lowercase(), id);
+    }
+    if let Some(value) = task.get("depends") {
+      depends.extend(depends_uuids(value).into_iter().map(|uuid| (id, uuid.to_lowercase())));
+    }
+
+    for field in task.keys() {
+      let mapped = (field == "uuid" && !todo.uuid.is_empty()) || matches!(
+        field.as_str(),
+        "description" | "status" | "entry" | "modified" | "end" | "priority" | "due" | "tags" | "project" | "annotations" | "depends"
+      );
+      if let Some(note) = unmapped_note(field).filter(|_| !mapped) {
+        report.add(field, note);
+      }
+    }
+
+    file.todos.push(todo);
+  }
+
+  for (todo_id, uuid) in depends {
+    match ids_by_uuid.get(&uuid) {
+      Some(&depends_on_id) => file.dependencies.push(ExportedDependency { todo_id, depends_on_id }),
+      None => report.add("depends", "파일에 없는 할일에 대한 의존성은 버림"),
+    }
+  }
+
+  Ok(TaskwarriorImport { file, unmapped: report.into_fields() })
+}
+
+fn task_value(todo: &Todo, depends: &[&str]) -> Value {
+  let mut task = Map::new();
+  if !todo.uuid.is_empty() {
+    task.insert("uuid".to_string(), json!(todo.uuid));
+  }
+  task.insert("description".to_string(), json!(todo.title));
+  let status = match (todo.deleted_at, todo.status) {
+    (Some(_), _) => "deleted",
+    (None, Status::Done) => "completed",
+    (None, Status::Pending) => "pending",
+  };
+  task.insert("status".to_string(), json!(status));
+  task.insert("entry".to_string(), json!(format_timestamp(&todo.created_at)));
+  task.insert("modified".to_string(), json!(format_timestamp(&todo.updated_at)));
+  // 완료 시각은 따로 없으므로 마지막 수정 시각
+  if let Some(end) = todo.deleted_at.or((todo.status == Status::Done).then_some(todo.updated_at)) {
+    task.insert("end".to_string(), json!(format_timestamp(&end)));
+  }
+  task.insert("priority".to_string(), json!(priority_letter(todo.priority)));
+  if let Some(due) = &todo.due_date {
+    task.insert("due".to_string(), json!(format_timestamp(due)));
+  }
+  if let Some(project) = &todo.project {
+    task.insert("project".to_string(), json!(project));
+  }
+  if !todo.tags.is_empty() {
+    task.insert("tags".to_string(), json!(todo.tags));
+  }
+  if let Some(description) = &todo.description {
+    let annotations: Vec<Value> = description
+      .lines()
+      .filter(|line| !line.trim().is_empty())
+      .map(|line| json!({ "entry": format_timestamp(&todo.updated_at), "description": line }))
+      .collect();
+    task.insert("annotations".to_string(), Value::Array(annotations));
+  }
+  if !depends.is_empty() {
+    task.insert("depends".to_string(), json!(depends));
+  }
+  Value::Object(task)
+}
+
+/// `task import`로 읽을 수 있는 JSON 배열을 씀. Taskwarrior로 옮기지 못한 Tasky 필드를 돌려줌
+pub fn write_taskwarrior(mut writer: impl Write, file: &ExportFile) -> Result<Vec<UnmappedField>> {
+  let mut report = Report::default();
+  for todo in &file.todos {
+    if todo.parent_id.is_some() {
+      report.add("parent_id", "Taskwarrior에 하위 할일이 없어서 독립된 할일로 내보냄");
+    }
+    if todo.recurrence.is_some() {
+      report.add("recurrence", "반복 규칙 형식이 달라서 이번 회차만 내보냄");
+    }
+    if todo.archived_at.is_some() {
+      report.add("archived_at", "보관 상태가 없어서 버림");
+    }
+  }
+
+  // 의존성은 선행 할일의 UUID로
+  let uuids: HashMap<i64, &str> = file
+    .todos
+    .iter()
+    .filter(|todo| !todo.uuid.is_empty())
+    .filter_map(|todo| todo.id.map(|id| (id, todo.uuid.as_str())))
+    .collect();
+  let mut depends: HashMap<i64, Vec<&str>> = HashMap::new();
+  for dependency in &file.dependencies {
+    match uuids.get(&dependency.depends_on_id) {
+      Some(uuid) => depends.entry(dependency.todo_id).or_default().push(uuid),
+      None => report.add("dependencies", "UUID가 없는 할일에 대한 의존성은 내보내지 않음"),
+    }
+  }
+
+  let tasks: Vec<Value> = file
+    .todos
+    .iter()
+    .map(|todo| task_value(todo, todo.id.and_then(|id| depends.get(&id)).map_or(&[], Vec::as_slice)))
+    .collect();
+  let output = serde_json::to_string_pretty(&tasks).map_err(|e| TaskyError::System(e.into()))?;
+  writeln!(writer, "{}", output)?;
+  Ok(report.into_fields())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_read_taskwarrior() {
+    let source = r#"[
+      {"id":1,"description":"보고서","entry":"20261001T000000Z","modified":"20261002T000000Z","status":"pending",
+       "uuid":"a","priority":"H","due":"20261020T153000Z","tags":["Work"],"project":"work.backend",
+       "depends":"0b5f7d2e-8c1a-4e3b-9f6d-2a7c4e8b1d09,missing","scheduled":"20261010T000000Z","urgency":8.1,
+       "annotations":[{"entry":"20261002T000000Z","description":"초안"},{"entry":"20261003T000000Z","description":"검토"}]},
+      {"id":0,"description":"자료 수집","entry":"20261001T000000Z","end":"20261005T000000Z","status":"completed","uuid":"0B5F7D2E-8C1A-4E3B-9F6D-2A7C4E8B1D09"},
+      {"id":0,"description":"지운 할일","entry":"20261001T000000Z","end":"20261006T000000Z","status":"deleted","uuid":"c"},
+      {"id":2,"description":"주간 회의","status":"recurring","recur":"weekly","uuid":"d"}
+    ]"#;
+    let now = Utc.with_ymd_and_hms(2026, 10, 18, 0, 0, 0).unwrap();
+    let imported = read_taskwarrior(source, now).unwrap();
+
+    let todos = &imported.file.todos;
+    assert_eq!(todos.len(), 3);
+    assert_eq!(todos[0].priority, Priority::High);
+    assert_eq!(todos[0].tags, vec!["work"]);
+    assert_eq!(todos[0].description.as_deref(), Some("초안\n검토"));
+    assert_eq!(todos[0].updated_at, Utc.with_ymd_and_hms(2026, 10, 2, 0, 0, 0).unwrap());
+    assert_eq!(todos[1].status, Status::Done);
+    assert_eq!(todos[1].uuid, "0b5f7d2e-8c1a-4e3b-9f6d-2a7c4e8b1d09");
+    assert_eq!(todos[0].uuid, "");
+    assert_eq!(todos[1].priority, Priority::Medium);
+    assert_eq!(todos[2].deleted_at, Some(Utc.with_ymd_and_hms(2026, 10, 6, 0, 0, 0).unwrap()));
+    assert_eq!(imported.file.dependencies, vec![ExportedDependency { todo_id: 1, depends_on_id: 2 }]);
+
+    let fields: Vec<(&str, usize)> = imported.unmapped.iter().map(|f| (f.field.as_str(), f.count)).collect();
+    assert_eq!(fields, vec![("annotations", 1), ("depends", 1), ("recur", 1), ("scheduled", 1), ("uuid", 2)]);
+
+    // 한 줄에 하나씩인 예전 형식
+    let lines = "{\"description\":\"하나\",\"status\":\"pending\"},\n{\"description\":\"둘\",\"status\":\"pending\"}\n";
+    assert_eq!(read_taskwarrior(lines, now).unwrap().file.todos.len(), 2);
+    assert!(read_taskwarrior(r#"[{"description":"x","priority":"X"}]"#, now).is_err());
+  }
+
+  #[test]
+  fn test_round_trip() {
+    let source = r#"[{"uuid":"4f2a9c1e-6b3d-4a8e-b5c7-9d0e1f2a3b4c","description":"보고서","status":"completed","entry":"20261001T000000Z","modified":"20261005T000000Z",
+      "end":"20261005T000000Z","priority":"L","due":"20261020T153000Z","project":"work","tags":["a","b"],
+      "annotations":[{"entry":"20261005T000000Z","description":"메모"}]},
+      {"uuid":"7c8d9e0f-1a2b-4c3d-8e4f-5a6b7c8d9e0f","description":"발표","status":"pending","entry":"20261001T000000Z",
+      "modified":"20261001T000000Z","priority":"M","depends":["4f2a9c1e-6b3d-4a8e-b5c7-9d0e1f2a3b4c"]}]"#;
+    let now = Utc.with_ymd_and_hms(2026, 10, 18, 0, 0, 0).unwrap();
+    let imported = read_taskwarrior(source, now).unwrap();
+
+    let mut buffer = Vec::new();
+    let unmapped = write_taskwarrior(&mut buffer, &imported.file).unwrap();
+    assert!(unmapped.is_empty());
+
+    let exported: Value = serde_json::from_slice(&buffer).unwrap();
+    let original: Value = serde_json::from_str(source).unwrap();
+    assert_eq!(exported, original);
+  }
+}
@@ -0,0 +1,272 @@
+//! todo.txt 형식 (`export --todotxt`, `import --todotxt`)
+//!
+//! `x 2026-10-18 2026-10-01 보고서 제출 +work @office due:2026-10-20 pri:A`처럼 한 줄에 할일 하나.
+//! 우선순위 (A)/(B)/(C)는 높음/보통/낮음, 완료 날짜는 수정 시각, `+프로젝트`는 프로젝트,
+//! `@컨텍스트`는 태그가 된다. 두 형식이 모두 표현하는 필드는 내보냈다 가져와도 그대로 남는다.
+//! 제목 안의 `+낱말`, `@낱말`, `due:`, `pri:`처럼 메타데이터로 읽힐 낱말은 앞에 `\`를 붙여 내보낸다.
+
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, Utc};
+use std::io::Write;
+
+use crate::error::{Result, TaskyError};
+use crate::models::{Priority, Status, Todo};
+use crate::utils::{self, Clock};
+
+fn parse_priority(letter: char) -> Option<Priority> {
+  match letter {
+    'A' => Some(Priority::High),
+    'B' => Some(Priority::Medium),
+    // D~Z는 가장 낮은 우선순위로
+    'C'..='Z' => Some(Priority::Low),
+    _ => None,
+  }
+}
+
+fn priority_letter(priority: Priority) -> char {
+  match priority {
+    Priority::High => 'A',
+    Priority::Medium => 'B',
+    Priority::Low => 'C',
+  }
+}
+
+/// `(A)` 형식의 우선순위
+fn parse_priority_token(token: &str) -> Option<Priority> {
+  let mut chars = token.chars();
+  match (chars.next(), chars.next(), chars.next(), chars.next()) {
+    (Some('('), Some(letter), Some(')'), None) => parse_priority(letter),
+    _ => None,
+  }
+}
+
+fn parse_day(token: &str) -> Option<DateTime<Utc>> {
+  NaiveDate::parse_from_str(token, "%Y-%m-%d").ok().map(utils::day_start)
+}
+
+fn format_day(at: &DateTime<Utc>) -> String {
+  at.with_timezone(&Local).format("%Y-%m-%d").to_string()
+}
+
+/// 한 줄을 할일로 바꿈. 날짜가 없으면 `clock`의 현재 시각
+pub fn parse_line(line: &str, clock: &dyn Clock) -> Result<Todo> {
+  let invalid = |message: String| TaskyError::InvalidImport { message };
+  let mut tokens = line.split_whitespace().peekable();
+
+  let done = tokens.next_if_eq(&"x").is_some();
+  let mut priority = if done { None } else { tokens.peek().and_then(|t| parse_priority_token(t)) };
+  if priority.is_some() {
+    tokens.next();
+  }
+
+  // 완료된 할일은 완료 날짜, 생성 날짜 순
+  let mut dates = Vec::new();
+  while dates.len() < if done { 2 } else { 1 } {
+    match tokens.peek().and_then(|t| parse_day(t)) {
+      Some(date) => {
+        dates.push(date);
+        tokens.next();
+      }
+      None => break,
+    }
+  }
+  let (completed_at, created_at) = match (done, dates.as_slice()) {
+    (true, [completed, created]) => (Some(*completed), Some(*created)),
+    (true, [completed]) => (Some(*completed), None),
+    (false, [created]) => (None, Some(*created)),
+    _ => (None, None),
+  };
+
+  let mut todo = Todo {
+    id: None,
+    title: String::new(),
+    description: None,
+    priority: Priority::default(),
+    status: if done { Status::Done } else { Status::Pending },
+    created_at: created_at.unwrap_or_else(|| clock.now()),
+    updated_at: completed_at.or(created_at).unwrap_or_else(|| clock.now()),
+    due_date: None,
+    due_all_day: true,
+    tags: Vec::new(),
+    project: None,
+    parent_id: None,
+    series_id: None,
+    recurrence: None,
+    deleted_at: None,
+    archived_at: None,
+    uuid: String::new(),
+  };
+
+  // 알아보지 못한 토큰은 제목에 남겨서 잃지 않게 함 (두 번째 이후의 +프로젝트 등)
+  let mut words = Vec::new();
+  for token in tokens {
+    if let Some(project) = token.strip_prefix('+').filter(|p| !p.is_empty() && todo.project.is_none()) {
+      todo.project = Some(project.to_string());
+    } else if let Some(tag) = token.strip_prefix('@').filter(|t| !t.is_empty()) {
+      todo.tags.push(tag.to_string());
+    } else if let Some(due) = token.strip_prefix("due:") {
+      if let Some(day) = parse_day(due) {
+        todo.due_date = Some(day);
+      } else {
+        let naive = NaiveDateTime::parse_from_str(due, "%Y-%m-%dT%H:%M")
+          .map_err(|_| invalid(format!("잘못된 마감일: {}", token)))?;
+        todo.due_date = Some(utils::date::local_to_utc(naive, due)?);
+        todo.due_all_day = false;
+      }
+    } else if let Some(letter) = token.strip_prefix("pri:").filter(|_| priority.is_none()) {
+      priority = letter.chars().next().filter(|_| letter.len() == 1).and_then(parse_priority);
+      if priority.is_none() {
+        words.push(token);
+      }
+    } else {
+      // 내보낼 때 붙인 `\`를 뗌
+      words.push(token.strip_prefix('\\').unwrap_or(token));
+    }
+  }
+
+  if words.is_empty() {
+    return Err(invalid("제목이 없습니다".to_string()));
+  }
+  todo.title = words.join(" ");
+  todo.priority = priority.unwrap_or_default();
+  Ok(todo)
+}
+
+/// 파일 전체를 읽음. 빈 줄은 건너뛰고, 잘못된 줄이 있으면 줄 번호와 함께 오류
+pub fn read_todotxt(source: &str, clock: &dyn Clock) -> Result<Vec<Todo>> {
+  source
+    .lines()
+    .enumerate()
+    .filter(|(_, line)| !line.trim().is_empty())
+    .map(|(index, line)| {
+      parse_line(line, clock).map_err(|e| {
+        let message = match e {
+          TaskyError::InvalidImport { message } => message,
+          other => other.to_string(),
+        };
+        TaskyError::InvalidImport { message: format!("{}행: {}", index + 1, message) }
+      })
+    })
+    .collect()
+}
+
+/// 다시 읽을 때 메타데이터나 이스케이프로 읽힐 제목 낱말에 `\`를 붙임
+fn escape_title_word(word: &str) -> String {
+  let metadata = ["+", "@"].iter().any(|prefix| word.len() > 1 && word.starts_with(prefix))
+    || ["due:", "pri:", "\\"].iter().any(|prefix| word.starts_with(prefix));
+  match metadata {
+    true => format!("\\{}", word),
+    false => word.to_string(),
+  }
+}
+
+pub fn format_line(todo: &Todo) -> String {
+  let mut parts = Vec::new();
+
+  // 완료된 할일은 줄 앞에 우선순위를 쓰지 않고 pri: 키로 남김
+  match todo.status {
+    Status::Done => {
+      parts.push("x".to_string());
+      parts.push(format_day(&todo.updated_at));
+    }
+    Status::Pending => parts.push(format!("({})", priority_letter(todo.priority))),
+  }
+  parts.push(format_day(&todo.created_at));
+
+  parts.push(todo.title.split(' ').map(escape_title_word).collect::<Vec<_>>().join(" "));
+
+  if let Some(project) = &todo.project {
+    parts.push(format!("+{}", project));
+  }
+  parts.extend(todo.tags.iter().map(|tag| format!("@{}", tag)));
+  if let Some(due) = &todo.due_date {
+    match todo.due_all_day {
+      true => parts.push(format!("due:{}", format_day(due))),
+      false => parts.push(format!("due:{}", due.with_timezone(&Local).format("%Y-%m-%dT%H:%M"))),
+    }
+  }
+  if todo.status == Status::Done {
+    parts.push(format!("pri:{}", priority_letter(todo.priority)));
+  }
+
+  parts.join(" ")
+}
+
+pub fn write_todotxt(mut writer: impl Write, todos: &[Todo]) -> Result<()> {
+  for todo in todos {
+    writeln!(writer, "{}", format_line(todo))?;
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::utils::FixedClock;
+
+  #[test]
+  fn test_parse_line() {
+    let clock = FixedClock::parse("2026-10-18 09:00").unwrap();
+
+    let todo = parse_line("(A) 2026-10-01 보고서 제출 +work @office @Home due:2026-10-20 +extra", &clock).unwrap();
+    assert_eq!(todo.title, "보고서 제출 +extra");
+    assert_eq!(todo.priority, Priority::High);
+    assert_eq!(todo.status, Status::Pending);
+    assert_eq!(todo.created_at, parse_day("2026-10-01").unwrap());
+    assert_eq!(todo.project.as_deref(), Some("work"));
+    assert_eq!(todo.tags, vec!["office", "Home"]);
+    assert_eq!(todo.due_date, parse_day("2026-10-20"));
+    assert!(todo.due_all_day);
+
+    let done = parse_line("x 2026-10-15 2026-10-01 회의록 pri:C", &clock).unwrap();
+    assert_eq!(done.status, Status::Done);
+    assert_eq!(done.priority, Priority::Low);
+    assert_eq!(done.updated_at, parse_day("2026-10-15").unwrap());
+    assert_eq!(done.created_at, parse_day("2026-10-01").unwrap());
+
+    // 날짜와 우선순위가 없으면 현재 시각과 보통
+    let plain = parse_line("장보기 due:2026-10-20T18:30", &clock).unwrap();
+    assert_eq!(plain.priority, Priority::Medium);
+    assert_eq!(plain.created_at, clock.now());
+    assert!(!plain.due_all_day);
+
+    // 소문자 x나 괄호 없는 글자는 제목
+    assert_eq!(parse_line("xylophone (a) 연습", &clock).unwrap().title, "xylophone (a) 연습");
+    assert!(parse_line("회의 due:someday", &clock).is_err());
+    assert!(parse_line("(A) 2026-10-01 +work", &clock).is_err());
+  }
+
+  #[test]
+  fn test_round_trip() {
+    let clock = FixedClock::parse("2026-10-18 09:00").unwrap();
+    let lines = [
+      "(A) 2026-10-01 보고서 제출 +work.backend @office due:2026-10-20",
+      "x 2026-10-15 2026-10-01 회의록 정리 @meeting pri:B",
+      "(C) 2026-10-18 장보기 due:2026-10-20T18:30",
+      "(A) 2026-10-01 report \\+extra +work",
+    ];
+
+    for line in lines {
+      assert_eq!(format_line(&parse_line(line, &clock).unwrap()), line);
+    }
+
+    // 제목에 메타데이터처럼 보이는 낱말이 있어도 다시 읽으면 같은 할일
+    let todo = parse_line("(A) 2026-10-01 report +work +extra", &clock).unwrap();
+    let reread = parse_line(&format_line(&todo), &clock).unwrap();
+    assert_eq!((reread.title.as_str(), reread.project.as_deref()), ("report +extra", Some("work")));
+    assert_eq!(format_line(&reread), format_line(&todo));
+
+    for title in ["email @bob about due:friday", "C++ +1 pri:A 검토", "\\server\\share 정리", "a + b @ c"] {
+      let mut todo = parse_line("(B) 2026-10-01 임시", &clock).unwrap();
+      todo.title = title.to_string();
+      let reread = parse_line(&format_line(&todo), &clock).unwrap();
+      assert_eq!(reread.title, title);
+      assert!(reread.tags.is_empty() && reread.project.is_none() && reread.due_date.is_none());
+      assert_eq!(reread.priority, Priority::Medium);
+    }
+
+    let source = format!("{}\n\n{}\n", lines[0], lines[1]);
+    assert_eq!(read_todotxt(&source, &clock).unwrap().len(), 2);
+    let broken = format!("{}\n회의 due:someday\n", lines[0]);
+    assert!(read_todotxt(&broken, &clock).unwrap_err().to_string().contains("2행"));
+  }
+}
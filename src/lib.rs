@@ -2,6 +2,7 @@
 pub mod cli;
 pub mod database;
 pub mod error;
+pub mod formats;
 pub mod models;
 pub mod services;
 pub mod utils;
@@ -12,7 +12,7 @@ fn main() {
     let cli = Cli::parse();
     
     // 명령어 실행
-    match execute(cli.command) {
+    match execute(cli) {
         Ok(_) => {}
         Err(e) => {
             eprintln!("{} {}", "오류:".red().bold(), e);
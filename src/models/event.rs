@@ -0,0 +1,176 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Result, TaskyError};
+use crate::models::Todo;
+use crate::utils::format_due;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EventKind {
+  Created,
+  Updated,
+  Completed,
+  Uncompleted,
+  // 휴지통으로 이동
+  Deleted,
+  // 휴지통에서 복원
+  Restored,
+  // 휴지통에서 영구 삭제
+  Purged,
+  Archived,
+  Unarchived
+}
+
+/// 할일 변경 이력 한 건. 수정은 바뀐 필드마다 한 건씩 기록된다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TodoEvent {
+  pub id: i64,
+  pub todo_id: i64,
+  pub kind: EventKind,
+  pub field: Option<String>,
+  pub old_value: Option<String>,
+  pub new_value: Option<String>,
+  // 변경한 사용자 (USER/USERNAME 환경변수)
+  pub actor: Option<String>,
+  pub created_at: DateTime<Utc>
+}
+
+/// 수정 전후로 달라진 필드 (필드 이름, 이전 값, 새 값)
+pub type FieldChange = (&'static str, Option<String>, Option<String>);
+
+impl EventKind {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      EventKind::Created => "created",
+      EventKind::Updated => "updated",
+      EventKind::Completed => "completed",
+      EventKind::Uncompleted => "uncompleted",
+      EventKind::Deleted => "deleted",
+      EventKind::Restored => "restored",
+      EventKind::Purged => "purged",
+      EventKind::Archived => "archived",
+      EventKind::Unarchived => "unarchived",
+    }
+  }
+
+  #[allow(clippy::should_implement_trait)]
+  pub fn from_str(source: &str) -> Result<Self> {
+    match source {
+      "created" => Ok(EventKind::Created),
+      "updated" => Ok(EventKind::Updated),
+      "completed" => Ok(EventKind::Completed),
+      "uncompleted" => Ok(EventKind::Uncompleted),
+      "deleted" => Ok(EventKind::Deleted),
+      "restored" => Ok(EventKind::Restored),
+      "purged" => Ok(EventKind::Purged),
+      "archived" => Ok(EventKind::Archived),
+      "unarchived" => Ok(EventKind::Unarchived),
+      _ => Err(TaskyError::InvalidInput { message: format!("알 수 없는 이벤트 종류: {}", source) }),
+    }
+  }
+
+  pub fn to_display_string(&self) -> &'static str {
+    match self {
+      EventKind::Created => "생성",
+      EventKind::Updated => "수정",
+      EventKind::Completed => "완료",
+      EventKind::Uncompleted => "완료 취소",
+      EventKind::Deleted => "삭제",
+      EventKind::Restored => "복원",
+      EventKind::Purged => "영구 삭제",
+      EventKind::Archived => "보관",
+      EventKind::Unarchived => "보관 해제",
+    }
+  }
+
+  pub fn to_emoji(&self) -> &'static str {
+    match self {
+      EventKind::Created => "➕",
+      EventKind::Updated => "✏️",
+      EventKind::Completed => "✅",
+      EventKind::Uncompleted => "↩️",
+      EventKind::Deleted => "🗑️",
+      EventKind::Restored => "♻️",
+      EventKind::Purged => "🔥",
+      EventKind::Archived => "📦",
+      EventKind::Unarchived => "📤",
+    }
+  }
+}
+
+impl TodoEvent {
+  pub fn field_display_name(&self) -> &str {
+    self.field.as_deref().map(field_display_name).unwrap_or("")
+  }
+}
+
+/// `field_changes`의 필드 이름을 화면 표시용으로
+pub fn field_display_name(field: &str) -> &str {
+  match field {
+    "title" => "제목",
+    "description" => "설명",
+    "priority" => "우선순위",
+    "due" => "마감일",
+    "tags" => "태그",
+    "project" => "프로젝트",
+    "status" => "상태",
+    "archived" => "보관",
+    "deleted" => "휴지통",
+    other => other,
+  }
+}
+
+/// 상태를 제외한 필드 변경 목록 (상태 변경은 완료/완료 취소 이벤트로 따로 기록)
+pub fn field_changes(before: &Todo, after: &Todo) -> Vec<FieldChange> {
+  let due = |todo: &Todo| todo.due_date.map(|d| format_due(&d, todo.due_all_day));
+  let tags = |todo: &Todo| if todo.tags.is_empty() { None } else { Some(todo.tags.join(",")) };
+  let description = |todo: &Todo| todo.description.clone().filter(|d| !d.is_empty());
+
+  let candidates = [
+    ("title", Some(before.title.clone()), Some(after.title.clone())),
+    ("description", description(before), description(after)),
+    ("priority", Some(before.priority.to_string()), Some(after.priority.to_string())),
+    ("due", due(before), due(after)),
+    ("tags", tags(before), tags(after)),
+    ("project", before.project.clone(), after.project.clone()),
+  ];
+
+  candidates
+    .into_iter()
+    .filter(|(_, old, new)| old != new)
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::models::{Priority, Status};
+
+  #[test]
+  fn test_field_changes() {
+    let before = Todo::sample("장보기");
+    let mut after = before.clone();
+    assert!(field_changes(&before, &after).is_empty());
+
+    after.title = "장보기 (마트)".to_string();
+    after.priority = Priority::High;
+    after.tags = vec!["home".to_string(), "weekly".to_string()];
+    after.status = Status::Done;
+
+    let changes = field_changes(&before, &after);
+    assert_eq!(changes, vec![
+      ("title", Some("장보기".to_string()), Some("장보기 (마트)".to_string())),
+      ("priority", Some("보통".to_string()), Some("높음".to_string())),
+      ("tags", None, Some("home,weekly".to_string())),
+    ]);
+  }
+
+  #[test]
+  fn test_event_kind_round_trip() {
+    for kind in [EventKind::Created, EventKind::Updated, EventKind::Completed, EventKind::Uncompleted, EventKind::Deleted,
+      EventKind::Restored, EventKind::Purged, EventKind::Archived, EventKind::Unarchived] {
+      assert_eq!(EventKind::from_str(kind.as_str()).unwrap(), kind);
+    }
+    assert!(EventKind::from_str("renamed").is_err());
+  }
+}
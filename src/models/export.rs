@@ -0,0 +1,160 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::{Result, TaskyError};
+use crate::models::{RecurrenceSeries, Todo};
+
+/// 내보내기 파일의 `format` 값
+pub const EXPORT_FORMAT: &str = "tasky";
+
+/// 내보내기 파일 버전. 필드를 추가하는 변경은 올리지 않고, 기존 필드의 의미가 바뀔 때만 올림
+pub const EXPORT_VERSION: u32 = 1;
+
+/// `tasky export`의 JSON 파일. 할일은 `--format json`과 같은 스키마
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportFile {
+  pub format: String,
+  pub version: u32,
+  pub exported_at: DateTime<Utc>,
+  pub todos: Vec<Todo>,
+  // 내보낸 할일끼리의 의존성만
+  #[serde(default)]
+  pub dependencies: Vec<ExportedDependency>,
+  // 내보낸 할일이 속한 반복 시리즈 (종료된 시리즈 포함)
+  #[serde(default)]
+  pub series: Vec<ExportedSeries>
+}
+
+/// `todo_id`는 `depends_on_id`가 완료되어야 시작할 수 있음
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedDependency {
+  pub todo_id: i64,
+  pub depends_on_id: i64
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedSeries {
+  pub id: i64,
+  pub rule: String,
+  pub created_at: DateTime<Utc>,
+  pub ended_at: Option<DateTime<Utc>>
+}
+
+/// 가져올 할일이 이미 있을 때 (UUID가 같은 할일, UUID가 없는 예전 파일은 ID가 같은 할일)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConflictStrategy {
+  // 하나라도 겹치면 아무것도 가져오지 않음
+  #[default]
+  Fail,
+  // 기존 할일을 그대로 두고 건너뜀
+  SkipExisting,
+  // 기존 할일을 파일 내용으로 덮어씀
+  Overwrite,
+  // 모든 할일을 새 ID의 사본으로 추가 (상위 할일, 의존성, 시리즈도 새 ID로, 겹치는 UUID는 새로)
+  Renumber
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportAction {
+  Create,
+  Overwrite,
+  Skip,
+  // ConflictStrategy::Fail에서 같은 할일이 이미 있음
+  Conflict
+}
+
+/// 가져오기 결과 (또는 --dry-run 계획)의 할일 한 건
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportItem {
+  // 파일 안의 ID
+  pub source_id: Option<i64>,
+  // 데이터베이스의 ID. 새 ID를 받을 할일은 실제로 가져오기 전까지 None
+  pub id: Option<i64>,
+  pub title: String,
+  pub action: ImportAction
+}
+
+impl ImportAction {
+  pub fn to_display_string(&self) -> &'static str {
+    match self {
+      ImportAction::Create => "추가",
+      ImportAction::Overwrite => "덮어쓰기",
+      ImportAction::Skip => "건너뜀",
+      ImportAction::Conflict => "충돌",
+    }
+  }
+}
+
+impl From<&RecurrenceSeries> for ExportedSeries {
+  fn from(series: &RecurrenceSeries) -> Self {
+    ExportedSeries {
+      id: series.id,
+      rule: series.rule.clone(),
+      created_at: series.created_at,
+      ended_at: series.ended_at,
+    }
+  }
+}
+
+impl ExportFile {
+  pub fn new(exported_at: DateTime<Utc>) -> Self {
+    ExportFile {
+      format: EXPORT_FORMAT.to_string(),
+      version: EXPORT_VERSION,
+      exported_at,
+      todos: Vec::new(),
+      dependencies: Vec::new(),
+      series: Vec::new(),
+    }
+  }
+
+  /// 파일 내용을 읽음. 형식과 버전을 먼저 확인해서 다른 파일이나 새 버전이면 알아보기 쉬운 오류를 냄
+  pub fn from_json(source: &str) -> Result<Self> {
+    let invalid = |message: String| TaskyError::InvalidImport { message };
+
+    let value: Value = serde_json::from_str(source).map_err(|e| invalid(format!("JSON이 아닙니다 ({})", e)))?;
+
+    if value.get("format").and_then(Value::as_str) != Some(EXPORT_FORMAT) {
+      return Err(invalid("tasky export로 만든 파일이 아닙니다".to_string()));
+    }
+
+    let version = value.get("version").and_then(Value::as_u64).unwrap_or(0);
+    if version == 0 || version > EXPORT_VERSION as u64 {
+      return Err(invalid(format!(
+        "지원하지 않는 버전입니다: {}. 이 프로그램은 버전 {}까지 읽을 수 있습니다",
+        version, EXPORT_VERSION
+      )));
+    }
+
+    serde_json::from_value(value).map_err(|e| invalid(e.to_string()))
+  }
+
+  pub fn to_json(&self) -> Result<String> {
+    serde_json::to_string_pretty(self).map_err(|e| TaskyError::System(e.into()))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_from_json() {
+    let now = Utc::now();
+    let file = ExportFile::new(now);
+    let parsed = ExportFile::from_json(&file.to_json().unwrap()).unwrap();
+    assert_eq!(parsed.version, EXPORT_VERSION);
+    assert!(parsed.todos.is_empty());
+
+    // 나중에 추가된 필드가 없는 파일도 읽음
+    let minimal = r#"{"format": "tasky", "version": 1, "exported_at": "2026-10-18T00:00:00Z", "todos": []}"#;
+    assert!(ExportFile::from_json(minimal).is_ok());
+
+    assert!(ExportFile::from_json("[1, 2]").is_err());
+    assert!(ExportFile::from_json("not json").is_err());
+    let newer = r#"{"format": "tasky", "version": 99, "exported_at": "2026-10-18T00:00:00Z", "todos": []}"#;
+    assert!(matches!(ExportFile::from_json(newer), Err(TaskyError::InvalidImport { .. })));
+  }
+}
@@ -0,0 +1,572 @@
+use std::ops::Range;
+
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use unicode_width::UnicodeWidthStr;
+
+use crate::error::{Result, TaskyError};
+use crate::models::{normalize_tag, Priority, SearchTerm, Status};
+use crate::utils::{day_start, parse_due, parse_duration, Clock};
+
+/// 비교 연산자. `:`는 `=`와 같고, `!=`는 파싱할 때 `not (… = …)`으로 바뀜
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+  Eq, Lt, Le, Gt, Ge
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateField {
+  Due, Created, Updated
+}
+
+/// 날짜 값. 날짜만 주면 그 하루 전체 [start, end), `+7d`나 시각을 주면 한 시점
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateValue {
+  Day { start: DateTime<Utc>, end: DateTime<Utc> },
+  Instant(DateTime<Utc>)
+}
+
+/// `is:` 조건
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TodoState {
+  Overdue, Blocked, Ready, Recurring, Subtask
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterCondition {
+  Status(Status),
+  Priority(Comparison, Priority),
+  Date(DateField, Comparison, DateValue),
+  NoDueDate,
+  Tag(String),
+  // 하위 프로젝트 포함
+  Project(String),
+  // 제목/설명에 포함된 말
+  Text(SearchTerm),
+  Is(TodoState)
+}
+
+/// `priority>=medium and (due<+7d or tag:work) and not status:done` 형식의 필터 식
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterExpr {
+  Condition(FilterCondition),
+  Not(Box<FilterExpr>),
+  And(Box<FilterExpr>, Box<FilterExpr>),
+  Or(Box<FilterExpr>, Box<FilterExpr>)
+}
+
+impl Comparison {
+  pub fn to_sql(&self) -> &'static str {
+    match self {
+      Comparison::Eq => "=",
+      Comparison::Lt => "<",
+      Comparison::Le => "<=",
+      Comparison::Gt => ">",
+      Comparison::Ge => ">=",
+    }
+  }
+}
+
+impl DateField {
+  pub fn column(&self) -> &'static str {
+    match self {
+      DateField::Due => "due_date",
+      DateField::Created => "created_at",
+      DateField::Updated => "updated_at",
+    }
+  }
+}
+
+impl DateValue {
+  fn day(date: NaiveDate) -> Self {
+    let next = date.succ_opt().unwrap_or(date);
+    DateValue::Day { start: day_start(date), end: day_start(next) }
+  }
+
+  /// 비교를 만족하는 열 값의 조건들 (연산자, 기준 시각)
+  pub fn bounds(&self, comparison: Comparison) -> Vec<(&'static str, DateTime<Utc>)> {
+    match (*self, comparison) {
+      (DateValue::Day { start, end }, Comparison::Eq) => vec![(">=", start), ("<", end)],
+      (DateValue::Day { start, .. }, Comparison::Lt) => vec![("<", start)],
+      (DateValue::Day { end, .. }, Comparison::Le) => vec![("<", end)],
+      (DateValue::Day { end, .. }, Comparison::Gt) => vec![(">=", end)],
+      (DateValue::Day { start, .. }, Comparison::Ge) => vec![(">=", start)],
+      (DateValue::Instant(at), comparison) => vec![(comparison.to_sql(), at)],
+    }
+  }
+}
+
+impl FilterExpr {
+  /// 필터 식 파싱. 상대 날짜(`today`, `+7d`)는 `clock` 기준으로 해석
+  pub fn parse(source: &str, clock: &dyn Clock) -> Result<Self> {
+    let tokens = tokenize(source)?;
+    let mut parser = Parser { source, tokens, position: 0, clock };
+
+    if parser.tokens.is_empty() {
+      return Err(parser.error_at(0..0, "필터 식이 비어 있습니다".to_string()));
+    }
+
+    let expr = parser.parse_or()?;
+    if let Some(token) = parser.peek() {
+      let span = token.span.clone();
+      let message = match token.kind {
+        TokenKind::RParen => "짝이 맞지 않는 ')'입니다".to_string(),
+        TokenKind::Operator(_) => "연산자 앞에 필드 이름이 필요합니다".to_string(),
+        _ => format!("예상하지 못한 '{}'입니다", &source[span.clone()]),
+      };
+      return Err(parser.error_at(span, message));
+    }
+
+    Ok(expr)
+  }
+
+  pub fn and(self, other: FilterExpr) -> Self {
+    FilterExpr::And(Box::new(self), Box::new(other))
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+  Colon, Eq, Ne, Lt, Le, Gt, Ge
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+  // 필드 이름 또는 (연산자 뒤의) 값
+  Word(String),
+  Operator(Operator),
+  LParen,
+  RParen,
+  And,
+  Or,
+  Not
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+  kind: TokenKind,
+  span: Range<usize>
+}
+
+fn is_operator_char(c: char) -> bool {
+  matches!(c, ':' | '=' | '<' | '>' | '!')
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>> {
+  let mut tokens: Vec<Token> = Vec::new();
+  let mut chars = source.char_indices().peekable();
+
+  while let Some(&(start, c)) = chars.peek() {
+    if c.is_whitespace() {
+      chars.next();
+      continue;
+    }
+
+    let after_operator = matches!(tokens.last(), Some(Token { kind: TokenKind::Operator(_), .. }));
+
+    // 연산자 뒤의 값은 공백이나 괄호 전까지 (`due<2026-10-20T14:30`처럼 ':'가 들어갈 수 있음)
+    if c == '"' || (after_operator && c != '(' && c != ')') {
+      let (text, end) = if c == '"' {
+        chars.next();
+        let mut text = String::new();
+        let mut end = None;
+        for (i, c) in chars.by_ref() {
+          if c == '"' {
+            end = Some(i + 1);
+            break;
+          }
+          text.push(c);
+        }
+        let end = end.ok_or_else(|| filter_error(source, start..start + 1, "닫는 따옴표가 없습니다".to_string()))?;
+        (text, end)
+      } else {
+        let mut text = String::new();
+        let mut end = start;
+        while let Some((i, c)) = chars.next_if(|&(_, c)| !c.is_whitespace() && c != '(' && c != ')') {
+          text.push(c);
+          end = i + c.len_utf8();
+        }
+        (text, end)
+      };
+      tokens.push(Token { kind: TokenKind::Word(text), span: start..end });
+      continue;
+    }
+
+    chars.next();
+    let next = chars.peek().map(|&(_, c)| c);
+    let (kind, length) = match (c, next) {
+      ('(', _) => (TokenKind::LParen, 1),
+      (')', _) => (TokenKind::RParen, 1),
+      ('&', Some('&')) => (TokenKind::And, 2),
+      ('|', Some('|')) => (TokenKind::Or, 2),
+      ('!', Some('=')) => (TokenKind::Operator(Operator::Ne), 2),
+      ('!', _) => (TokenKind::Not, 1),
+      ('<', Some('=')) => (TokenKind::Operator(Operator::Le), 2),
+      ('>', Some('=')) => (TokenKind::Operator(Operator::Ge), 2),
+      ('=', Some('=')) => (TokenKind::Operator(Operator::Eq), 2),
+      ('<', _) => (TokenKind::Operator(Operator::Lt), 1),
+      ('>', _) => (TokenKind::Operator(Operator::Gt), 1),
+      ('=', _) => (TokenKind::Operator(Operator::Eq), 1),
+      (':', _) => (TokenKind::Operator(Operator::Colon), 1),
+      _ => {
+        let mut word = c.to_string();
+        let mut end = start + c.len_utf8();
+        while let Some((i, c)) = chars.next_if(|&(_, c)| {
+          !c.is_whitespace() && !is_operator_char(c) && !matches!(c, '(' | ')' | '"')
+        }) {
+          word.push(c);
+          end = i + c.len_utf8();
+        }
+        let kind = match word.to_lowercase().as_str() {
+          "and" => TokenKind::And,
+          "or" => TokenKind::Or,
+          "not" => TokenKind::Not,
+          _ => TokenKind::Word(word),
+        };
+        tokens.push(Token { kind, span: start..end });
+        continue;
+      }
+    };
+
+    if length == 2 {
+      chars.next();
+    }
+    tokens.push(Token { kind, span: start..start + length });
+  }
+
+  Ok(tokens)
+}
+
+/// 문제가 된 부분을 `^`로 가리키는 오류
+fn filter_error(source: &str, span: Range<usize>, message: String) -> TaskyError {
+  let offset = source[..span.start].width();
+  let length = source[span.clone()].width().max(1);
+
+  TaskyError::InvalidFilter {
+    message,
+    expression: source.to_string(),
+    marker: format!("{}{}", " ".repeat(offset), "^".repeat(length)),
+  }
+}
+
+struct Parser<'a> {
+  source: &'a str,
+  tokens: Vec<Token>,
+  position: usize,
+  clock: &'a dyn Clock
+}
+
+impl<'a> Parser<'a> {
+  fn peek(&self) -> Option<&Token> {
+    self.tokens.get(self.position)
+  }
+
+  fn next(&mut self) -> Option<Token> {
+    let token = self.tokens.get(self.position).cloned();
+    self.position += 1;
+    token
+  }
+
+  fn end_span(&self) -> Range<usize> {
+    self.source.len()..self.source.len()
+  }
+
+  fn error_at(&self, span: Range<usize>, message: String) -> TaskyError {
+    filter_error(self.source, span, message)
+  }
+
+  fn parse_or(&mut self) -> Result<FilterExpr> {
+    let mut expr = self.parse_and()?;
+
+    while matches!(self.peek(), Some(Token { kind: TokenKind::Or, .. })) {
+      self.next();
+      let right = self.parse_and()?;
+      expr = FilterExpr::Or(Box::new(expr), Box::new(right));
+    }
+
+    Ok(expr)
+  }
+
+  // `and`는 생략 가능 (`tag:work priority:high`)
+  fn parse_and(&mut self) -> Result<FilterExpr> {
+    let mut expr = self.parse_unary()?;
+
+    loop {
+      match self.peek().map(|token| &token.kind) {
+        Some(TokenKind::And) => {
+          self.next();
+        }
+        Some(TokenKind::Word(_) | TokenKind::Not | TokenKind::LParen) => {}
+        _ => break,
+      }
+      let right = self.parse_unary()?;
+      expr = expr.and(right);
+    }
+
+    Ok(expr)
+  }
+
+  fn parse_unary(&mut self) -> Result<FilterExpr> {
+    if matches!(self.peek(), Some(Token { kind: TokenKind::Not, .. })) {
+      self.next();
+      let inner = self.parse_unary()?;
+      return Ok(FilterExpr::Not(Box::new(inner)));
+    }
+
+    self.parse_primary()
+  }
+
+  fn parse_primary(&mut self) -> Result<FilterExpr> {
+    let Some(token) = self.next() else {
+      return Err(self.error_at(self.end_span(), "식이 끝났습니다. 조건이 필요합니다".to_string()));
+    };
+
+    match token.kind {
+      TokenKind::LParen => {
+        let expr = self.parse_or()?;
+        match self.next() {
+          Some(Token { kind: TokenKind::RParen, .. }) => Ok(expr),
+          Some(other) => Err(self.error_at(other.span, "')'가 필요합니다".to_string())),
+          None => Err(self.error_at(token.span, "닫는 괄호가 없습니다".to_string())),
+        }
+      }
+      TokenKind::Word(field) => {
+        let operator = match self.next() {
+          Some(Token { kind: TokenKind::Operator(operator), span }) => (operator, span),
+          _ => {
+            return Err(self.error_at(
+              token.span,
+              format!("'{}' 뒤에 연산자가 필요합니다 (예: {}:값)", field, field),
+            ));
+          }
+        };
+        let value = match self.next() {
+          Some(Token { kind: TokenKind::Word(value), span }) => (value, span),
+          _ => return Err(self.error_at(operator.1, "연산자 뒤에 값이 필요합니다".to_string())),
+        };
+        self.condition((field, token.span), operator, value)
+      }
+      TokenKind::RParen => Err(self.error_at(token.span, "짝이 맞지 않는 ')'입니다".to_string())),
+      TokenKind::Operator(_) => Err(self.error_at(token.span, "연산자 앞에 필드 이름이 필요합니다".to_string())),
+      TokenKind::And | TokenKind::Or | TokenKind::Not => {
+        let keyword = self.source[token.span.clone()].to_string();
+        Err(self.error_at(token.span, format!("'{}' 앞에 조건이 필요합니다", keyword)))
+      }
+    }
+  }
+
+  fn condition(
+    &self,
+    (field, field_span): (String, Range<usize>),
+    (operator, operator_span): (Operator, Range<usize>),
+    (value, value_span): (String, Range<usize>),
+  ) -> Result<FilterExpr> {
+    let equality_only = || -> Result<bool> {
+      match operator {
+        Operator::Colon | Operator::Eq => Ok(false),
+        Operator::Ne => Ok(true),
+        _ => Err(self.error_at(
+          operator_span.clone(),
+          format!("'{}'에는 '{}' 연산자를 쓸 수 없습니다 (:, =, != 만 가능)", field, &self.source[operator_span.clone()]),
+        )),
+      }
+    };
+    let value_error = |message: String| self.error_at(value_span.clone(), message);
+
+    let (condition, negated) = match field.to_lowercase().as_str() {
+      "status" | "상태" => {
+        let negated = equality_only()?;
+        let status = Status::from_str(&value).map_err(|e| value_error(e.to_string()))?;
+        (FilterCondition::Status(status), negated)
+      }
+      "priority" | "pri" | "우선순위" => {
+        let priority = Priority::from_str(&value).map_err(|e| value_error(e.to_string()))?;
+        let (comparison, negated) = comparison(operator);
+        (FilterCondition::Priority(comparison, priority), negated)
+      }
+      "due" | "마감일" if matches!(value.to_lowercase().as_str(), "none" | "없음") => {
+        (FilterCondition::NoDueDate, equality_only()?)
+      }
+      name @ ("due" | "마감일" | "created" | "생성일" | "updated" | "수정일") => {
+        let field = match name {
+          "due" | "마감일" => DateField::Due,
+          "created" | "생성일" => DateField::Created,
+          _ => DateField::Updated,
+        };
+        let (comparison, negated) = comparison(operator);
+        let date = self.date_value(&value, comparison).ok_or_else(|| {
+          value_error(format!("잘못된 날짜 '{}' (예: today, 2026-10-20, \"next friday\", +7d, -2w)", value))
+        })?;
+        (FilterCondition::Date(field, comparison, date), negated)
+      }
+      "tag" | "태그" => {
+        let negated = equality_only()?;
+        (FilterCondition::Tag(normalize_tag(&value)), negated)
+      }
+      "project" | "프로젝트" => {
+        let negated = equality_only()?;
+        (FilterCondition::Project(value), negated)
+      }
+      "text" | "내용" => {
+        let negated = equality_only()?;
+        (FilterCondition::Text(SearchTerm { text: value, prefix: false, excluded: false }), negated)
+      }
+      "is" => {
+        let negated = equality_only()?;
+        let state = match value.to_lowercase().as_str() {
+          "overdue" | "지연" => TodoState::Overdue,
+          "blocked" | "막힘" => TodoState::Blocked,
+          "ready" | "가능" => TodoState::Ready,
+          "recurring" | "반복" => TodoState::Recurring,
+          "subtask" | "하위" => TodoState::Subtask,
+          _ => {
+            return Err(value_error(format!(
+              "알 수 없는 상태 '{}' (overdue, blocked, ready, recurring, subtask 중 하나)", value
+            )));
+          }
+        };
+        (FilterCondition::Is(state), negated)
+      }
+      _ => {
+        return Err(self.error_at(field_span, format!(
+          "알 수 없는 필드 '{}' (status, priority, due, created, updated, tag, project, text, is 중 하나)", field
+        )));
+      }
+    };
+
+    let expr = FilterExpr::Condition(condition);
+    Ok(if negated { FilterExpr::Not(Box::new(expr)) } else { expr })
+  }
+
+  /// `+7d`/`-2w`는 현재 시각 기준, 그 외는 마감일 형식. 한 시점과 같은지 비교하면 그 날짜 전체
+  fn date_value(&self, value: &str, comparison: Comparison) -> Option<DateValue> {
+    let offset = match value.chars().next() {
+      Some('+') => Some(parse_duration(&value[1..]).ok()?),
+      Some('-') => Some(-parse_duration(&value[1..]).ok()?),
+      _ => None,
+    };
+
+    let date = match offset {
+      Some(offset) => DateValue::Instant(self.clock.now().checked_add_signed(offset)?),
+      None => {
+        let due = parse_due(value, self.clock).ok()?;
+        if due.all_day {
+          DateValue::day(local_date(due.at))
+        } else {
+          DateValue::Instant(due.at)
+        }
+      }
+    };
+
+    Some(match (date, comparison) {
+      (DateValue::Instant(at), Comparison::Eq) => DateValue::day(local_date(at)),
+      _ => date,
+    })
+  }
+}
+
+fn local_date(at: DateTime<Utc>) -> NaiveDate {
+  at.with_timezone(&Local).date_naive()
+}
+
+/// `!=`는 `=`의 부정
+fn comparison(operator: Operator) -> (Comparison, bool) {
+  match operator {
+    Operator::Colon | Operator::Eq => (Comparison::Eq, false),
+    Operator::Ne => (Comparison::Eq, true),
+    Operator::Lt => (Comparison::Lt, false),
+    Operator::Le => (Comparison::Le, false),
+    Operator::Gt => (Comparison::Gt, false),
+    Operator::Ge => (Comparison::Ge, false),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::utils::FixedClock;
+
+  fn condition(condition: FilterCondition) -> FilterExpr {
+    FilterExpr::Condition(condition)
+  }
+
+  fn not(expr: FilterExpr) -> FilterExpr {
+    FilterExpr::Not(Box::new(expr))
+  }
+
+  #[test]
+  fn test_parse() {
+    let clock = FixedClock::parse("2026-10-18T09:00").unwrap();
+    let expr = FilterExpr::parse("priority>=medium and (due<+7d or tag:Work) and not status:done", &clock).unwrap();
+
+    let due = condition(FilterCondition::Date(
+      DateField::Due, Comparison::Lt, DateValue::Instant(clock.now() + chrono::Duration::days(7)),
+    ));
+    let expected = condition(FilterCondition::Priority(Comparison::Ge, Priority::Medium))
+      .and(FilterExpr::Or(Box::new(due), Box::new(condition(FilterCondition::Tag("work".to_string())))))
+      .and(not(condition(FilterCondition::Status(Status::Done))));
+    assert_eq!(expr, expected);
+
+    // and 생략, !=, 따옴표 값, 우선순위는 or보다 and가 먼저
+    let expr = FilterExpr::parse(r#"project:"홈 정리" tag!=later || is:overdue"#, &clock).unwrap();
+    let expected = FilterExpr::Or(
+      Box::new(condition(FilterCondition::Project("홈 정리".to_string()))
+        .and(not(condition(FilterCondition::Tag("later".to_string()))))),
+      Box::new(condition(FilterCondition::Is(TodoState::Overdue))),
+    );
+    assert_eq!(expr, expected);
+
+    assert_eq!(FilterExpr::parse("due:none", &clock).unwrap(), condition(FilterCondition::NoDueDate));
+  }
+
+  #[test]
+  fn test_date_values() {
+    let clock = FixedClock::parse("2026-10-18T09:00").unwrap();
+    let today = DateValue::day(clock.today());
+    let date = |source: &str| match FilterExpr::parse(source, &clock).unwrap() {
+      FilterExpr::Condition(FilterCondition::Date(_, _, value)) => value,
+      other => panic!("날짜 조건이 아님: {:?}", other),
+    };
+
+    assert_eq!(date("due:today"), today);
+    assert_eq!(date("created<=2026-10-18"), today);
+    // 시점과 같은지 비교하면 그 날짜 전체
+    assert_eq!(date("updated=+0d"), today);
+    assert_eq!(date("due>-12h"), DateValue::Instant(clock.now() - chrono::Duration::hours(12)));
+
+    let DateValue::Day { start, end } = today else { unreachable!() };
+    assert_eq!(today.bounds(Comparison::Le), vec![("<", end)]);
+    assert_eq!(today.bounds(Comparison::Gt), vec![(">=", end)]);
+    assert_eq!(today.bounds(Comparison::Eq), vec![(">=", start), ("<", end)]);
+  }
+
+  #[test]
+  fn test_parse_errors() {
+    let clock = FixedClock::parse("2026-10-18T09:00").unwrap();
+    let marker = |source: &str| match FilterExpr::parse(source, &clock) {
+      Err(TaskyError::InvalidFilter { marker, .. }) => marker,
+      other => panic!("오류가 아님: {:?}", other),
+    };
+
+    assert_eq!(marker("prio>=high"), "^^^^");
+    assert_eq!(marker("status:done and tag<work"), "                   ^");
+    assert_eq!(marker("priority:urgent"), "         ^^^^^^");
+    assert_eq!(marker("(tag:work or due<soon"), "                 ^^^^");
+    assert_eq!(marker("(tag:work or is:ready"), "^");
+    assert_eq!(marker("tag:work)"), "        ^");
+    assert_eq!(marker("태그:일 and"), "           ^");
+    assert_eq!(marker("work"), "^^^^");
+    assert_eq!(marker(""), "^");
+
+    // 남은 토큰은 종류에 맞는 오류
+    let message = |source: &str| match FilterExpr::parse(source, &clock) {
+      Err(TaskyError::InvalidFilter { message, .. }) => message,
+      other => panic!("오류가 아님: {:?}", other),
+    };
+    assert_eq!(marker("tag:work = x"), "         ^");
+    assert_eq!(message("tag:work = x"), "연산자 앞에 필드 이름이 필요합니다");
+    assert_eq!(message("tag:work)"), "짝이 맞지 않는 ')'입니다");
+
+    // 범위를 넘는 상대 날짜는 값 오류
+    assert_eq!(marker("due<+99999999w"), "    ^^^^^^^^^^");
+  }
+}
@@ -0,0 +1,43 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::Todo;
+
+/// 할일 한 건의 전체 상태 (태그, 프로젝트, 양방향 의존성 포함)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TodoSnapshot {
+  pub todo: Todo,
+  // 이 할일이 기다리는 할일
+  pub depends_on: Vec<i64>,
+  // 이 할일을 기다리는 할일
+  pub dependents: Vec<i64>
+}
+
+/// 작업 하나가 할일 한 건에 남긴 변경. before가 없으면 생성, after가 없으면 영구 삭제
+/// (휴지통으로 옮긴 것은 deleted_at만 다른 수정)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperationChange {
+  pub todo_id: i64,
+  pub before: Option<TodoSnapshot>,
+  pub after: Option<TodoSnapshot>
+}
+
+/// 되돌리기 단위가 되는 명령 하나 (예: `done 1 2 3`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Operation {
+  pub id: i64,
+  pub label: String,
+  pub created_at: DateTime<Utc>,
+  pub undone_at: Option<DateTime<Utc>>,
+  pub changes: Vec<OperationChange>
+}
+
+impl OperationChange {
+  pub fn title(&self) -> &str {
+    self.after
+      .as_ref()
+      .or(self.before.as_ref())
+      .map(|s| s.todo.title.as_str())
+      .unwrap_or("")
+  }
+}
@@ -0,0 +1,244 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::models::{field_changes, EventKind, Todo, TodoEvent};
+use crate::utils::format_due;
+
+/// 병합하는 두 데이터베이스 중 한쪽
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeSide {
+  // 지금 쓰는 데이터베이스
+  Local,
+  // `merge`에 준 데이터베이스
+  Other
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeAction {
+  // 다른 쪽에만 있는 할일
+  Create,
+  // 다른 쪽 값을 하나 이상 가져옴
+  Update,
+  // 값은 다르지만 모두 이쪽 값이 이김
+  Keep
+}
+
+/// 두 쪽 값이 다른 필드 하나
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldMerge {
+  pub field: String,
+  pub local: Option<String>,
+  pub other: Option<String>,
+  pub winner: MergeSide,
+  // 두 쪽이 각자 바꾼 값이라 나중에 바뀐 쪽을 골랐음
+  pub conflict: bool
+}
+
+/// 병합 결과 (또는 --dry-run 계획)의 할일 한 건. 두 쪽이 같은 할일은 나오지 않음
+#[derive(Debug, Clone, Serialize)]
+pub struct MergeItem {
+  pub uuid: String,
+  // 이 데이터베이스의 ID. 새로 추가할 할일은 실제로 합치기 전까지 None
+  pub id: Option<i64>,
+  pub title: String,
+  pub action: MergeAction,
+  pub fields: Vec<FieldMerge>
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MergeReport {
+  pub items: Vec<MergeItem>,
+  // 다른 쪽에서 가져온 의존성 수
+  pub dependencies: usize
+}
+
+impl MergeAction {
+  pub fn to_display_string(&self) -> &'static str {
+    match self {
+      MergeAction::Create => "추가",
+      MergeAction::Update => "수정",
+      MergeAction::Keep => "유지",
+    }
+  }
+}
+
+impl MergeSide {
+  pub fn to_display_string(&self) -> &'static str {
+    match self {
+      MergeSide::Local => "이쪽",
+      MergeSide::Other => "다른 쪽",
+    }
+  }
+}
+
+impl MergeReport {
+  pub fn count(&self, action: MergeAction) -> usize {
+    self.items.iter().filter(|item| item.action == action).count()
+  }
+
+  pub fn conflicts(&self) -> usize {
+    self.items.iter().flat_map(|item| &item.fields).filter(|field| field.conflict).count()
+  }
+}
+
+/// 상태처럼 값 대신 이벤트로만 기록되는 필드와 그 이벤트 종류
+const STATE_FIELDS: [(&str, [EventKind; 2]); 3] = [
+  ("status", [EventKind::Completed, EventKind::Uncompleted]),
+  ("archived", [EventKind::Archived, EventKind::Unarchived]),
+  ("deleted", [EventKind::Deleted, EventKind::Restored]),
+];
+
+fn state_value(todo: &Todo, field: &str) -> Option<String> {
+  match field {
+    "status" => Some(todo.status.to_string()),
+    "archived" => todo.archived_at.map(|at| format_due(&at, false)),
+    _ => todo.deleted_at.map(|at| format_due(&at, false)),
+  }
+}
+
+/// 한 쪽 할일과 그 할일의 변경 이력
+struct Side<'a> {
+  todo: &'a Todo,
+  events: &'a [&'a TodoEvent]
+}
+
+impl Side<'_> {
+  fn field_events<'b>(&'b self, field: &'b str) -> impl Iterator<Item = &'b TodoEvent> + 'b {
+    self.events.iter().copied().filter(move |event| event.kind == EventKind::Updated && event.field.as_deref() == Some(field))
+  }
+
+  /// 이 필드가 한때 `value`였는지
+  fn had_value(&self, field: &str, value: &Option<String>) -> bool {
+    self.field_events(field).any(|event| &event.old_value == value || &event.new_value == value)
+  }
+
+  /// 이 필드를 마지막으로 바꾼 시각. 이력이 없으면 할일의 수정 시각
+  fn changed_at(&self, field: &str) -> DateTime<Utc> {
+    self.field_events(field).map(|event| event.created_at).max().unwrap_or(self.todo.updated_at)
+  }
+
+  fn state_changed_at(&self, kinds: &[EventKind]) -> DateTime<Utc> {
+    self.events.iter().filter(|event| kinds.contains(&event.kind)).map(|event| event.created_at).max().unwrap_or(self.todo.updated_at)
+  }
+}
+
+/// 같은 할일의 두 쪽 값을 필드별로 비교. 한 쪽 값이 다른 쪽 이력에 있으면 그쪽이 뒤처진 것이므로
+/// 다른 쪽 값을 쓰고, 두 쪽이 각자 바꿨으면 나중에 바꾼 값을 쓰고 충돌로 표시.
+/// 상태, 보관, 휴지통은 나중에 바뀐 쪽을 씀
+pub fn merge_fields(local: &Todo, local_events: &[&TodoEvent], other: &Todo, other_events: &[&TodoEvent]) -> Vec<FieldMerge> {
+  let local_side = Side { todo: local, events: local_events };
+  let other_side = Side { todo: other, events: other_events };
+  let mut fields = Vec::new();
+
+  for (field, local_value, other_value) in field_changes(local, other) {
+    let (winner, conflict) = match (local_side.had_value(field, &other_value), other_side.had_value(field, &local_value)) {
+      (true, false) => (MergeSide::Local, false),
+      (false, true) => (MergeSide::Other, false),
+      _ if other_side.changed_at(field) > local_side.changed_at(field) => (MergeSide::Other, true),
+      _ => (MergeSide::Local, true),
+    };
+    fields.push(FieldMerge { field: field.to_string(), local: local_value, other: other_value, winner, conflict });
+  }
+
+  for (field, kinds) in STATE_FIELDS {
+    let (local_value, other_value) = (state_value(local, field), state_value(other, field));
+    // 보관/삭제 시각만 다른 것은 같은 상태
+    let differs = match field {
+      "status" => local_value != other_value,
+      _ => local_value.is_some() != other_value.is_some(),
+    };
+    if differs {
+      let winner = match other_side.state_changed_at(&kinds) > local_side.state_changed_at(&kinds) {
+        true => MergeSide::Other,
+        false => MergeSide::Local,
+      };
+      fields.push(FieldMerge { field: field.to_string(), local: local_value, other: other_value, winner, conflict: false });
+    }
+  }
+
+  fields
+}
+
+/// 이쪽 할일에 다른 쪽이 이긴 필드를 옮긴 결과
+pub fn merged_todo(local: &Todo, other: &Todo, fields: &[FieldMerge]) -> Todo {
+  let mut merged = local.clone();
+  for field in fields.iter().filter(|field| field.winner == MergeSide::Other) {
+    match field.field.as_str() {
+      "title" => merged.title = other.title.clone(),
+      "description" => merged.description = other.description.clone(),
+      "priority" => merged.priority = other.priority,
+      "due" => {
+        merged.due_date = other.due_date;
+        merged.due_all_day = other.due_all_day;
+      }
+      "tags" => merged.tags = other.tags.clone(),
+      "project" => merged.project = other.project.clone(),
+      "status" => merged.status = other.status,
+      "archived" => merged.archived_at = other.archived_at,
+      "deleted" => merged.deleted_at = other.deleted_at,
+      _ => {}
+    }
+    merged.updated_at = merged.updated_at.max(other.updated_at);
+  }
+  merged
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::models::Status;
+  use chrono::TimeZone;
+
+  fn todo(title: &str, updated_day: u32) -> Todo {
+    let mut todo = Todo::sample(title);
+    todo.created_at = Utc.with_ymd_and_hms(2026, 10, updated_day, 0, 0, 0).unwrap();
+    todo.updated_at = todo.created_at;
+    todo.uuid = "3f6c1a2e-9b4d-4e7a-8c5f-0d1e2f3a4b5c".to_string();
+    todo
+  }
+
+  fn event(kind: EventKind, field: Option<&str>, old: Option<&str>, new: Option<&str>, day: u32) -> TodoEvent {
+    TodoEvent {
+      id: 0,
+      todo_id: 1,
+      kind,
+      field: field.map(str::to_string),
+      old_value: old.map(str::to_string),
+      new_value: new.map(str::to_string),
+      actor: None,
+      created_at: Utc.with_ymd_and_hms(2026, 10, day, 0, 0, 0).unwrap(),
+    }
+  }
+
+  #[test]
+  fn test_merge_fields() {
+    let local = todo("보고서", 1);
+    let mut other = todo("보고서 초안", 5);
+    other.status = Status::Done;
+    let renamed = event(EventKind::Updated, Some("title"), Some("보고서"), Some("보고서 초안"), 5);
+    let completed = event(EventKind::Completed, None, None, None, 5);
+
+    // 다른 쪽만 바꿨으면 충돌 없이 다른 쪽 값
+    let fields = merge_fields(&local, &[], &other, &[&renamed, &completed]);
+    assert_eq!(fields.len(), 2);
+    assert!(fields.iter().all(|field| field.winner == MergeSide::Other && !field.conflict));
+    let merged = merged_todo(&local, &other, &fields);
+    assert_eq!(merged.title, "보고서 초안");
+    assert_eq!(merged.status, Status::Done);
+    assert_eq!(merged.updated_at, other.updated_at);
+
+    // 두 쪽이 각자 바꿨으면 나중에 바꾼 쪽이 이기고 충돌
+    let local = todo("최종 보고서", 7);
+    let local_renamed = event(EventKind::Updated, Some("title"), Some("보고서"), Some("최종 보고서"), 7);
+    let fields = merge_fields(&local, &[&local_renamed], &other, &[&renamed]);
+    let title = fields.iter().find(|field| field.field == "title").unwrap();
+    assert_eq!(title.winner, MergeSide::Local);
+    assert!(title.conflict);
+    assert_eq!(title.other.as_deref(), Some("보고서 초안"));
+
+    // 같은 할일이면 아무것도 없음
+    assert!(merge_fields(&other, &[], &other.clone(), &[]).is_empty());
+  }
+}
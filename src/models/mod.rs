@@ -1,5 +1,27 @@
+pub mod event;
+pub mod export;
+pub mod filter;
+pub mod journal;
+pub mod merge;
+pub mod project;
+pub mod recurrence;
+pub mod search;
 pub mod todo;
+pub mod view;
 
 pub use todo::{
-  CreateTodo, Priority, SortBy, SortOrder, Status, Todo, TodoFilter, TodoStats, UpdateTodo,
-};
\ No newline at end of file
+  normalize_tag, CreateTodo, OverdueCutoff, Priority, SortBy, SortOrder, Status, TagStats, Todo,
+  TodoFilter, TodoStats, UpdateTodo,
+};
+pub use filter::{Comparison, DateField, DateValue, FilterCondition, FilterExpr, TodoState};
+pub use event::{field_changes, field_display_name, EventKind, FieldChange, TodoEvent};
+pub use export::{
+  ConflictStrategy, ExportFile, ExportedDependency, ExportedSeries, ImportAction, ImportItem, EXPORT_FORMAT,
+  EXPORT_VERSION,
+};
+pub use journal::{Operation, OperationChange, TodoSnapshot};
+pub use merge::{merge_fields, merged_todo, FieldMerge, MergeAction, MergeItem, MergeReport, MergeSide};
+pub use project::{is_in_project, project_ancestors, validate_project_name, ProjectStats};
+pub use recurrence::{RecurrenceRule, RecurrenceSeries};
+pub use search::{SearchQuery, SearchTerm};
+pub use view::{validate_view_name, SavedView, ViewDefinition, BUILTIN_VIEWS};
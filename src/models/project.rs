@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Result, TaskyError};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectStats {
+  pub name: String,
+  // 하위 프로젝트의 할일까지 포함한 개수
+  pub pending_todos: i64,
+  pub completed_todos: i64
+}
+
+/// 프로젝트 이름 검증 (`work.backend`처럼 점으로 하위 프로젝트 구분)
+pub fn validate_project_name(name: &str) -> Result<()> {
+  let invalid = |message: &str| Err(TaskyError::InvalidInput {
+    message: format!("잘못된 프로젝트 이름 '{}': {}", name, message),
+  });
+
+  if name.is_empty() {
+    return invalid("비어있을 수 없습니다");
+  }
+
+  if name.chars().count() > 100 {
+    return invalid("100자를 초과할 수 없습니다");
+  }
+
+  if name.split('.').any(|part| part.is_empty()) {
+    return invalid("점(.)으로 시작하거나 끝나거나 연속될 수 없습니다");
+  }
+
+  if !name.chars().all(|c| c.is_alphanumeric() || c == '.' || c == '-' || c == '_') {
+    return invalid("문자, 숫자, '-', '_', '.'만 사용할 수 있습니다");
+  }
+
+  Ok(())
+}
+
+/// `a.b.c` -> [`a`, `a.b`, `a.b.c`]
+pub fn project_ancestors(name: &str) -> Vec<String> {
+  let parts: Vec<&str> = name.split('.').collect();
+  (1..=parts.len()).map(|n| parts[..n].join(".")).collect()
+}
+
+/// `project`가 `scope` 자신이거나 그 하위 프로젝트인지 확인
+pub fn is_in_project(project: &str, scope: &str) -> bool {
+  project == scope
+    || (project.starts_with(scope) && project[scope.len()..].starts_with('.'))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_validate_project_name() {
+    assert!(validate_project_name("work").is_ok());
+    assert!(validate_project_name("work.backend").is_ok());
+    assert!(validate_project_name("회사.백엔드").is_ok());
+    assert!(validate_project_name("").is_err());
+    assert!(validate_project_name(".work").is_err());
+    assert!(validate_project_name("work..backend").is_err());
+    assert!(validate_project_name("work backend").is_err());
+  }
+
+  #[test]
+  fn test_project_hierarchy() {
+    assert_eq!(project_ancestors("a.b.c"), vec!["a", "a.b", "a.b.c"]);
+    assert!(is_in_project("work.backend", "work"));
+    assert!(is_in_project("work", "work"));
+    assert!(!is_in_project("workshop", "work"));
+    assert!(!is_in_project("work", "work.backend"));
+  }
+}
@@ -0,0 +1,255 @@
+use chrono::{DateTime, Datelike, Duration, Local, Months, NaiveDate, TimeZone, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::error::{Result, TaskyError};
+
+/// 반복 규칙 (`daily`, `weekly:mon,thu`, `monthly:last`, `every 3 days` 등)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecurrenceRule {
+  Days(u32),
+  Weeks(u32),
+  Weekdays(Vec<Weekday>),
+  Months(u32),
+  MonthDay(u32),
+  MonthLast
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecurrenceSeries {
+  pub id: i64,
+  pub rule: String,
+  pub title: String,
+  pub active: bool,
+  pub total_todos: i64,
+  pub completed_todos: i64,
+  // 대기중인 회차의 마감일
+  pub next_due: Option<DateTime<Utc>>,
+  pub created_at: DateTime<Utc>,
+  pub ended_at: Option<DateTime<Utc>>
+}
+
+/// `every N days|weeks|months`의 최대 N
+const MAX_INTERVAL: u32 = 1000;
+
+impl RecurrenceRule {
+  pub fn parse(source: &str) -> Result<Self> {
+    let invalid = || TaskyError::InvalidRecurrence { rule: source.to_string() };
+    let rule = source.trim().to_lowercase();
+
+    match rule.as_str() {
+      "daily" | "매일" => return Ok(RecurrenceRule::Days(1)),
+      "weekly" | "매주" => return Ok(RecurrenceRule::Weeks(1)),
+      "monthly" | "매월" | "매달" => return Ok(RecurrenceRule::Months(1)),
+      _ => {}
+    }
+
+    if let Some(days) = rule.strip_prefix("weekly:") {
+      let mut weekdays = days
+        .split(',')
+        .map(|d| parse_weekday(d.trim()).ok_or_else(invalid))
+        .collect::<Result<Vec<_>>>()?;
+      weekdays.sort_by_key(|d| d.num_days_from_monday());
+      weekdays.dedup();
+      return Ok(RecurrenceRule::Weekdays(weekdays));
+    }
+
+    if let Some(day) = rule.strip_prefix("monthly:") {
+      if day.trim() == "last" || day.trim() == "말일" {
+        return Ok(RecurrenceRule::MonthLast);
+      }
+      return match day.trim().parse::<u32>() {
+        Ok(day) if (1..=31).contains(&day) => Ok(RecurrenceRule::MonthDay(day)),
+        _ => Err(invalid()),
+      };
+    }
+
+    if let Some(rest) = rule.strip_prefix("every ") {
+      let parts: Vec<&str> = rest.split_whitespace().collect();
+      let (count, unit) = match parts.as_slice() {
+        [unit] => (1, *unit),
+        [count, unit] => (count.parse::<u32>().map_err(|_| invalid())?, *unit),
+        _ => return Err(invalid()),
+      };
+
+      if count == 0 || count > MAX_INTERVAL {
+        return Err(invalid());
+      }
+
+      return match unit {
+        "day" | "days" => Ok(RecurrenceRule::Days(count)),
+        "week" | "weeks" => Ok(RecurrenceRule::Weeks(count)),
+        "month" | "months" => Ok(RecurrenceRule::Months(count)),
+        _ => Err(invalid()),
+      };
+    }
+
+    Err(invalid())
+  }
+
+  /// 주어진 날짜 다음 회차의 날짜. 표현할 수 있는 날짜 범위를 넘으면 None
+  pub fn next_date(&self, date: NaiveDate) -> Option<NaiveDate> {
+    match self {
+      RecurrenceRule::Days(n) => date.checked_add_signed(Duration::days(*n as i64)),
+      RecurrenceRule::Weeks(n) => date.checked_add_signed(Duration::weeks(*n as i64)),
+      RecurrenceRule::Weekdays(weekdays) => (1..=7)
+        .filter_map(|offset| date.checked_add_signed(Duration::days(offset)))
+        .find(|d| weekdays.contains(&d.weekday()))
+        .or_else(|| date.checked_add_signed(Duration::weeks(1))),
+      RecurrenceRule::Months(n) => date.checked_add_months(Months::new(*n)),
+      RecurrenceRule::MonthDay(day) => {
+        let this_month = clamp_day(date.year(), date.month(), *day)?;
+        if this_month > date {
+          Some(this_month)
+        } else {
+          let next = first_of_next_month(date)?;
+          clamp_day(next.year(), next.month(), *day)
+        }
+      }
+      RecurrenceRule::MonthLast => {
+        let this_month = last_day_of_month(date.year(), date.month())?;
+        if this_month > date {
+          Some(this_month)
+        } else {
+          let next = first_of_next_month(date)?;
+          last_day_of_month(next.year(), next.month())
+        }
+      }
+    }
+  }
+
+  /// 다음 마감일 계산 (로컬 시간 기준으로 날짜만 옮기고 시각은 유지). 범위를 넘으면 None
+  pub fn next_due(&self, due: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let local = due.with_timezone(&Local).naive_local();
+    let next = self.next_date(local.date())?.and_time(local.time());
+
+    Local
+      .from_local_datetime(&next)
+      .earliest()
+      .map(|dt| dt.with_timezone(&Utc))
+      .or_else(|| due.checked_add_signed(Duration::days(1)))
+  }
+}
+
+impl fmt::Display for RecurrenceRule {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      RecurrenceRule::Days(1) => write!(f, "daily"),
+      RecurrenceRule::Days(n) => write!(f, "every {} days", n),
+      RecurrenceRule::Weeks(1) => write!(f, "weekly"),
+      RecurrenceRule::Weeks(n) => write!(f, "every {} weeks", n),
+      RecurrenceRule::Weekdays(weekdays) => {
+        let names: Vec<&str> = weekdays.iter().map(|d| weekday_name(*d)).collect();
+        write!(f, "weekly:{}", names.join(","))
+      }
+      RecurrenceRule::Months(1) => write!(f, "monthly"),
+      RecurrenceRule::Months(n) => write!(f, "every {} months", n),
+      RecurrenceRule::MonthDay(day) => write!(f, "monthly:{}", day),
+      RecurrenceRule::MonthLast => write!(f, "monthly:last"),
+    }
+  }
+}
+
+fn parse_weekday(source: &str) -> Option<Weekday> {
+  match source {
+    "mon" | "monday" | "월" => Some(Weekday::Mon),
+    "tue" | "tuesday" | "화" => Some(Weekday::Tue),
+    "wed" | "wednesday" | "수" => Some(Weekday::Wed),
+    "thu" | "thursday" | "목" => Some(Weekday::Thu),
+    "fri" | "friday" | "금" => Some(Weekday::Fri),
+    "sat" | "saturday" | "토" => Some(Weekday::Sat),
+    "sun" | "sunday" | "일" => Some(Weekday::Sun),
+    _ => None,
+  }
+}
+
+fn weekday_name(weekday: Weekday) -> &'static str {
+  match weekday {
+    Weekday::Mon => "mon",
+    Weekday::Tue => "tue",
+    Weekday::Wed => "wed",
+    Weekday::Thu => "thu",
+    Weekday::Fri => "fri",
+    Weekday::Sat => "sat",
+    Weekday::Sun => "sun",
+  }
+}
+
+fn first_of_next_month(date: NaiveDate) -> Option<NaiveDate> {
+  date.with_day(1)?.checked_add_months(Months::new(1))
+}
+
+fn last_day_of_month(year: i32, month: u32) -> Option<NaiveDate> {
+  first_of_next_month(NaiveDate::from_ymd_opt(year, month, 1)?)?.pred_opt()
+}
+
+/// 해당 월에 없는 날짜(예: 2월 31일)는 말일로 맞춤
+fn clamp_day(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+  let last = last_day_of_month(year, month)?;
+  last.with_day(day.min(last.day()))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+  }
+
+  #[test]
+  fn test_parse_and_display() {
+    let cases = [
+      ("daily", "daily"),
+      ("매일", "daily"),
+      ("weekly", "weekly"),
+      ("weekly:thu,mon", "weekly:mon,thu"),
+      ("weekly:월,금", "weekly:mon,fri"),
+      ("monthly", "monthly"),
+      ("monthly:last", "monthly:last"),
+      ("monthly:15", "monthly:15"),
+      ("every 3 days", "every 3 days"),
+      ("Every 2 Weeks", "every 2 weeks"),
+      ("every month", "monthly"),
+    ];
+
+    for (input, expected) in cases {
+      let rule = RecurrenceRule::parse(input).unwrap();
+      assert_eq!(rule.to_string(), expected, "입력: {}", input);
+      assert_eq!(RecurrenceRule::parse(expected).unwrap(), rule);
+    }
+
+    assert!(RecurrenceRule::parse("sometimes").is_err());
+    assert!(RecurrenceRule::parse("every 0 days").is_err());
+    assert!(RecurrenceRule::parse("weekly:xyz").is_err());
+    assert!(RecurrenceRule::parse("monthly:32").is_err());
+    assert!(RecurrenceRule::parse("every 1000 days").is_ok());
+    assert!(RecurrenceRule::parse("every 1001 days").is_err());
+    assert!(RecurrenceRule::parse("every 4000000000 months").is_err());
+  }
+
+  #[test]
+  fn test_next_date() {
+    // 2026-10-15는 목요일
+    let thu = date(2026, 10, 15);
+
+    assert_eq!(RecurrenceRule::Days(3).next_date(thu), Some(date(2026, 10, 18)));
+    assert_eq!(RecurrenceRule::Weeks(1).next_date(thu), Some(date(2026, 10, 22)));
+
+    let mon_thu = RecurrenceRule::parse("weekly:mon,thu").unwrap();
+    assert_eq!(mon_thu.next_date(thu), Some(date(2026, 10, 19)));
+    assert_eq!(mon_thu.next_date(date(2026, 10, 19)), Some(date(2026, 10, 22)));
+
+    assert_eq!(RecurrenceRule::Months(1).next_date(date(2026, 1, 31)), Some(date(2026, 2, 28)));
+    assert_eq!(RecurrenceRule::MonthLast.next_date(date(2026, 1, 31)), Some(date(2026, 2, 28)));
+    assert_eq!(RecurrenceRule::MonthLast.next_date(date(2026, 2, 10)), Some(date(2026, 2, 28)));
+    assert_eq!(RecurrenceRule::MonthDay(31).next_date(date(2026, 1, 31)), Some(date(2026, 2, 28)));
+    assert_eq!(RecurrenceRule::MonthDay(15).next_date(date(2026, 12, 20)), Some(date(2027, 1, 15)));
+
+    // 표현할 수 있는 마지막 날짜 뒤는 없음
+    assert_eq!(RecurrenceRule::Days(1).next_date(NaiveDate::MAX), None);
+    assert_eq!(RecurrenceRule::Months(1000).next_date(NaiveDate::MAX), None);
+    assert_eq!(RecurrenceRule::MonthLast.next_date(NaiveDate::MAX), None);
+    assert_eq!(RecurrenceRule::parse("weekly:mon").unwrap().next_date(NaiveDate::MAX), None);
+  }
+}
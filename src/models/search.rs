@@ -0,0 +1,217 @@
+use std::ops::Range;
+
+use crate::error::{Result, TaskyError};
+use crate::models::Todo;
+
+// trigram 토크나이저가 색인으로 찾을 수 있는 최소 글자 수
+const TRIGRAM_LENGTH: usize = 3;
+
+/// 검색어 하나. `"여러 단어"`는 구절, `접두*`는 단어 시작, `-제외`는 포함하지 않아야 하는 말
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchTerm {
+  pub text: String,
+  pub prefix: bool,
+  pub excluded: bool
+}
+
+/// 제목과 설명에 대한 검색 조건. 모든 검색어를 만족해야 일치 (대소문자 무시)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchQuery {
+  pub terms: Vec<SearchTerm>
+}
+
+impl SearchTerm {
+  /// FTS5 색인으로 찾을 수 있는지 (두 글자 이하는 LIKE로 찾음)
+  pub fn uses_index(&self) -> bool {
+    self.text.chars().count() >= TRIGRAM_LENGTH
+  }
+
+  /// FTS5 MATCH용 구절. trigram 토크나이저에서는 부분 문자열 일치
+  pub fn fts_phrase(&self) -> String {
+    format!("\"{}\"", self.text.replace('"', "\"\""))
+  }
+
+  /// `LIKE ... ESCAPE '\'`용 패턴
+  pub fn like_pattern(&self) -> String {
+    let escaped = self.text.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+    format!("%{}%", escaped)
+  }
+
+  /// 텍스트 안에서 이 검색어와 일치하는 위치 (접두 검색어는 단어 시작 위치만)
+  pub fn find_in(&self, text: &str) -> Vec<Range<usize>> {
+    let mut ranges = find_matches(text, &self.text);
+    if self.prefix {
+      ranges.retain(|range| {
+        !matches!(text[..range.start].chars().next_back(), Some(c) if c.is_alphanumeric())
+      });
+    }
+    ranges
+  }
+}
+
+impl SearchQuery {
+  /// `회의 "주간 보고" deploy* -초안` 형식의 검색어 파싱
+  pub fn parse(source: &str) -> Result<Self> {
+    let mut terms = Vec::new();
+    let mut chars = source.chars().peekable();
+
+    loop {
+      while chars.next_if(|c| c.is_whitespace()).is_some() {}
+      let Some(&first) = chars.peek() else {
+        break;
+      };
+
+      let excluded = first == '-';
+      if excluded {
+        chars.next();
+      }
+
+      let quoted = chars.next_if_eq(&'"').is_some();
+      let mut text = String::new();
+      if quoted {
+        // 닫는 따옴표가 없으면 끝까지 구절로 취급
+        text.extend(chars.by_ref().take_while(|&c| c != '"'));
+      } else {
+        while let Some(c) = chars.next_if(|c| !c.is_whitespace()) {
+          text.push(c);
+        }
+      }
+
+      let text = text.trim();
+      let (text, prefix) = match text.strip_suffix('*') {
+        Some(stripped) if !quoted => (stripped, true),
+        _ => (text, false),
+      };
+
+      if !text.is_empty() {
+        terms.push(SearchTerm { text: text.to_string(), prefix, excluded });
+      }
+    }
+
+    if terms.is_empty() {
+      return Err(TaskyError::InvalidInput { message: "검색어를 입력해주세요.".to_string() });
+    }
+
+    Ok(Self { terms })
+  }
+
+  /// 순위(bm25) 계산용 MATCH 식. 색인으로 찾을 수 있는 포함 검색어가 없으면 None
+  pub fn match_expression(&self) -> Option<String> {
+    let phrases: Vec<String> = self.terms
+      .iter()
+      .filter(|term| !term.excluded && term.uses_index())
+      .map(SearchTerm::fts_phrase)
+      .collect();
+
+    if phrases.is_empty() {
+      None
+    } else {
+      Some(phrases.join(" AND "))
+    }
+  }
+
+  pub fn matches(&self, todo: &Todo) -> bool {
+    self.terms.iter().all(|term| {
+      let found = !term.find_in(&todo.title).is_empty()
+        || todo.description.as_deref().is_some_and(|d| !term.find_in(d).is_empty());
+      found != term.excluded
+    })
+  }
+
+  /// 포함 검색어와 일치하는 부분을 `paint`로 감싼 텍스트
+  pub fn highlight(&self, text: &str, paint: impl Fn(&str) -> String) -> String {
+    let mut ranges: Vec<Range<usize>> = self.terms
+      .iter()
+      .filter(|term| !term.excluded)
+      .flat_map(|term| term.find_in(text))
+      .collect();
+    ranges.sort_by_key(|range| range.start);
+
+    let mut result = String::new();
+    let mut position = 0;
+    for range in ranges {
+      // 겹치는 일치는 이어서 칠함
+      let start = range.start.max(position);
+      if start >= range.end {
+        continue;
+      }
+      result.push_str(&text[position..start]);
+      result.push_str(&paint(&text[start..range.end]));
+      position = range.end;
+    }
+    result.push_str(&text[position..]);
+
+    result
+  }
+}
+
+fn fold(c: char) -> char {
+  c.to_lowercase().next().unwrap_or(c)
+}
+
+/// 대소문자를 무시하고 찾은 모든 일치 위치 (바이트 범위)
+pub fn find_matches(text: &str, needle: &str) -> Vec<Range<usize>> {
+  let haystack: Vec<(usize, char)> = text.char_indices().collect();
+  let needle: Vec<char> = needle.chars().map(fold).collect();
+
+  if needle.is_empty() || needle.len() > haystack.len() {
+    return Vec::new();
+  }
+
+  (0..=haystack.len() - needle.len())
+    .filter(|&start| (0..needle.len()).all(|i| fold(haystack[start + i].1) == needle[i]))
+    .map(|start| {
+      let end = haystack.get(start + needle.len()).map(|(i, _)| *i).unwrap_or(text.len());
+      haystack[start].0..end
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse() {
+    let query = SearchQuery::parse(r#"회의 "weekly report" deploy* -"초안 작성" -draft"#).unwrap();
+    let summary: Vec<(&str, bool, bool)> = query.terms
+      .iter()
+      .map(|t| (t.text.as_str(), t.prefix, t.excluded))
+      .collect();
+
+    assert_eq!(summary, vec![
+      ("회의", false, false),
+      ("weekly report", false, false),
+      ("deploy", true, false),
+      ("초안 작성", false, true),
+      ("draft", false, true),
+    ]);
+    assert_eq!(query.match_expression().as_deref(), Some(r#""weekly report" AND "deploy""#));
+
+    assert!(SearchQuery::parse("   ").is_err());
+    assert!(SearchQuery::parse("\"\" -").is_err());
+  }
+
+  #[test]
+  fn test_matches() {
+    let mut report = Todo::sample("주간 회의 준비");
+    report.description = Some("Deployment checklist 검토".to_string());
+
+    assert!(SearchQuery::parse("회의").unwrap().matches(&report));
+    assert!(SearchQuery::parse("deploy*").unwrap().matches(&report));
+    assert!(SearchQuery::parse("DEPLOYMENT").unwrap().matches(&report));
+    assert!(!SearchQuery::parse("ploy*").unwrap().matches(&report));
+    assert!(!SearchQuery::parse("회의 -검토").unwrap().matches(&report));
+    assert!(SearchQuery::parse("\"회의 준비\"").unwrap().matches(&report));
+    assert!(!SearchQuery::parse("\"준비 회의\"").unwrap().matches(&report));
+  }
+
+  #[test]
+  fn test_highlight() {
+    let query = SearchQuery::parse("회의 의 준").unwrap();
+    let paint = |s: &str| format!("[{}]", s);
+
+    assert_eq!(query.highlight("주간 회의 준비", paint), "주간 [회의] [준]비");
+    assert_eq!(SearchQuery::parse("Plan").unwrap().highlight("plan B, PLAN c", paint), "[plan] B, [PLAN] c");
+  }
+}
@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 
 use crate::error::{Result, TaskyError};
+use crate::models::{FilterExpr, RecurrenceRule, SearchQuery};
+use crate::utils::Clock;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Todo {
@@ -14,7 +16,22 @@ pub struct Todo {
   pub status: Status,
   pub created_at: DateTime<Utc>,
   pub updated_at: DateTime<Utc>,
-  pub due_date: Option<DateTime<Utc>>
+  pub due_date: Option<DateTime<Utc>>,
+  // 종일 마감이면 true (due_date는 로컬 자정), 시각이 지정된 마감이면 false
+  pub due_all_day: bool,
+  pub tags: Vec<String>,
+  pub project: Option<String>,
+  pub parent_id: Option<i64>,
+  pub series_id: Option<i64>,
+  // 진행 중인 반복 시리즈의 규칙 (종료된 시리즈면 None)
+  pub recurrence: Option<String>,
+  // 휴지통으로 옮겨진 시각 (휴지통에 없으면 None)
+  pub deleted_at: Option<DateTime<Utc>>,
+  // 보관한 시각. 완료 상태와 별개로, 보관된 할일은 기본 목록과 통계에서 빠짐
+  pub archived_at: Option<DateTime<Utc>>,
+  // 데이터베이스 사이에서도 겹치지 않는 식별자. 예전 내보내기 파일과 저널에는 없어서 빈 문자열
+  #[serde(default)]
+  pub uuid: String
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -35,7 +52,15 @@ pub struct CreateTodo {
   pub title: String,
   pub description: Option<String>,
   pub priority: Priority,
-  pub due_date: Option<DateTime<Utc>>
+  pub due_date: Option<DateTime<Utc>>,
+  pub due_all_day: bool,
+  pub tags: Vec<String>,
+  pub project: Option<String>,
+  pub parent_id: Option<i64>,
+  // 새 반복 시리즈 시작
+  pub recurrence: Option<RecurrenceRule>,
+  // 기존 시리즈의 다음 회차로 생성할 때 지정
+  pub series_id: Option<i64>
 }
 
 #[derive(Debug, Clone, Default)]
@@ -44,7 +69,14 @@ pub struct UpdateTodo {
   pub description: Option<String>,
   pub priority: Option<Priority>,
   pub status: Option<Status>,
-  pub due_date: Option<DateTime<Utc>>
+  pub due_date: Option<DateTime<Utc>>,
+  pub due_all_day: Option<bool>,
+  // Some이면 태그 목록 전체를 교체
+  pub tags: Option<Vec<String>>,
+  // Some(None)이면 프로젝트 해제
+  pub project: Option<Option<String>>,
+  // Some(true)면 보관, Some(false)면 보관 해제
+  pub archived: Option<bool>
 }
 
 #[derive(Debug, Clone, Default)]
@@ -54,10 +86,30 @@ pub struct TodoFilter {
   pub created_before: Option<DateTime<Utc>>,
   pub created_after: Option<DateTime<Utc>>,
   pub due_before: Option<DateTime<Utc>>,
-  pub due_after: Option<DateTime<Utc>>
+  pub due_after: Option<DateTime<Utc>>,
+  // 모든 태그를 가진 할일만
+  pub tags: Vec<String>,
+  // 하나라도 가진 할일은 제외
+  pub without_tags: Vec<String>,
+  // 기한이 지난 대기 할일만 (OverdueCutoff 기준)
+  pub overdue: bool,
+  // 하위 프로젝트 포함
+  pub project: Option<String>,
+  // Some(true): 완료되지 않은 선행 할일이 있는 할일, Some(false): 바로 시작 가능한 대기 할일
+  pub blocked: Option<bool>,
+  // true면 휴지통의 할일만, false면 휴지통에 없는 할일만
+  pub trashed: bool,
+  // 보관된 할일도 포함
+  pub include_archived: bool,
+  // 이 시각 전에 완료된 할일만 (마지막 완료 이력 기준, 이력이 없으면 수정일)
+  pub completed_before: Option<DateTime<Utc>>,
+  // 제목/설명 전문 검색
+  pub text: Option<SearchQuery>,
+  // 필터 식 (다른 조건과 AND)
+  pub expression: Option<FilterExpr>
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SortBy {
   CreatedAt,
   UpdatedAt,
@@ -66,7 +118,7 @@ pub enum SortBy {
   Title
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SortOrder {
   Asc, Desc
 }
@@ -78,10 +130,22 @@ pub struct TodoStats {
   pub completed_todos: i64,
   pub high_priority_todos: i64,
   pub overdue_todos: i64,
-  pub completion_rate: f64
+  pub completion_rate: f64,
+  // 범위 안의 보관된 할일 수 (보관된 할일을 포함하지 않은 통계에서도 항상 계산)
+  pub archived_todos: i64,
+  pub tag_stats: Vec<TagStats>
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagStats {
+  pub tag: String,
+  pub total_todos: i64,
+  pub pending_todos: i64,
+  pub completed_todos: i64
 }
 
 impl Priority {
+  #[allow(clippy::should_implement_trait)]
   pub fn from_str(source: &str) -> Result<Self> {
     match source.to_lowercase().as_str() {
       "low" | "낮음" | "l" => Ok(Priority::Low),
@@ -91,6 +155,15 @@ impl Priority {
     }
   }
 
+  /// `from_str`이 받는 영문 이름
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Priority::Low => "low",
+      Priority::Medium => "medium",
+      Priority::High => "high",
+    }
+  }
+
   pub fn to_display_string(&self) -> &'static str {
     match self {
         Priority::Low => "낮음",
@@ -114,6 +187,7 @@ impl fmt::Display for Priority {
   }
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for Priority {
   fn default() -> Self {
     Priority::Medium
@@ -121,6 +195,7 @@ impl Default for Priority {
 }
 
 impl Status {
+  #[allow(clippy::should_implement_trait)]
   pub fn from_str(source: &str) -> Result<Self> {
     match source.to_lowercase().as_str() {
       "pending" | "대기" | "p" => Ok(Status::Pending),
@@ -129,6 +204,14 @@ impl Status {
     }
   }
 
+  /// `from_str`이 받는 영문 이름
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Status::Pending => "pending",
+      Status::Done => "done",
+    }
+  }
+
   pub fn to_display_string(&self) -> &'static str {
     match self {
       Status::Pending => "대기중",
@@ -150,6 +233,7 @@ impl fmt::Display for Status {
   }
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for Status {
   fn default() -> Self {
       Status::Pending
@@ -157,6 +241,7 @@ impl Default for Status {
 }
 
 impl SortBy {
+  #[allow(clippy::should_implement_trait)]
   pub fn from_str(source: &str) -> Result<Self> {
     match source.to_lowercase().as_str() {
         "created" | "created_at" => Ok(SortBy::CreatedAt),
@@ -169,6 +254,7 @@ impl SortBy {
   }
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for SortBy {
     fn default() -> Self {
         SortBy::CreatedAt
@@ -176,6 +262,7 @@ impl Default for SortBy {
 }
 
 impl SortOrder {
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(source: &str) -> Result<Self> {
       match source.to_lowercase().as_str() {
           "asc" | "ascending" => Ok(SortOrder::Asc),
@@ -185,6 +272,7 @@ impl SortOrder {
     }
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for SortOrder {
   fn default() -> Self {
       SortOrder::Desc
@@ -197,7 +285,13 @@ impl CreateTodo {
       title,
       description: None,
       priority: Priority::default(),
-      due_date: None
+      due_date: None,
+      due_all_day: true,
+      tags: Vec::new(),
+      project: None,
+      parent_id: None,
+      recurrence: None,
+      series_id: None
     }
   }
 
@@ -213,29 +307,116 @@ impl CreateTodo {
 
   pub fn with_due_date (mut self, due_date: DateTime<Utc>) -> Self {
     self.due_date = Some(due_date);
+    self.due_all_day = true;
+    self
+  }
+
+  pub fn with_due_time(mut self, due_at: DateTime<Utc>) -> Self {
+    self.due_date = Some(due_at);
+    self.due_all_day = false;
+    self
+  }
+
+  pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+    self.tags = tags;
+    self
+  }
+
+  pub fn with_project(mut self, project: String) -> Self {
+    self.project = Some(project);
+    self
+  }
+
+  pub fn with_parent(mut self, parent_id: i64) -> Self {
+    self.parent_id = Some(parent_id);
+    self
+  }
+
+  pub fn with_recurrence(mut self, recurrence: RecurrenceRule) -> Self {
+    self.recurrence = Some(recurrence);
     self
   }
 }
 
+/// 태그 정규화: 앞뒤 공백과 '#' 접두사를 제거하고 소문자로 변환
+pub fn normalize_tag(tag: &str) -> String {
+  tag.trim().trim_start_matches('#').to_lowercase()
+}
+
+/// 기한 초과의 단일 기준. 종일 마감은 마감일 다음 날 0시(로컬)부터,
+/// 시각이 있는 마감은 그 시각부터 기한 초과로 본다.
+#[derive(Debug, Clone, Copy)]
+pub struct OverdueCutoff {
+  pub now: DateTime<Utc>,
+  pub today_start: DateTime<Utc>
+}
+
+impl OverdueCutoff {
+  /// SQL 조건식 (파라미터: today_start, now)
+  pub const SQL: &'static str =
+    "(status = 0 AND due_date IS NOT NULL AND ((due_all_day = 1 AND due_date < ?) OR (due_all_day = 0 AND due_date < ?)))";
+
+  pub fn at(clock: &dyn Clock) -> Self {
+    Self {
+      now: clock.now(),
+      today_start: clock.today_start(),
+    }
+  }
+
+  pub fn is_overdue(&self, due: DateTime<Utc>, all_day: bool) -> bool {
+    if all_day {
+      due < self.today_start
+    } else {
+      due < self.now
+    }
+  }
+}
+
 impl Todo {
-  pub fn is_overdue(&self) -> bool {
+  pub fn is_overdue(&self, clock: &dyn Clock) -> bool {
     if self.status == Status::Done {
       return false;
     }
 
-    if let Some(due_date) = self.due_date {
-      let today_start = crate::utils::today_start();
-      // 오늘보다 이전 날짜만 overdue로 처리 (오늘은 제외)
-      due_date < today_start
-    } else {
-      false
+    match self.due_date {
+      Some(due_date) => OverdueCutoff::at(clock).is_overdue(due_date, self.due_all_day),
+      None => false,
     }
   }
 
-  pub fn days_until_due(&self) -> Option<i64> {
+  pub fn days_until_due(&self, clock: &dyn Clock) -> Option<i64> {
     self.due_date.map(|due: DateTime<Utc>| {
-      let today_start = crate::utils::today_start();
+      let today_start = clock.today_start();
       (due - today_start).num_days()
     })
   }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+impl Todo {
+  /// 테스트용 할일. ID는 1, 시각은 2026-10-18 00:00 UTC이고 나머지는 기본값
+  pub(crate) fn sample(title: &str) -> Self {
+    use chrono::TimeZone;
+
+    let at = Utc.with_ymd_and_hms(2026, 10, 18, 0, 0, 0).unwrap();
+    Todo {
+      id: Some(1),
+      title: title.to_string(),
+      description: None,
+      priority: Priority::default(),
+      status: Status::default(),
+      created_at: at,
+      updated_at: at,
+      due_date: None,
+      due_all_day: true,
+      tags: Vec::new(),
+      project: None,
+      parent_id: None,
+      series_id: None,
+      recurrence: None,
+      deleted_at: None,
+      archived_at: None,
+      uuid: String::new(),
+    }
+  }
+}
@@ -0,0 +1,236 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Result, TaskyError};
+use crate::models::{FilterExpr, Priority, SearchQuery, SortBy, SortOrder, Status, TodoFilter};
+use crate::utils::Clock;
+
+/// 기본 제공 보기 (저장하거나 삭제할 수 없음)
+pub const BUILTIN_VIEWS: &[&str] = &["today", "overdue", "urgent"];
+
+// `view save`, `view list`처럼 하위 명령과 겹치는 이름
+const RESERVED_VIEW_NAMES: &[&str] = &["save", "list", "delete"];
+
+/// `list`의 필터/정렬 옵션 묶음. 필터 식은 실행할 때마다 현재 시각 기준으로 다시 해석
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ViewDefinition {
+  // 모두 만족해야 함 (각각 따로 파싱해 오류 위치를 정확히 가리킴)
+  pub expressions: Vec<String>,
+  pub status: Option<Status>,
+  pub priority: Option<Priority>,
+  pub tags: Vec<String>,
+  pub without_tags: Vec<String>,
+  pub project: Option<String>,
+  // Some(true): --blocked, Some(false): --ready
+  pub blocked: Option<bool>,
+  pub archived: bool,
+  pub grep: Option<String>,
+  pub sort: Option<SortBy>,
+  pub order: Option<SortOrder>
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedView {
+  pub name: String,
+  pub definition: ViewDefinition,
+  // 기본 보기는 None
+  pub updated_at: Option<DateTime<Utc>>
+}
+
+impl ViewDefinition {
+  pub fn builtin(name: &str) -> Option<Self> {
+    let expression = match name {
+      "today" => "due:today",
+      "overdue" => "is:overdue",
+      "urgent" => "status:pending and priority:high",
+      _ => return None,
+    };
+
+    // 마감일이 가까운 순
+    let mut definition = ViewDefinition::default();
+    definition.expressions.push(expression.to_string());
+    definition.sort = Some(SortBy::DueDate);
+    definition.order = Some(SortOrder::Asc);
+    Some(definition)
+  }
+
+  pub fn is_empty(&self) -> bool {
+    *self == ViewDefinition::default()
+  }
+
+  /// 두 보기의 조건을 모두 적용. 정렬처럼 하나만 쓸 수 있는 값은 `self`가 우선
+  pub fn merge(mut self, other: ViewDefinition) -> Self {
+    self.expressions.extend(other.expressions);
+    self.status = self.status.or(other.status);
+    self.priority = self.priority.or(other.priority);
+    self.tags.extend(other.tags);
+    self.without_tags.extend(other.without_tags);
+    self.project = self.project.or(other.project);
+    self.blocked = self.blocked.or(other.blocked);
+    self.archived |= other.archived;
+    self.grep = match (self.grep, other.grep) {
+      // 검색어는 모두 만족해야 하므로 이어 붙이면 됨
+      (Some(mine), Some(theirs)) => Some(format!("{} {}", mine, theirs)),
+      (mine, theirs) => mine.or(theirs),
+    };
+    self.sort = self.sort.or(other.sort);
+    self.order = self.order.or(other.order);
+    self
+  }
+
+  /// 조회 조건으로 변환. 상대 날짜는 `clock` 기준
+  #[allow(clippy::field_reassign_with_default)]
+  pub fn resolve(&self, clock: &dyn Clock) -> Result<(TodoFilter, SortBy, SortOrder)> {
+    let mut filter = TodoFilter::default();
+    filter.status = self.status;
+    filter.priority = self.priority;
+    filter.tags = self.tags.clone();
+    filter.without_tags = self.without_tags.clone();
+    filter.project = self.project.clone();
+    filter.blocked = self.blocked;
+    filter.include_archived = self.archived;
+    filter.text = self.grep.as_deref().map(SearchQuery::parse).transpose()?;
+    filter.expression = self.expressions
+      .iter()
+      .map(|expression| FilterExpr::parse(expression, clock))
+      .collect::<Result<Vec<_>>>()?
+      .into_iter()
+      .reduce(FilterExpr::and);
+
+    Ok((filter, self.sort.unwrap_or_default(), self.order.unwrap_or_default()))
+  }
+
+  /// 같은 조건의 `list` 인자 (보기 목록 표시용)
+  pub fn to_args(&self) -> Vec<String> {
+    let mut args: Vec<String> = self.expressions.iter().map(|e| quote(e)).collect();
+
+    if let Some(status) = self.status {
+      args.push(format!("-s {}", status.as_str()));
+    }
+    if let Some(priority) = self.priority {
+      args.push(format!("-p {}", priority.as_str()));
+    }
+    args.extend(self.tags.iter().map(|tag| format!("--tag {}", quote(tag))));
+    args.extend(self.without_tags.iter().map(|tag| format!("--without-tag {}", quote(tag))));
+    if let Some(project) = &self.project {
+      args.push(format!("--project {}", quote(project)));
+    }
+    match self.blocked {
+      Some(true) => args.push("--blocked".to_string()),
+      Some(false) => args.push("--ready".to_string()),
+      None => {}
+    }
+    if self.archived {
+      args.push("--archived".to_string());
+    }
+    if let Some(grep) = &self.grep {
+      args.push(format!("--grep {}", quote(grep)));
+    }
+    if let Some(sort) = self.sort {
+      let sort = match sort {
+        SortBy::CreatedAt => "created",
+        SortBy::UpdatedAt => "updated",
+        SortBy::DueDate => "due",
+        SortBy::Priority => "priority",
+        SortBy::Title => "title",
+      };
+      args.push(format!("--sort {}", sort));
+    }
+    if let Some(order) = self.order {
+      let order = match order {
+        SortOrder::Asc => "asc",
+        SortOrder::Desc => "desc",
+      };
+      args.push(format!("--order {}", order));
+    }
+
+    args
+  }
+}
+
+// 공백이나 셸 특수문자가 있으면 작은따옴표로 감쌈
+fn quote(value: &str) -> String {
+  if value.chars().all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | ':' | '+')) {
+    value.to_string()
+  } else {
+    format!("'{}'", value.replace('\'', "'\\''"))
+  }
+}
+
+/// 보기 이름 검증
+pub fn validate_view_name(name: &str) -> Result<()> {
+  let invalid = |message: &str| Err(TaskyError::InvalidInput {
+    message: format!("잘못된 보기 이름 '{}': {}", name, message),
+  });
+
+  if name.is_empty() {
+    return invalid("비어있을 수 없습니다");
+  }
+
+  if name.chars().count() > 50 {
+    return invalid("50자를 초과할 수 없습니다");
+  }
+
+  if !name.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_') {
+    return invalid("문자, 숫자, '-', '_'만 사용할 수 있습니다");
+  }
+
+  if BUILTIN_VIEWS.contains(&name) {
+    return invalid("기본 보기 이름입니다");
+  }
+
+  if RESERVED_VIEW_NAMES.contains(&name) {
+    return invalid("view 하위 명령 이름입니다");
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::utils::FixedClock;
+
+  #[test]
+  fn test_merge_and_resolve() {
+    let mut mine = ViewDefinition::default();
+    mine.expressions.push("tag:work".to_string());
+    mine.priority = Some(Priority::High);
+    mine.grep = Some("회의".to_string());
+    mine.sort = Some(SortBy::Title);
+
+    let mut other = ViewDefinition::builtin("today").unwrap();
+    other.grep = Some("-초안".to_string());
+    let merged = mine.merge(other);
+
+    assert_eq!(merged.expressions, vec!["tag:work", "due:today"]);
+    assert_eq!(merged.grep.as_deref(), Some("회의 -초안"));
+    assert_eq!(merged.sort, Some(SortBy::Title));
+    assert_eq!(merged.order, Some(SortOrder::Asc));
+    assert_eq!(
+      merged.to_args().join(" "),
+      "tag:work due:today -p high --grep '회의 -초안' --sort title --order asc"
+    );
+
+    let clock = FixedClock::parse("2026-10-18T09:00").unwrap();
+    let (filter, sort_by, sort_order) = merged.resolve(&clock).unwrap();
+    assert!(matches!(filter.expression, Some(FilterExpr::And(_, _))));
+    assert_eq!(filter.text.map(|q| q.terms.len()), Some(2));
+    assert_eq!((sort_by, sort_order), (SortBy::Title, SortOrder::Asc));
+
+    let mut broken = ViewDefinition::default();
+    broken.expressions.push("due<someday".to_string());
+    assert!(broken.resolve(&clock).is_err());
+  }
+
+  #[test]
+  fn test_validate_view_name() {
+    assert!(validate_view_name("work-this-week").is_ok());
+    assert!(validate_view_name("이번주").is_ok());
+    assert!(validate_view_name("").is_err());
+    assert!(validate_view_name("my view").is_err());
+    assert!(validate_view_name("today").is_err());
+    assert!(validate_view_name("list").is_err());
+  }
+}
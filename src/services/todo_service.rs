@@ -1,40 +1,88 @@
-use crate::database::{Database, SqliteTodoRepository, TodoRepository};
+use chrono::{DateTime, Utc};
+use std::cell::Cell;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use crate::database::{
+  migrations, Database, JournalRepository, MergeRepository, SqliteJournalRepository, SqliteMergeRepository,
+  SqliteTodoRepository, SqliteViewRepository, TodoRepository, ViewRepository,
+};
 use crate::error::{Result, TaskyError};
 use crate::models::{
-  CreateTodo, Priority, SortBy, SortOrder, Status, Todo, TodoFilter, TodoStats, UpdateTodo,
+  merge_fields, merged_todo, normalize_tag, validate_project_name, validate_view_name, ConflictStrategy, CreateTodo,
+  ExportFile, ExportedDependency, ExportedSeries, ImportAction, ImportItem, MergeAction, MergeItem, MergeReport,
+  MergeSide, Operation, OperationChange, ProjectStats,
+  RecurrenceRule, RecurrenceSeries, SavedView, SearchQuery, TodoSnapshot, SortBy, SortOrder, Status, Todo, TodoEvent, TodoFilter,
+  TodoStats, UpdateTodo, ViewDefinition, BUILTIN_VIEWS,
 };
-use crate::utils;
+use crate::utils::{Clock, SharedClock};
 
 pub trait TodoService {
     
   fn create_todo(&self, create_todo: CreateTodo) -> Result<Todo>;
+  fn validate_create_todo(&self, todo: &CreateTodo) -> Result<()>;
   fn get_todo_by_id(&self, id: i64) -> Result<Todo>;
+  fn resolve_todo_id(&self, reference: &str) -> Result<i64>;
   fn list_todos(&self, filter: Option<TodoFilter>, sort_by: Option<SortBy>, sort_order: Option<SortOrder>,) -> Result<Vec<Todo>>;
+  fn search_todos(&self, query: &SearchQuery, limit: usize) -> Result<Vec<Todo>>;
+  fn list_view(&self, definition: &ViewDefinition) -> Result<Vec<Todo>>;
+  fn save_view(&self, name: &str, definition: &ViewDefinition) -> Result<bool>;
+  fn find_view(&self, name: &str) -> Result<SavedView>;
+  fn list_views(&self) -> Result<Vec<SavedView>>;
+  fn delete_view(&self, name: &str) -> Result<()>;
   fn update_todo(&self, id: i64, update_todo: UpdateTodo) -> Result<Todo>;
   fn delete_todo(&self, id: i64) -> Result<bool>;
+  fn list_trash(&self) -> Result<Vec<Todo>>;
+  fn restore_todo(&self, id: i64) -> Result<Todo>;
+  fn purge_todo(&self, id: i64) -> Result<usize>;
+  fn purge_trash(&self, deleted_before: Option<DateTime<Utc>>) -> Result<usize>;
   fn complete_todo(&self, id: i64) -> Result<Todo>;
+  fn complete_todo_cascade(&self, id: i64) -> Result<Todo>;
   fn uncomplete_todo(&self, id: i64) -> Result<Todo>;
-  fn get_stats(&self) -> Result<TodoStats>;
+  fn archive_todo(&self, id: i64) -> Result<Todo>;
+  fn archive_done_before(&self, cutoff: DateTime<Utc>) -> Result<Vec<Todo>>;
+  fn unarchive_todo(&self, id: i64) -> Result<Todo>;
+  fn get_stats(&self, project: Option<&str>, include_archived: bool) -> Result<TodoStats>;
+  fn list_projects(&self, include_archived: bool) -> Result<Vec<ProjectStats>>;
+  fn get_subtasks(&self, id: i64) -> Result<Vec<Todo>>;
+  fn add_dependency(&self, id: i64, on: i64) -> Result<bool>;
+  fn remove_dependency(&self, id: i64, on: i64) -> Result<bool>;
+  fn get_blockers(&self, id: i64) -> Result<Vec<Todo>>;
+  fn get_dependents(&self, id: i64) -> Result<Vec<Todo>>;
+  fn get_next_occurrence(&self, series_id: i64) -> Result<Option<Todo>>;
+  fn list_series(&self) -> Result<Vec<RecurrenceSeries>>;
+  fn end_series(&self, series_id: i64) -> Result<bool>;
+  fn get_history(&self, id: Option<i64>, limit: usize) -> Result<Vec<TodoEvent>>;
+  fn export_todos(&self, definition: &ViewDefinition) -> Result<ExportFile>;
+  fn import_todos(&self, file: &ExportFile, strategy: ConflictStrategy, dry_run: bool) -> Result<Vec<ImportItem>>;
+  fn merge_database(&self, path: &Path, dry_run: bool) -> Result<MergeReport>;
+  fn undoable_operations(&self, count: usize) -> Result<Vec<Operation>>;
+  fn redoable_operations(&self, count: usize) -> Result<Vec<Operation>>;
+  fn undo(&self, count: usize) -> Result<Vec<Operation>>;
+  fn redo(&self, count: usize) -> Result<Vec<Operation>>;
   fn get_today_todos(&self) -> Result<Vec<Todo>>;
   fn get_urgent_todos(&self) -> Result<Vec<Todo>>;
   fn get_overdue_todos(&self) -> Result<Vec<Todo>>;
+  fn clock(&self) -> &dyn Clock;
 }
 
 pub struct DefaultTodoService {
   db: Database,
+  // 진행중인 작업(undo 단위)의 ID
+  operation: Cell<Option<i64>>,
 }
 
 impl DefaultTodoService {
   pub fn new(db: Database) -> Self {
-    Self { db }
+    Self { db, operation: Cell::new(None) }
   }
 
+  #[allow(clippy::should_implement_trait)]
   pub fn default() -> Result<Self> {
     let db = Database::default()?;
 
-    if !db.is_initialized() {
-      db.initialize()?;
-    }
+    // 기존 DB에 아직 적용되지 않은 마이그레이션이 있으면 적용
+    db.initialize()?;
 
     Ok(Self::new(db))
   }
@@ -45,25 +93,400 @@ impl DefaultTodoService {
     Ok(Self::new(db))
   }
 
-  fn validate_create_todo(&self, todo: &CreateTodo) -> Result<()> {
+  pub fn with_clock(self, clock: SharedClock) -> Self {
+    Self::new(self.db.with_clock(clock))
+  }
 
-    if todo.title.trim().is_empty() {
+  /// 이후의 변경을 하나의 작업(undo 단위)으로 묶기 시작
+  pub fn begin_operation(&self, label: &str) -> Result<()> {
+    self.end_operation()?;
+
+    let journal = SqliteJournalRepository::new(self.db.conn(), self.db.clock());
+    self.operation.set(Some(journal.begin(label)?));
+    Ok(())
+  }
+
+  /// 작업을 마침. 아무 변경도 없었으면 저널에 남지 않음
+  pub fn end_operation(&self) -> Result<()> {
+    if let Some(operation_id) = self.operation.take() {
+      let journal = SqliteJournalRepository::new(self.db.conn(), self.db.clock());
+      journal.finish(operation_id)?;
+    }
+    Ok(())
+  }
+
+  fn snapshot(&self, id: i64) -> Result<Option<TodoSnapshot>> {
+    let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+    repo.snapshot(id)
+  }
+
+  /// 할일 한 건의 변경을 현재 작업의 저널에 기록 (변경 후 상태는 지금 상태).
+  /// 열린 작업이 없으면 변경 하나짜리 작업으로 기록
+  fn journal(&self, todo_id: i64, before: Option<TodoSnapshot>) -> Result<()> {
+    let journal = SqliteJournalRepository::new(self.db.conn(), self.db.clock());
+    let change = OperationChange { todo_id, before, after: self.snapshot(todo_id)? };
+
+    match self.operation.get() {
+      Some(operation_id) => journal.add_change(operation_id, &change),
+      None => {
+        let operation_id = journal.begin(&format!("#{} {}", todo_id, change.title()))?;
+        journal.add_change(operation_id, &change)?;
+        journal.finish(operation_id)
+      }
+    }
+  }
+
+  /// 휴지통에 있는 할일. 휴지통에 없는 할일이면 오류
+  fn find_trashed(&self, id: i64) -> Result<Todo> {
+    let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+
+    match repo.find_in_trash(id)? {
+      Some(todo) => Ok(todo),
+      None if repo.find_by_id(id)?.is_some() => Err(TaskyError::InvalidInput {
+        message: format!("{}번 할일은 휴지통에 있지 않습니다.", id),
+      }),
+      None => Err(TaskyError::TodoNotFound { id }),
+    }
+  }
+
+  /// 새로 만들거나 가져오는 할일의 공통 검증
+  fn validate_todo_fields(&self, title: &str, description: Option<&str>, tags: &[String], project: Option<&str>) -> Result<()> {
+
+    if title.trim().is_empty() {
       return Err(TaskyError::EmptyTitle);
     }
 
-    if todo.title.len() > 200 {
+    if title.len() > 200 {
       return Err(TaskyError::InvalidInput { message: "제목은 200자를 초과할 수 없습니다.".to_string(), });
     }
 
-    if let Some(desc) = &todo.description {
+    if let Some(desc) = description {
       if desc.len() > 1000 {
         return Err(TaskyError::InvalidInput { message: "설명은 1000자를 초과할 수 없습니다.".to_string(), });
       }
     }
 
+    self.validate_tags(tags)?;
+
+    if let Some(project) = project {
+      validate_project_name(project)?;
+    }
+
+    Ok(())
+  }
+
+  /// 가져오기 계획. 파일 순서대로, 같은 UUID의 할일(휴지통 포함)이 있는지에 따라 할 일을 정함.
+  /// UUID가 없는 예전 파일은 같은 ID의 할일과 비교
+  fn plan_import(&self, file: &ExportFile, strategy: ConflictStrategy) -> Result<Vec<ImportItem>> {
+    let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+    let mut seen = HashSet::new();
+    let mut seen_uuids = HashSet::new();
+    let mut items = Vec::new();
+
+    for todo in &file.todos {
+      self.validate_todo_fields(&todo.title, todo.description.as_deref(), &todo.tags, todo.project.as_deref())
+        .map_err(|e| TaskyError::InvalidImport {
+          message: format!("'{}': {}", todo.title, e),
+        })?;
+
+      if let Some(id) = todo.id {
+        if !seen.insert(id) {
+          return Err(TaskyError::InvalidImport { message: format!("ID {}인 할일이 두 번 이상 나옵니다", id) });
+        }
+      }
+      if !todo.uuid.is_empty() && !seen_uuids.insert(todo.uuid.as_str()) {
+        return Err(TaskyError::InvalidImport { message: format!("UUID {}인 할일이 두 번 이상 나옵니다", todo.uuid) });
+      }
+
+      let existing = match (strategy, todo.id) {
+        (ConflictStrategy::Renumber, _) => None,
+        _ if !todo.uuid.is_empty() => repo.find_id_by_uuid(&todo.uuid)?,
+        (_, Some(id)) => repo.snapshot(id)?.map(|_| id),
+        (_, None) => None,
+      };
+      let action = match (existing, strategy) {
+        (None, _) => ImportAction::Create,
+        (Some(_), ConflictStrategy::SkipExisting) => ImportAction::Skip,
+        (Some(_), ConflictStrategy::Overwrite) => ImportAction::Overwrite,
+        (Some(_), _) => ImportAction::Conflict,
+      };
+      // 새로 만드는 할일은 파일의 ID가 비어 있을 때만 그대로 씀
+      let id = match (existing, strategy, todo.id) {
+        (Some(id), _, _) => Some(id),
+        (None, ConflictStrategy::Renumber, _) | (None, _, None) => None,
+        (None, _, Some(id)) => repo.snapshot(id)?.is_none().then_some(id),
+      };
+
+      items.push(ImportItem { source_id: todo.id, id, title: todo.title.clone(), action });
+    }
+
+    Ok(items)
+  }
+
+  /// 붙여 둔 다른 데이터베이스(`other`)를 합침. 다른 쪽에만 있는 할일은 추가하고, 양쪽에 있는 할일은 필드별로 합침
+  fn merge_attached(&self, dry_run: bool) -> Result<MergeReport> {
+    let merge = SqliteMergeRepository::new(self.db.conn());
+    let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+
+    let local_todos = merge.find_todos(MergeSide::Local)?;
+    let other_todos = merge.find_todos(MergeSide::Other)?;
+    let local_events = merge.find_events(MergeSide::Local)?;
+    let other_events = merge.find_events(MergeSide::Other)?;
+
+    // 할일별 이력 (기록 순)
+    let mut local_history: HashMap<i64, Vec<&TodoEvent>> = HashMap::new();
+    for event in &local_events {
+      local_history.entry(event.todo_id).or_default().push(event);
+    }
+    let mut other_history: HashMap<i64, Vec<&TodoEvent>> = HashMap::new();
+    for event in &other_events {
+      other_history.entry(event.todo_id).or_default().push(event);
+    }
+    let no_events = Vec::new();
+
+    let local_by_uuid: HashMap<&str, &Todo> = local_todos.iter().map(|todo| (todo.uuid.as_str(), todo)).collect();
+    // 합친 뒤의 할일 (추가할 할일은 다른 쪽 그대로)
+    let mut plan: Vec<(MergeItem, Todo)> = Vec::new();
+
+    for other in &other_todos {
+      let Some(local) = local_by_uuid.get(other.uuid.as_str()) else {
+        let item = MergeItem { uuid: other.uuid.clone(), id: None, title: other.title.clone(), action: MergeAction::Create, fields: Vec::new() };
+        plan.push((item, other.clone()));
+        continue;
+      };
+
+      let local_events = local.id.and_then(|id| local_history.get(&id)).unwrap_or(&no_events);
+      let other_events = other.id.and_then(|id| other_history.get(&id)).unwrap_or(&no_events);
+      let fields = merge_fields(local, local_events, other, other_events);
+      if fields.is_empty() {
+        continue;
+      }
+
+      let action = match fields.iter().any(|field| field.winner == MergeSide::Other) {
+        true => MergeAction::Update,
+        false => MergeAction::Keep,
+      };
+      let merged = merged_todo(local, other, &fields);
+      plan.push((MergeItem { uuid: local.uuid.clone(), id: local.id, title: merged.title.clone(), action, fields }, merged));
+    }
+
+    // 다른 쪽의 의존성 중 이쪽에 없는 것 (UUID로 비교)
+    let uuid_pairs = |todos: &[Todo], dependencies: Vec<ExportedDependency>| -> Vec<(String, String)> {
+      let uuids: HashMap<i64, &str> = todos.iter().filter_map(|todo| Some((todo.id?, todo.uuid.as_str()))).collect();
+      dependencies
+        .iter()
+        .filter_map(|d| Some((uuids.get(&d.todo_id)?.to_string(), uuids.get(&d.depends_on_id)?.to_string())))
+        .collect()
+    };
+    let local_pairs: HashSet<(String, String)> = uuid_pairs(&local_todos, merge.find_dependencies(MergeSide::Local)?).into_iter().collect();
+    let new_pairs: Vec<(String, String)> = uuid_pairs(&other_todos, merge.find_dependencies(MergeSide::Other)?)
+      .into_iter()
+      .filter(|pair| !local_pairs.contains(pair))
+      .collect();
+
+    if dry_run {
+      let items = plan.into_iter().map(|(item, _)| item).collect();
+      return Ok(MergeReport { items, dependencies: new_pairs.len() });
+    }
+
+    let tx = self.db.conn().unchecked_transaction()?;
+
+    // 새 할일은 다른 쪽 ID가 비어 있으면 그대로 쓰고, 아니면 새 ID
+    let mut ids: HashMap<String, i64> = local_todos.iter().filter_map(|todo| Some((todo.uuid.clone(), todo.id?))).collect();
+    let taken: HashSet<i64> = ids.values().copied().collect();
+    let kept: Vec<i64> = plan
+      .iter()
+      .filter(|(item, _)| item.action == MergeAction::Create)
+      .filter_map(|(_, todo)| todo.id.filter(|id| !taken.contains(id)))
+      .collect();
+    let mut next_id = kept.iter().map(|id| id + 1).fold(repo.next_id()?, i64::max);
+    for (item, todo) in plan.iter_mut().filter(|(item, _)| item.action == MergeAction::Create) {
+      let id = match todo.id.filter(|id| !taken.contains(id)) {
+        Some(id) => id,
+        None => {
+          next_id += 1;
+          next_id - 1
+        }
+      };
+      item.id = Some(id);
+      ids.insert(todo.uuid.clone(), id);
+    }
+
+    let other_uuids: HashMap<i64, &str> = other_todos.iter().filter_map(|todo| Some((todo.id?, todo.uuid.as_str()))).collect();
+    let other_series: HashMap<i64, ExportedSeries> =
+      merge.find_series(MergeSide::Other)?.into_iter().map(|series| (series.id, series)).collect();
+    let mut series_ids = HashMap::new();
+    let mut journaled: Vec<(i64, Option<TodoSnapshot>)> = Vec::new();
+
+    for (item, merged) in plan.iter().filter(|(item, _)| item.action == MergeAction::Update) {
+      let id = item.id.unwrap_or_default();
+      let before = repo.snapshot(id)?;
+      let (depends_on, dependents) = before
+        .as_ref()
+        .map(|snapshot| (snapshot.depends_on.clone(), snapshot.dependents.clone()))
+        .unwrap_or_default();
+      repo.restore(&TodoSnapshot { todo: merged.clone(), depends_on, dependents })?;
+      journaled.push((id, before));
+    }
+
+    // 상위 할일부터 추가
+    let mut remaining: Vec<&(MergeItem, Todo)> = plan.iter().filter(|(item, _)| item.action == MergeAction::Create).collect();
+    let mut inserted: HashSet<i64> = taken;
+    while !remaining.is_empty() {
+      let parent_of = |todo: &Todo| todo.parent_id.and_then(|id| other_uuids.get(&id)).and_then(|uuid| ids.get(*uuid)).copied();
+      let (ready, waiting): (Vec<_>, Vec<_>) =
+        remaining.into_iter().partition(|(_, todo)| !matches!(parent_of(todo), Some(parent) if !inserted.contains(&parent)));
+      if ready.is_empty() {
+        return Err(TaskyError::InvalidInput { message: "상위 할일 관계가 순환합니다".to_string() });
+      }
+
+      for (item, other) in ready {
+        let mut todo = other.clone();
+        todo.id = item.id;
+        todo.parent_id = parent_of(other);
+        todo.series_id = match other.series_id.and_then(|id| other_series.get(&id)) {
+          Some(series) => Some(match series_ids.get(&series.id) {
+            Some(&id) => id,
+            None => {
+              let id = repo.import_series(series, false)?;
+              series_ids.insert(series.id, id);
+              id
+            }
+          }),
+          None => None,
+        };
+        let id = repo.insert(&todo)?;
+        inserted.insert(id);
+        journaled.push((id, None));
+      }
+      remaining = waiting;
+    }
+
+    // 의존성은 순환이 생기지 않는 것만
+    let mut dependencies = 0;
+    for (todo_uuid, on_uuid) in &new_pairs {
+      let (Some(&id), Some(&on)) = (ids.get(todo_uuid), ids.get(on_uuid)) else {
+        continue;
+      };
+      if id == on || repo.depends_on(on, id)? {
+        continue;
+      }
+      if !journaled.iter().any(|(journaled_id, _)| *journaled_id == id) {
+        journaled.push((id, repo.snapshot(id)?));
+      }
+      if repo.add_dependency(id, on)? {
+        dependencies += 1;
+      }
+    }
+
+    for (id, before) in journaled {
+      self.journal(id, before)?;
+    }
+
+    tx.commit()?;
+    let items = plan.into_iter().map(|(item, _)| item).collect();
+    Ok(MergeReport { items, dependencies })
+  }
+
+  /// 완료 처리하고 반복 할일이면 다음 회차 생성
+  #[allow(clippy::field_reassign_with_default)]
+  fn mark_done(&self, id: i64) -> Result<Todo> {
+    let mut update = UpdateTodo::default();
+    update.status = Some(Status::Done);
+
+    // 다음 회차를 만들지 못하면 완료도 취소
+    let tx = self.db.conn().unchecked_transaction()?;
+    let todo = self.update_todo(id, update)?;
+    self.spawn_next_occurrence(&todo)?;
+    tx.commit()?;
+
+    Ok(todo)
+  }
+
+  fn spawn_next_occurrence(&self, todo: &Todo) -> Result<Option<Todo>> {
+    let (Some(series_id), Some(rule)) = (todo.series_id, &todo.recurrence) else {
+      return Ok(None);
+    };
+
+    let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+
+    // 완료 취소 후 다시 완료한 경우 등 이미 대기중인 회차가 있으면 만들지 않음
+    if repo.find_pending_in_series(series_id)?.is_some() {
+      return Ok(None);
+    }
+
+    // 밀린 회차는 건너뛰고 오늘 이후의 첫 회차로
+    let rule = RecurrenceRule::parse(rule)?;
+    let today_start = self.db.clock().today_start();
+    // 다음 마감일이 앞으로 가지 않으면(날짜 범위를 넘으면) 멈춤
+    let advance = |due: DateTime<Utc>| {
+      rule.next_due(due).filter(|next| *next > due).ok_or_else(|| TaskyError::InvalidInput {
+        message: format!("반복 규칙 '{}'의 다음 마감일을 계산할 수 없습니다", rule),
+      })
+    };
+    let mut next_due = advance(todo.due_date.unwrap_or(today_start))?;
+    while next_due < today_start {
+      next_due = advance(next_due)?;
+    }
+
+    let mut next = CreateTodo::new(todo.title.clone())
+      .with_priority(todo.priority)
+      .with_tags(todo.tags.clone())
+      .with_due_date(next_due);
+    next.due_all_day = todo.due_all_day;
+    next.description = todo.description.clone();
+    next.project = todo.project.clone();
+    next.parent_id = todo.parent_id;
+    next.series_id = Some(series_id);
+
+    let created = repo.create(&next)?;
+    self.journal(created.id.unwrap_or_default(), None)?;
+    Ok(Some(created))
+  }
+
+  /// 완료되지 않은 하위(자손) 할일 ID 목록
+  fn pending_descendant_ids(&self, id: i64) -> Result<Vec<i64>> {
+    let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+
+    let mut pending = Vec::new();
+    for descendant_id in repo.find_descendant_ids(id)? {
+      if let Some(todo) = repo.find_by_id(descendant_id)? {
+        if todo.status == Status::Pending {
+          pending.push(descendant_id);
+        }
+      }
+    }
+
+    Ok(pending)
+  }
+
+  fn validate_tags(&self, tags: &[String]) -> Result<()> {
+    for tag in tags {
+      let tag = normalize_tag(tag);
+
+      if tag.is_empty() {
+        return Err(TaskyError::InvalidInput { message: "태그는 비어있을 수 없습니다.".to_string(), });
+      }
+
+      if tag.chars().any(|c| c.is_whitespace() || c == ',') {
+        return Err(TaskyError::InvalidInput { message: format!("태그에는 공백이나 쉼표를 쓸 수 없습니다: {}", tag), });
+      }
+
+      if tag.chars().count() > 50 {
+        return Err(TaskyError::InvalidInput { message: "태그는 50자를 초과할 수 없습니다.".to_string(), });
+      }
+    }
+
     Ok(())
   }
 
+  fn normalize_tags(tags: Vec<String>) -> Vec<String> {
+    let mut tags: Vec<String> = tags.iter().map(|t| normalize_tag(t)).collect();
+    tags.sort();
+    tags.dedup();
+    tags
+  }
+
   fn validate_update_todo(&self, todo: &UpdateTodo) -> Result<()> {
 
     if let Some(title) = &todo.title {
@@ -82,6 +505,14 @@ impl DefaultTodoService {
       }
     }
 
+    if let Some(tags) = &todo.tags {
+      self.validate_tags(tags)?;
+    }
+
+    if let Some(Some(project)) = &todo.project {
+      validate_project_name(project)?;
+    }
+
     Ok(())
   }
 }
@@ -96,20 +527,57 @@ impl TodoService for DefaultTodoService {
       create_todo.description = Some(desc.trim().to_string());
     }
 
-    let repo = SqliteTodoRepository::new(self.db.conn());
-    repo.create(&create_todo)
+    create_todo.tags = Self::normalize_tags(create_todo.tags);
+
+    let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+
+    if let Some(parent_id) = create_todo.parent_id {
+      if repo.find_by_id(parent_id)?.is_none() {
+        return Err(TaskyError::TodoNotFound { id: parent_id });
+      }
+    }
+
+    let created = repo.create(&create_todo)?;
+    self.journal(created.id.unwrap_or_default(), None)?;
+    Ok(created)
+  }
+
+  fn validate_create_todo(&self, todo: &CreateTodo) -> Result<()> {
+    self.validate_todo_fields(&todo.title, todo.description.as_deref(), &todo.tags, todo.project.as_deref())
   }
 
   fn get_todo_by_id(&self, id: i64) -> Result<Todo> {
     
-    let repo = SqliteTodoRepository::new(self.db.conn());
+    let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
     repo.find_by_id(id)?
         .ok_or(TaskyError::TodoNotFound { id })
   }
 
+  /// 숫자면 ID, 아니면 UUID 앞부분으로 찾은 할일의 ID (휴지통 포함, 하나만 맞아야 함)
+  fn resolve_todo_id(&self, reference: &str) -> Result<i64> {
+    let prefix = reference.trim().to_lowercase();
+    let invalid = || TaskyError::InvalidInput {
+      message: format!("할일은 ID나 UUID 앞부분으로 지정해야 합니다: {}", reference),
+    };
+
+    if !prefix.is_empty() && prefix.chars().all(|c| c.is_ascii_digit()) {
+      return prefix.parse().map_err(|_| invalid());
+    }
+    if prefix.is_empty() || !prefix.chars().all(|c| c.is_ascii_hexdigit() || c == '-') {
+      return Err(invalid());
+    }
+
+    let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+    match repo.find_ids_by_uuid_prefix(&prefix)?.as_slice() {
+      [id] => Ok(*id),
+      [] => Err(TaskyError::UuidPrefixNotFound { prefix }),
+      ids => Err(TaskyError::AmbiguousUuidPrefix { prefix, count: ids.len() }),
+    }
+  }
+
   fn list_todos(&self, filter: Option<TodoFilter>, sort_by: Option<SortBy>, sort_order: Option<SortOrder>,) -> Result<Vec<Todo>> {
       
-    let repo = SqliteTodoRepository::new(self.db.conn());
+    let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
     let filter = filter.unwrap_or_default();
     let sort_by = sort_by.unwrap_or_default();
     let sort_order = sort_order.unwrap_or_default();
@@ -117,6 +585,73 @@ impl TodoService for DefaultTodoService {
     repo.find_all(&filter, sort_by, sort_order)
   }
 
+  #[allow(clippy::field_reassign_with_default)]
+  fn search_todos(&self, query: &SearchQuery, limit: usize) -> Result<Vec<Todo>> {
+    let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+
+    // 보관된 할일도 검색 대상
+    let mut filter = TodoFilter::default();
+    filter.include_archived = true;
+
+    repo.search(query, &filter, limit)
+  }
+
+  fn list_view(&self, definition: &ViewDefinition) -> Result<Vec<Todo>> {
+    let (filter, sort_by, sort_order) = definition.resolve(self.db.clock())?;
+    self.list_todos(Some(filter), Some(sort_by), Some(sort_order))
+  }
+
+  fn save_view(&self, name: &str, definition: &ViewDefinition) -> Result<bool> {
+    validate_view_name(name)?;
+
+    if definition.is_empty() {
+      return Err(TaskyError::InvalidInput {
+        message: "저장할 조건이 없습니다. 필터 식이나 list 옵션을 함께 지정하세요.".to_string(),
+      });
+    }
+
+    // 잘못된 필터 식은 저장하기 전에 알림
+    definition.resolve(self.db.clock())?;
+
+    let repo = SqliteViewRepository::new(self.db.conn(), self.db.clock());
+    repo.save(name, definition)
+  }
+
+  fn find_view(&self, name: &str) -> Result<SavedView> {
+    if let Some(definition) = ViewDefinition::builtin(name) {
+      return Ok(SavedView { name: name.to_string(), definition, updated_at: None });
+    }
+
+    let repo = SqliteViewRepository::new(self.db.conn(), self.db.clock());
+    repo.find_by_name(name)?.ok_or_else(|| TaskyError::ViewNotFound { name: name.to_string() })
+  }
+
+  fn list_views(&self) -> Result<Vec<SavedView>> {
+    let repo = SqliteViewRepository::new(self.db.conn(), self.db.clock());
+
+    // 기본 보기 먼저
+    let mut views: Vec<SavedView> = BUILTIN_VIEWS
+      .iter()
+      .map(|name| self.find_view(name))
+      .collect::<Result<_>>()?;
+    views.extend(repo.find_all()?);
+
+    Ok(views)
+  }
+
+  fn delete_view(&self, name: &str) -> Result<()> {
+    if BUILTIN_VIEWS.contains(&name) {
+      return Err(TaskyError::InvalidInput { message: format!("기본 보기는 삭제할 수 없습니다: {}", name) });
+    }
+
+    let repo = SqliteViewRepository::new(self.db.conn(), self.db.clock());
+    if !repo.delete(name)? {
+      return Err(TaskyError::ViewNotFound { name: name.to_string() });
+    }
+
+    Ok(())
+  }
+
   fn update_todo(&self, id: i64, mut update_todo: UpdateTodo) -> Result<Todo> {
     self.validate_update_todo(&update_todo)?;
 
@@ -128,73 +663,551 @@ impl TodoService for DefaultTodoService {
       update_todo.description = Some(desc.trim().to_string());
     }
 
-    let repo = SqliteTodoRepository::new(self.db.conn());
+    if let Some(tags) = update_todo.tags {
+      update_todo.tags = Some(Self::normalize_tags(tags));
+    }
 
-    repo.update(id, &update_todo)?
-        .ok_or(TaskyError::TodoNotFound { id })
+    let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+    let before = self.snapshot(id)?.ok_or(TaskyError::TodoNotFound { id })?;
+
+    let updated = repo.update(id, &update_todo)?
+        .ok_or(TaskyError::TodoNotFound { id })?;
+    self.journal(id, Some(before))?;
+
+    Ok(updated)
   }
 
   fn delete_todo(&self, id: i64) -> Result<bool> {
-    let repo = SqliteTodoRepository::new(self.db.conn());
+    let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+
+    // 함께 삭제되는 하위 할일은 깊은 것부터, 삭제한 할일은 마지막에 기록해야
+    // 되돌릴 때(역순) 상위 할일부터 복원됨
+    let mut removed = Vec::new();
+    for descendant_id in repo.find_descendant_ids(id)?.into_iter().rev() {
+      removed.extend(self.snapshot(descendant_id)?);
+    }
+    removed.extend(self.snapshot(id)?);
 
     let deleted = repo.delete(id)?;
     if !deleted {
       return Err(TaskyError::TodoNotFound { id });
     }
 
+    for snapshot in removed {
+      self.journal(snapshot.todo.id.unwrap_or(id), Some(snapshot))?;
+    }
+
     Ok(deleted)
   }
 
+  #[allow(clippy::field_reassign_with_default)]
+  fn list_trash(&self) -> Result<Vec<Todo>> {
+    let mut filter = TodoFilter::default();
+    filter.trashed = true;
+
+    // 최근에 삭제한 것부터
+    let mut todos = self.list_todos(Some(filter), None, None)?;
+    todos.sort_by_key(|todo| std::cmp::Reverse(todo.deleted_at));
+    Ok(todos)
+  }
+
+  fn restore_todo(&self, id: i64) -> Result<Todo> {
+    let todo = self.find_trashed(id)?;
+    let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+
+    if let Some(parent_id) = todo.parent_id {
+      if repo.find_in_trash(parent_id)?.is_some() {
+        return Err(TaskyError::InvalidInput {
+          message: format!("상위 할일({}번)이 휴지통에 있습니다. 상위 할일을 먼저 복원하세요.", parent_id),
+        });
+      }
+    }
+
+    let mut restored = Vec::new();
+    restored.extend(self.snapshot(id)?);
+    for descendant_id in repo.find_trashed_descendant_ids(id)? {
+      restored.extend(self.snapshot(descendant_id)?);
+    }
+
+    repo.restore_from_trash(id)?;
+
+    for snapshot in restored {
+      self.journal(snapshot.todo.id.unwrap_or(id), Some(snapshot))?;
+    }
+
+    self.get_todo_by_id(id)
+  }
+
+  fn purge_todo(&self, id: i64) -> Result<usize> {
+    self.find_trashed(id)?;
+    let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+
+    // delete_todo와 같은 순서로 기록해야 되돌릴 때 상위 할일부터 다시 만들어짐
+    let mut purged = Vec::new();
+    for descendant_id in repo.find_trashed_descendant_ids(id)?.into_iter().rev() {
+      purged.extend(self.snapshot(descendant_id)?);
+    }
+    purged.extend(self.snapshot(id)?);
+
+    repo.purge(id)?;
+
+    let count = purged.len();
+    for snapshot in purged {
+      self.journal(snapshot.todo.id.unwrap_or(id), Some(snapshot))?;
+    }
+
+    Ok(count)
+  }
+
+  fn purge_trash(&self, deleted_before: Option<DateTime<Utc>>) -> Result<usize> {
+    let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+    let mut count = 0;
+
+    for todo in self.list_trash()? {
+      let expired = match (todo.deleted_at, deleted_before) {
+        (Some(deleted_at), Some(cutoff)) => deleted_at < cutoff,
+        _ => true,
+      };
+      let id = todo.id.unwrap_or_default();
+
+      // 상위 할일과 함께 이미 지워졌을 수 있음
+      if expired && repo.find_in_trash(id)?.is_some() {
+        count += self.purge_todo(id)?;
+      }
+    }
+
+    Ok(count)
+  }
+
   fn complete_todo(&self, id: i64) -> Result<Todo> {
-    let mut update = UpdateTodo::default();
-    update.status = Some(Status::Done);
+    self.get_todo_by_id(id)?;
+
+    let pending = self.pending_descendant_ids(id)?;
+    if !pending.is_empty() {
+      return Err(TaskyError::PendingSubtasks { id, count: pending.len() });
+    }
+
+    self.mark_done(id)
+  }
+
+  fn complete_todo_cascade(&self, id: i64) -> Result<Todo> {
+    self.get_todo_by_id(id)?;
+
+    for descendant_id in self.pending_descendant_ids(id)? {
+      self.mark_done(descendant_id)?;
+    }
 
-    self.update_todo(id, update)
+    self.complete_todo(id)
   }
 
+  #[allow(clippy::field_reassign_with_default)]
   fn uncomplete_todo(&self, id: i64) -> Result<Todo> {
       let mut update = UpdateTodo::default();
       update.status = Some(Status::Pending);
+      // 다시 할 일이 되었으니 보관함에서도 꺼냄
+      update.archived = Some(false);
 
       self.update_todo(id, update)
   }
 
-  fn get_stats(&self) -> Result<TodoStats> {
-      let repo = SqliteTodoRepository::new(self.db.conn());
-      repo.get_stats()
+  #[allow(clippy::field_reassign_with_default)]
+  fn archive_todo(&self, id: i64) -> Result<Todo> {
+      let todo = self.get_todo_by_id(id)?;
+
+      if todo.status != Status::Done {
+        return Err(TaskyError::InvalidInput { message: "완료된 할일만 보관할 수 있습니다.".to_string(), });
+      }
+      if todo.archived_at.is_some() {
+        return Err(TaskyError::InvalidInput { message: "이미 보관된 할일입니다.".to_string(), });
+      }
+
+      let mut update = UpdateTodo::default();
+      update.archived = Some(true);
+
+      self.update_todo(id, update)
   }
 
-  fn get_today_todos(&self) -> Result<Vec<Todo>> {
+  #[allow(clippy::field_reassign_with_default)]
+  fn archive_done_before(&self, cutoff: DateTime<Utc>) -> Result<Vec<Todo>> {
       let mut filter = TodoFilter::default();
-      filter.due_after = Some(utils::today_start());
-      filter.due_before = Some(utils::today_end());
+      filter.completed_before = Some(cutoff);
 
-      self.list_todos(Some(filter), Some(SortBy::DueDate), Some(SortOrder::Asc))
+      self.list_todos(Some(filter), Some(SortBy::CreatedAt), Some(SortOrder::Asc))?
+        .into_iter()
+        .map(|todo| self.archive_todo(todo.id.unwrap_or_default()))
+        .collect()
   }
 
-  fn get_urgent_todos(&self) -> Result<Vec<Todo>> {
-      let mut filter = TodoFilter::default();
-      filter.status = Some(Status::Pending);
-      filter.priority = Some(Priority::High);
+  #[allow(clippy::field_reassign_with_default)]
+  fn unarchive_todo(&self, id: i64) -> Result<Todo> {
+      let todo = self.get_todo_by_id(id)?;
+
+      if todo.archived_at.is_none() {
+        return Err(TaskyError::InvalidInput { message: "보관된 할일이 아닙니다.".to_string(), });
+      }
+
+      let mut update = UpdateTodo::default();
+      update.archived = Some(false);
 
-      self.list_todos(Some(filter), Some(SortBy::DueDate), Some(SortOrder::Asc))
+      self.update_todo(id, update)
   }
 
-  fn get_overdue_todos(&self) -> Result<Vec<Todo>> {
-      let today_start = crate::utils::today_start();
+  fn get_stats(&self, project: Option<&str>, include_archived: bool) -> Result<TodoStats> {
+      let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+      repo.get_stats(project, include_archived)
+  }
 
-      let mut filter = TodoFilter::default();
-      filter.status = Some(Status::Pending);
-      filter.due_before = Some(today_start);
+  fn list_projects(&self, include_archived: bool) -> Result<Vec<ProjectStats>> {
+      let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+      repo.get_project_stats(include_archived)
+  }
+
+  fn get_subtasks(&self, id: i64) -> Result<Vec<Todo>> {
+      let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+      repo.find_children(id)
+  }
+
+  fn add_dependency(&self, id: i64, on: i64) -> Result<bool> {
+      self.get_todo_by_id(id)?;
+      self.get_todo_by_id(on)?;
+
+      if id == on {
+        return Err(TaskyError::InvalidInput { message: "할일은 자기 자신에 의존할 수 없습니다.".to_string(), });
+      }
+
+      let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+
+      // on이 이미 id에 의존하면 순환이 생김
+      if repo.depends_on(on, id)? {
+        return Err(TaskyError::DependencyCycle { id, on });
+      }
+
+      let before = self.snapshot(id)?;
+      let added = repo.add_dependency(id, on)?;
+      if added {
+        self.journal(id, before)?;
+      }
+
+      Ok(added)
+  }
+
+  fn remove_dependency(&self, id: i64, on: i64) -> Result<bool> {
+      self.get_todo_by_id(id)?;
+
+      let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+      let before = self.snapshot(id)?;
+      let removed = repo.remove_dependency(id, on)?;
+      if removed {
+        self.journal(id, before)?;
+      }
+
+      Ok(removed)
+  }
+
+  fn get_blockers(&self, id: i64) -> Result<Vec<Todo>> {
+      let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+      repo.find_blockers(id)
+  }
+
+  fn get_dependents(&self, id: i64) -> Result<Vec<Todo>> {
+      let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+      repo.find_dependents(id)
+  }
+
+  fn get_next_occurrence(&self, series_id: i64) -> Result<Option<Todo>> {
+      let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+      repo.find_pending_in_series(series_id)
+  }
+
+  fn list_series(&self) -> Result<Vec<RecurrenceSeries>> {
+      let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+      repo.list_series()
+  }
+
+  fn end_series(&self, series_id: i64) -> Result<bool> {
+      let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+
+      if !repo.list_series()?.iter().any(|series| series.id == series_id) {
+        return Err(TaskyError::SeriesNotFound { id: series_id });
+      }
+
+      repo.end_series(series_id)
+  }
+
+  fn get_history(&self, id: Option<i64>, limit: usize) -> Result<Vec<TodoEvent>> {
+      let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+      repo.find_events(id, limit)
+  }
+
+  /// 조건에 맞는 할일을 ID 순으로 내보냄. 보관된 할일은 포함하고 휴지통의 할일은 뺌
+  fn export_todos(&self, definition: &ViewDefinition) -> Result<ExportFile> {
+      let mut definition = definition.clone();
+      definition.archived = true;
+
+      let mut todos = self.list_view(&definition)?;
+      todos.sort_by_key(|todo| todo.id);
+
+      let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+      let ids: HashSet<i64> = todos.iter().filter_map(|todo| todo.id).collect();
+      let series_ids: HashSet<i64> = todos.iter().filter_map(|todo| todo.series_id).collect();
+
+      let mut file = ExportFile::new(self.db.clock().now());
+      for &id in &ids {
+        let Some(snapshot) = repo.snapshot(id)? else { continue };
+        file.dependencies.extend(
+          snapshot.depends_on
+            .into_iter()
+            .filter(|depends_on_id| ids.contains(depends_on_id))
+            .map(|depends_on_id| ExportedDependency { todo_id: id, depends_on_id }),
+        );
+      }
+      file.dependencies.sort_by_key(|dependency| (dependency.todo_id, dependency.depends_on_id));
+
+      file.series = repo.list_series()?
+        .iter()
+        .filter(|series| series_ids.contains(&series.id))
+        .map(ExportedSeries::from)
+        .collect();
+      file.series.sort_by_key(|series| series.id);
+      file.todos = todos;
+
+      Ok(file)
+  }
+
+  /// 내보낸 파일을 가져옴. 생성/수정 시각과 상태는 파일 그대로 유지하고, 가져온 할일은 현재 작업(undo 단위)에 기록
+  fn import_todos(&self, file: &ExportFile, strategy: ConflictStrategy, dry_run: bool) -> Result<Vec<ImportItem>> {
+      let mut items = self.plan_import(file, strategy)?;
+      if dry_run {
+        return Ok(items);
+      }
+
+      let conflicts = items.iter().filter(|item| item.action == ImportAction::Conflict).count();
+      if conflicts > 0 {
+        return Err(TaskyError::ImportConflict { count: conflicts });
+      }
+
+      let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+      let keep_ids = strategy != ConflictStrategy::Renumber;
+      let tx = self.db.conn().unchecked_transaction()?;
+
+      let mut series_ids = HashMap::new();
+      for series in &file.series {
+        series_ids.insert(series.id, repo.import_series(series, keep_ids)?);
+      }
+
+      // 파일 안의 ID -> 데이터베이스 ID (건너뛴 할일은 기존 할일을 가리킴)
+      let source_ids: HashSet<i64> = file.todos.iter().filter_map(|todo| todo.id).collect();
+      let mut ids: HashMap<i64, i64> = items
+        .iter()
+        .filter(|item| item.action == ImportAction::Skip)
+        .filter_map(|item| item.source_id.zip(item.id))
+        .collect();
+      // 새 ID는 파일의 ID를 그대로 쓰는 할일과 겹치지 않게 미리 정함
+      let mut next_id = items.iter().filter_map(|item| item.id).map(|id| id + 1).fold(repo.next_id()?, i64::max);
+
+      // 상위 할일부터 저장 (파일 순서와 관계없이)
+      let mut remaining: Vec<usize> = (0..items.len()).filter(|&i| items[i].action != ImportAction::Skip).collect();
+      let mut journaled = Vec::new();
+
+      while !remaining.is_empty() {
+        let (ready, waiting): (Vec<usize>, Vec<usize>) = remaining.into_iter().partition(|&i| {
+          match file.todos[i].parent_id {
+            Some(parent_id) => !source_ids.contains(&parent_id) || ids.contains_key(&parent_id),
+            None => true,
+          }
+        });
+        if ready.is_empty() {
+          return Err(TaskyError::InvalidImport { message: "상위 할일 관계가 순환합니다".to_string() });
+        }
+
+        for i in ready {
+          let mut todo = file.todos[i].clone();
+          todo.id = Some(items[i].id.unwrap_or_else(|| {
+            next_id += 1;
+            next_id - 1
+          }));
+          // 새 ID로 복사하는 할일이 이미 있는 UUID를 쓰면 새 UUID를 받음
+          if strategy == ConflictStrategy::Renumber && repo.find_id_by_uuid(&todo.uuid)?.is_some() {
+            todo.uuid.clear();
+          }
+          todo.series_id = todo.series_id.and_then(|series_id| series_ids.get(&series_id).copied());
+          todo.parent_id = match todo.parent_id {
+            Some(parent_id) if source_ids.contains(&parent_id) => ids.get(&parent_id).copied(),
+            // 파일에 없는 상위 할일은 ID를 유지할 때만, 그리고 이 데이터베이스에 있을 때만 연결
+            Some(parent_id) if keep_ids && repo.snapshot(parent_id)?.is_some() => Some(parent_id),
+            _ => None,
+          };
+
+          let (id, before) = match items[i].action {
+            ImportAction::Overwrite => {
+              let id = todo.id.unwrap_or_default();
+              let before = repo.snapshot(id)?;
+              // 이 데이터베이스에서 맺은 의존성은 유지
+              let (depends_on, dependents) = before
+                .as_ref()
+                .map(|snapshot| (snapshot.depends_on.clone(), snapshot.dependents.clone()))
+                .unwrap_or_default();
+              repo.restore(&TodoSnapshot { todo, depends_on, dependents })?;
+              (id, before)
+            }
+            _ => (repo.insert(&todo)?, None),
+          };
+
+          if let Some(source_id) = items[i].source_id {
+            ids.insert(source_id, id);
+          }
+          items[i].id = Some(id);
+          journaled.push((id, before));
+        }
+
+        remaining = waiting;
+      }
+
+      // 의존성은 가져온 할일 쪽에서만 추가 (순환이 생기는 관계는 건너뜀)
+      let imported: HashSet<i64> = journaled.iter().map(|(id, _)| *id).collect();
+      for dependency in &file.dependencies {
+        let (Some(&id), Some(&on)) = (ids.get(&dependency.todo_id), ids.get(&dependency.depends_on_id)) else {
+          continue;
+        };
+        if imported.contains(&id) && id != on && !repo.depends_on(on, id)? {
+          repo.add_dependency(id, on)?;
+        }
+      }
+
+      for (id, before) in journaled {
+        self.journal(id, before)?;
+      }
+
+      tx.commit()?;
+      Ok(items)
+  }
+
+  /// 다른 Tasky 데이터베이스 파일을 합침. 합친 할일은 현재 작업(undo 단위)에 기록
+  fn merge_database(&self, path: &Path, dry_run: bool) -> Result<MergeReport> {
+      if !path.is_file() {
+        return Err(TaskyError::InvalidInput { message: format!("데이터베이스 파일이 없습니다: {}", path.display()) });
+      }
+
+      let merge = SqliteMergeRepository::new(self.db.conn());
+      let same_file = match merge.main_path()? {
+        Some(main) => main.canonicalize().ok() == path.canonicalize().ok(),
+        None => false,
+      };
+      if same_file {
+        return Err(TaskyError::InvalidInput { message: "지금 쓰는 데이터베이스와 같은 파일입니다".to_string() });
+      }
+
+      // 다른 쪽 파일은 읽기만 하므로 스키마 버전이 같아야 함
+      let other = Database::open_read_only(path)?;
+      if !other.is_initialized() {
+        return Err(TaskyError::InvalidInput { message: format!("Tasky 데이터베이스가 아닙니다: {}", path.display()) });
+      }
+      let (version, latest) = (other.schema_version()?, migrations::latest_version());
+      other.close()?;
+      if version != latest {
+        return Err(TaskyError::InvalidInput {
+          message: format!(
+            "스키마 버전이 다릅니다 (다른 쪽 v{}, 이 프로그램 v{}). 다른 쪽에서 먼저 'tasky db migrate'를 실행하세요",
+            version, latest
+          ),
+        });
+      }
+
+      merge.attach(path)?;
+      let report = self.merge_attached(dry_run);
+      merge.detach()?;
+      report
+  }
 
-      self.list_todos(Some(filter), Some(SortBy::DueDate), Some(SortOrder::Asc))
+  fn undoable_operations(&self, count: usize) -> Result<Vec<Operation>> {
+      let journal = SqliteJournalRepository::new(self.db.conn(), self.db.clock());
+      journal.find_undoable(count)
+  }
+
+  fn redoable_operations(&self, count: usize) -> Result<Vec<Operation>> {
+      let journal = SqliteJournalRepository::new(self.db.conn(), self.db.clock());
+      journal.find_redoable(count)
+  }
+
+  fn undo(&self, count: usize) -> Result<Vec<Operation>> {
+      let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+      let journal = SqliteJournalRepository::new(self.db.conn(), self.db.clock());
+      let operations = journal.find_undoable(count)?;
+
+      // 최근 작업부터, 작업 안에서는 기록의 역순으로 변경 전 상태를 되살림
+      let tx = self.db.conn().unchecked_transaction()?;
+      for operation in &operations {
+        for change in operation.changes.iter().rev() {
+          match &change.before {
+            Some(before) => repo.restore(before)?,
+            // 생성을 되돌리면 휴지통을 거치지 않고 지움
+            None => {
+              repo.delete(change.todo_id)?;
+              repo.purge(change.todo_id)?;
+            }
+          }
+        }
+        journal.set_undone(operation.id, true)?;
+      }
+      tx.commit()?;
+
+      Ok(operations)
+  }
+
+  fn redo(&self, count: usize) -> Result<Vec<Operation>> {
+      let repo = SqliteTodoRepository::new(self.db.conn(), self.db.clock());
+      let journal = SqliteJournalRepository::new(self.db.conn(), self.db.clock());
+      let operations = journal.find_redoable(count)?;
+
+      let tx = self.db.conn().unchecked_transaction()?;
+      for operation in &operations {
+        for change in &operation.changes {
+          match &change.after {
+            Some(after) => repo.restore(after)?,
+            None => {
+              repo.delete(change.todo_id)?;
+              repo.purge(change.todo_id)?;
+            }
+          }
+        }
+        journal.set_undone(operation.id, false)?;
+      }
+      tx.commit()?;
+
+      Ok(operations)
+  }
+
+  fn get_today_todos(&self) -> Result<Vec<Todo>> {
+      self.list_view(&self.find_view("today")?.definition)
+  }
+
+  fn get_urgent_todos(&self) -> Result<Vec<Todo>> {
+      self.list_view(&self.find_view("urgent")?.definition)
+  }
+
+  fn get_overdue_todos(&self) -> Result<Vec<Todo>> {
+      self.list_view(&self.find_view("overdue")?.definition)
+  }
+
+  fn clock(&self) -> &dyn Clock {
+      self.db.clock()
   }
 }
 
 #[cfg(test)]
+#[allow(clippy::field_reassign_with_default)]
 mod tests {
     use super::*;
-    use chrono::{Duration, Utc};
+    use chrono::Duration;
+    use std::sync::Arc;
+    use crate::models::Priority;
+    use crate::utils::FixedClock;
+
+    fn service_at(now: &str) -> (DefaultTodoService, Arc<FixedClock>) {
+        let clock = Arc::new(FixedClock::parse(now).unwrap());
+        let service = DefaultTodoService::in_memory().unwrap().with_clock(clock.clone());
+        (service, clock)
+    }
 
     #[test]
     fn test_create_todo() {
@@ -226,6 +1239,529 @@ mod tests {
         assert_eq!(uncompleted.status, Status::Pending);
     }
     
+    #[test]
+    fn test_complete_with_pending_subtasks() {
+        let service = DefaultTodoService::in_memory().unwrap();
+
+        let parent = service.create_todo(CreateTodo::new("상위".to_string())).unwrap();
+        let parent_id = parent.id.unwrap();
+        let child = service.create_todo(CreateTodo::new("하위".to_string())
+            .with_parent(parent_id)).unwrap();
+        service.create_todo(CreateTodo::new("손자".to_string())
+            .with_parent(child.id.unwrap())).unwrap();
+
+        // 하위 할일이 남아있으면 거부
+        let result = service.complete_todo(parent_id);
+        assert!(matches!(result.unwrap_err(), TaskyError::PendingSubtasks { count: 2, .. }));
+
+        // cascade면 자손까지 모두 완료
+        let completed = service.complete_todo_cascade(parent_id).unwrap();
+        assert_eq!(completed.status, Status::Done);
+        let subtasks = service.get_subtasks(parent_id).unwrap();
+        assert!(subtasks.iter().all(|t| t.status == Status::Done));
+
+        // 존재하지 않는 상위 할일
+        let result = service.create_todo(CreateTodo::new("고아".to_string()).with_parent(999));
+        assert!(matches!(result.unwrap_err(), TaskyError::TodoNotFound { id: 999 }));
+    }
+
+    #[test]
+    fn test_dependency_cycle_rejected() {
+        let service = DefaultTodoService::in_memory().unwrap();
+
+        let a = service.create_todo(CreateTodo::new("A".to_string())).unwrap().id.unwrap();
+        let b = service.create_todo(CreateTodo::new("B".to_string())).unwrap().id.unwrap();
+        let c = service.create_todo(CreateTodo::new("C".to_string())).unwrap().id.unwrap();
+
+        service.add_dependency(b, a).unwrap();
+        service.add_dependency(c, b).unwrap();
+
+        let result = service.add_dependency(a, c);
+        assert!(matches!(result.unwrap_err(), TaskyError::DependencyCycle { .. }));
+        assert!(service.add_dependency(a, a).is_err());
+        assert!(service.add_dependency(a, 999).is_err());
+    }
+
+    #[test]
+    fn test_complete_recurring_spawns_next() {
+        let (service, clock) = service_at("2026-10-15 08:00");
+
+        let due = clock.today_start() + Duration::hours(9);
+        let create = CreateTodo::new("물 주기".to_string())
+            .with_due_date(due)
+            .with_tags(vec!["home".to_string()])
+            .with_recurrence(RecurrenceRule::parse("every 3 days").unwrap());
+        let todo = service.create_todo(create).unwrap();
+        let series_id = todo.series_id.unwrap();
+
+        service.complete_todo(todo.id.unwrap()).unwrap();
+
+        let next = service.get_next_occurrence(series_id).unwrap().unwrap();
+        assert_ne!(next.id, todo.id);
+        assert_eq!(next.title, "물 주기");
+        assert_eq!(next.tags, vec!["home".to_string()]);
+        assert_eq!(next.due_date, RecurrenceRule::Days(3).next_due(due));
+
+        // 완료 취소 후 재완료해도 회차가 중복 생성되지 않음
+        service.uncomplete_todo(todo.id.unwrap()).unwrap();
+        service.complete_todo(todo.id.unwrap()).unwrap();
+        let series = service.list_series().unwrap();
+        assert_eq!(series[0].total_todos, 2);
+
+        // 시리즈를 끝내면 더 이상 생성되지 않음
+        service.end_series(series_id).unwrap();
+        service.complete_todo(next.id.unwrap()).unwrap();
+        assert!(service.get_next_occurrence(series_id).unwrap().is_none());
+        assert!(service.end_series(999).is_err());
+    }
+
+    #[test]
+    fn test_undo_redo_batch_done() {
+        let service = DefaultTodoService::in_memory().unwrap();
+        let ids: Vec<i64> = ["A", "B", "C"].iter()
+            .map(|t| service.create_todo(CreateTodo::new(t.to_string())).unwrap().id.unwrap())
+            .collect();
+
+        // done 여러 개가 하나의 작업
+        service.begin_operation("done 1 2 3").unwrap();
+        for id in &ids {
+            service.complete_todo(*id).unwrap();
+        }
+        service.end_operation().unwrap();
+
+        let preview = service.undoable_operations(1).unwrap();
+        assert_eq!(preview[0].label, "done 1 2 3");
+        assert_eq!(preview[0].changes.len(), 3);
+
+        service.undo(1).unwrap();
+        assert!(ids.iter().all(|id| service.get_todo_by_id(*id).unwrap().status == Status::Pending));
+
+        service.redo(1).unwrap();
+        assert!(ids.iter().all(|id| service.get_todo_by_id(*id).unwrap().status == Status::Done));
+        assert!(service.redoable_operations(10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_undo_remove_restores_subtasks_and_dependencies() {
+        let service = DefaultTodoService::in_memory().unwrap();
+
+        let parent = service.create_todo(CreateTodo::new("이사".to_string())
+            .with_tags(vec!["home".to_string()])
+            .with_project("life".to_string())).unwrap().id.unwrap();
+        let child = service.create_todo(CreateTodo::new("짐 싸기".to_string()).with_parent(parent)).unwrap().id.unwrap();
+        let other = service.create_todo(CreateTodo::new("청소".to_string())).unwrap().id.unwrap();
+        service.add_dependency(other, child).unwrap();
+
+        service.begin_operation("remove").unwrap();
+        service.delete_todo(parent).unwrap();
+        service.end_operation().unwrap();
+        assert!(service.get_todo_by_id(child).is_err());
+
+        service.undo(1).unwrap();
+        let restored = service.get_todo_by_id(parent).unwrap();
+        assert_eq!(restored.tags, vec!["home".to_string()]);
+        assert_eq!(restored.project.as_deref(), Some("life"));
+        assert_eq!(service.get_todo_by_id(child).unwrap().parent_id, Some(parent));
+        assert_eq!(service.get_blockers(other).unwrap()[0].id, Some(child));
+
+        // 되돌린 뒤 새 작업을 하면 redo 대상은 사라짐
+        let mut update = UpdateTodo::default();
+        update.priority = Some(Priority::High);
+        service.update_todo(other, update).unwrap();
+        assert!(service.redoable_operations(10).unwrap().is_empty());
+
+        // 작업을 열지 않은 변경도 한 건씩 되돌릴 수 있음
+        service.undo(1).unwrap();
+        assert_eq!(service.get_todo_by_id(other).unwrap().priority, Priority::Medium);
+    }
+
+    #[test]
+    fn test_restore_and_purge_trash() {
+        let (service, clock) = service_at("2026-10-01 09:00");
+
+        let old = service.create_todo(CreateTodo::new("오래된 할일".to_string())).unwrap().id.unwrap();
+        let parent = service.create_todo(CreateTodo::new("이사".to_string())).unwrap().id.unwrap();
+        let child = service.create_todo(CreateTodo::new("짐 싸기".to_string()).with_parent(parent)).unwrap().id.unwrap();
+
+        service.delete_todo(old).unwrap();
+        clock.advance(chrono::Duration::days(40));
+        service.delete_todo(parent).unwrap();
+        assert_eq!(service.list_trash().unwrap().len(), 3);
+
+        // 상위 할일이 휴지통에 있으면 하위 할일만 복원할 수 없음
+        assert!(service.restore_todo(child).is_err());
+        assert!(matches!(service.restore_todo(999).unwrap_err(), TaskyError::TodoNotFound { .. }));
+
+        service.begin_operation("restore").unwrap();
+        let restored = service.restore_todo(parent).unwrap();
+        service.end_operation().unwrap();
+        assert!(restored.deleted_at.is_none());
+        assert_eq!(service.get_subtasks(parent).unwrap().len(), 1);
+        assert!(service.restore_todo(parent).is_err());
+
+        // 복원도 되돌릴 수 있음
+        service.undo(1).unwrap();
+        assert!(service.get_todo_by_id(child).is_err());
+        assert_eq!(service.list_trash().unwrap().len(), 3);
+
+        let cutoff = clock.now() - chrono::Duration::days(30);
+        assert_eq!(service.purge_trash(Some(cutoff)).unwrap(), 1);
+        assert_eq!(service.list_trash().unwrap().len(), 2);
+
+        // 영구 삭제를 되돌리면 휴지통으로 돌아옴
+        service.undo(1).unwrap();
+        assert_eq!(service.list_trash().unwrap().len(), 3);
+
+        assert_eq!(service.purge_trash(None).unwrap(), 3);
+        assert!(service.list_trash().unwrap().is_empty());
+        assert_eq!(service.get_stats(None, false).unwrap().total_todos, 0);
+    }
+
+    #[test]
+    fn test_saved_views() {
+        let (service, clock) = service_at("2026-10-18 09:00");
+        service.create_todo(CreateTodo::new("보고서".to_string())
+            .with_tags(vec!["work".to_string()])
+            .with_due_date(clock.today_start() + Duration::days(2))).unwrap();
+        service.create_todo(CreateTodo::new("장보기".to_string())
+            .with_due_date(clock.today_start() + Duration::days(10))).unwrap();
+
+        let mut definition = ViewDefinition::default();
+        definition.expressions.push("due<+7d".to_string());
+        assert!(service.save_view("soon", &definition).unwrap());
+        assert_eq!(service.list_view(&service.find_view("soon").unwrap().definition).unwrap().len(), 1);
+
+        // 상대 날짜는 실행할 때마다 다시 해석
+        clock.advance(Duration::days(5));
+        assert_eq!(service.list_view(&service.find_view("soon").unwrap().definition).unwrap().len(), 2);
+
+        definition.tags.push("work".to_string());
+        assert!(!service.save_view("soon", &definition).unwrap());
+        let saved = service.find_view("soon").unwrap();
+        assert_eq!(saved.definition, definition);
+
+        let names: Vec<String> = service.list_views().unwrap().into_iter().map(|v| v.name).collect();
+        assert_eq!(names, vec!["today", "overdue", "urgent", "soon"]);
+
+        let mut broken = ViewDefinition::default();
+        broken.expressions.push("tag<work".to_string());
+        assert!(matches!(service.save_view("broken", &broken), Err(TaskyError::InvalidFilter { .. })));
+        assert!(service.save_view("empty", &ViewDefinition::default()).is_err());
+        assert!(service.save_view("today", &definition).is_err());
+
+        assert!(service.delete_view("today").is_err());
+        service.delete_view("soon").unwrap();
+        assert!(matches!(service.find_view("soon"), Err(TaskyError::ViewNotFound { .. })));
+    }
+
+    #[test]
+    fn test_export_import() {
+        let (source, clock) = service_at("2026-10-01 09:00");
+        let parent = source.create_todo(CreateTodo::new("출시".to_string())
+            .with_project("work".to_string())
+            .with_tags(vec!["release".to_string()])).unwrap().id.unwrap();
+        let child = source.create_todo(CreateTodo::new("문서".to_string()).with_parent(parent)).unwrap().id.unwrap();
+        let weekly = source.create_todo(CreateTodo::new("주간 회의".to_string())
+            .with_due_date(clock.today_start())
+            .with_recurrence(RecurrenceRule::parse("weekly").unwrap())).unwrap().id.unwrap();
+        source.add_dependency(parent, weekly).unwrap();
+        clock.advance(Duration::days(3));
+        source.complete_todo(child).unwrap();
+
+        let file = source.export_todos(&ViewDefinition::default()).unwrap();
+        assert_eq!(file.todos.len(), 3);
+        assert_eq!(file.dependencies, vec![ExportedDependency { todo_id: parent, depends_on_id: weekly }]);
+        assert_eq!(file.series.len(), 1);
+        let file = ExportFile::from_json(&file.to_json().unwrap()).unwrap();
+
+        // 빈 데이터베이스에는 ID와 시각을 그대로 가져옴
+        let (target, _) = service_at("2026-10-18 09:00");
+        target.begin_operation("import").unwrap();
+        let items = target.import_todos(&file, ConflictStrategy::Fail, false).unwrap();
+        target.end_operation().unwrap();
+        assert!(items.iter().all(|item| item.action == ImportAction::Create && item.id == item.source_id));
+
+        let done = target.get_todo_by_id(child).unwrap();
+        assert_eq!(done.status, Status::Done);
+        assert_eq!(done.parent_id, Some(parent));
+        assert_eq!(done.updated_at, source.get_todo_by_id(child).unwrap().updated_at);
+        assert_eq!(target.get_todo_by_id(parent).unwrap().project.as_deref(), Some("work"));
+        assert_eq!(target.get_blockers(parent).unwrap().len(), 1);
+        assert_eq!(target.get_todo_by_id(weekly).unwrap().recurrence.as_deref(), Some("weekly"));
+
+        // 다시 가져오면 모두 겹침
+        assert!(matches!(target.import_todos(&file, ConflictStrategy::Fail, false), Err(TaskyError::ImportConflict { count: 3 })));
+        let plan = target.import_todos(&file, ConflictStrategy::Fail, true).unwrap();
+        assert!(plan.iter().all(|item| item.action == ImportAction::Conflict));
+
+        let mut edited = file.clone();
+        edited.todos[0].title = "출시 v2".to_string();
+        let items = target.import_todos(&edited, ConflictStrategy::Overwrite, false).unwrap();
+        assert!(items.iter().all(|item| item.action == ImportAction::Overwrite));
+        assert_eq!(target.get_todo_by_id(parent).unwrap().title, "출시 v2");
+        assert_eq!(target.get_blockers(parent).unwrap().len(), 1);
+
+        let items = target.import_todos(&file, ConflictStrategy::SkipExisting, false).unwrap();
+        assert!(items.iter().all(|item| item.action == ImportAction::Skip));
+        assert_eq!(target.get_todo_by_id(parent).unwrap().title, "출시 v2");
+
+        // 새 ID를 받아도 상위 할일과 의존성 관계는 유지
+        let items = target.import_todos(&file, ConflictStrategy::Renumber, false).unwrap();
+        let new_ids: Vec<i64> = items.iter().map(|item| item.id.unwrap()).collect();
+        assert!(new_ids.iter().all(|id| *id > 3));
+        assert_eq!(target.get_todo_by_id(new_ids[1]).unwrap().parent_id, Some(new_ids[0]));
+        assert_eq!(target.get_blockers(new_ids[0]).unwrap()[0].id, Some(new_ids[2]));
+        assert_ne!(target.get_todo_by_id(new_ids[2]).unwrap().series_id, Some(1));
+
+        // 처음 가져온 작업을 되돌리면 가져온 할일이 사라짐
+        let (undo_target, _) = service_at("2026-10-18 09:00");
+        undo_target.begin_operation("import").unwrap();
+        undo_target.import_todos(&file, ConflictStrategy::Fail, false).unwrap();
+        undo_target.end_operation().unwrap();
+        undo_target.undo(1).unwrap();
+        assert!(undo_target.list_todos(None, None, None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_import_matches_by_uuid() {
+        let (source, _) = service_at("2026-10-01 09:00");
+        let parent = source.create_todo(CreateTodo::new("출시".to_string())).unwrap();
+        source.create_todo(CreateTodo::new("문서".to_string()).with_parent(parent.id.unwrap())).unwrap();
+        let file = source.export_todos(&ViewDefinition::default()).unwrap();
+
+        // 같은 ID의 다른 할일이 있으면 겹치지 않고 새 ID로 추가
+        let (target, _) = service_at("2026-10-18 09:00");
+        target.create_todo(CreateTodo::new("다른 할일".to_string())).unwrap();
+        target.create_todo(CreateTodo::new("또 다른 할일".to_string())).unwrap();
+        let items = target.import_todos(&file, ConflictStrategy::Fail, false).unwrap();
+        assert!(items.iter().all(|item| item.action == ImportAction::Create));
+        let ids: Vec<i64> = items.iter().map(|item| item.id.unwrap()).collect();
+        assert_eq!(ids, vec![3, 4]);
+        assert_eq!(target.get_todo_by_id(3).unwrap().uuid, parent.uuid);
+        assert_eq!(target.get_todo_by_id(4).unwrap().parent_id, Some(3));
+
+        // 다시 가져오면 UUID로 같은 할일을 찾음
+        let plan = target.import_todos(&file, ConflictStrategy::Fail, true).unwrap();
+        assert!(plan.iter().all(|item| item.action == ImportAction::Conflict));
+        assert_eq!(plan[0].id, Some(3));
+        let mut edited = file.clone();
+        edited.todos[0].title = "출시 v2".to_string();
+        target.import_todos(&edited, ConflictStrategy::Overwrite, false).unwrap();
+        assert_eq!(target.get_todo_by_id(3).unwrap().title, "출시 v2");
+        assert_eq!(target.get_todo_by_id(1).unwrap().title, "다른 할일");
+
+        // 새 ID로 복사하면 새 UUID를 받음
+        let items = target.import_todos(&file, ConflictStrategy::Renumber, false).unwrap();
+        assert_ne!(target.get_todo_by_id(items[0].id.unwrap()).unwrap().uuid, parent.uuid);
+
+        // UUID가 없는 예전 파일은 ID로 비교
+        let mut legacy = file.clone();
+        legacy.todos.iter_mut().for_each(|todo| todo.uuid.clear());
+        let plan = target.import_todos(&legacy, ConflictStrategy::SkipExisting, true).unwrap();
+        assert!(plan.iter().all(|item| item.action == ImportAction::Skip && item.id == item.source_id));
+
+        let mut duplicated = file.clone();
+        duplicated.todos[1].uuid = duplicated.todos[0].uuid.clone();
+        assert!(matches!(target.import_todos(&duplicated, ConflictStrategy::Fail, true), Err(TaskyError::InvalidImport { .. })));
+    }
+
+    #[test]
+    fn test_merge_database() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("other.db");
+        let clock = Arc::new(FixedClock::parse("2026-10-01 09:00").unwrap());
+        let other = DefaultTodoService::new(Database::new(&path).unwrap()).with_clock(clock.clone());
+        other.db.initialize().unwrap();
+        let report = other.create_todo(CreateTodo::new("보고서".to_string())).unwrap();
+        other.create_todo(CreateTodo::new("장보기".to_string())).unwrap();
+
+        let (local, local_clock) = service_at("2026-10-01 09:00");
+        local.import_todos(&other.export_todos(&ViewDefinition::default()).unwrap(), ConflictStrategy::Fail, false).unwrap();
+
+        // 이쪽은 제목을, 다른 쪽은 나중에 제목과 우선순위를 바꾸고 새 할일을 추가
+        local_clock.advance(Duration::hours(1));
+        let title = |title: &str| UpdateTodo { title: Some(title.to_string()), ..Default::default() };
+        local.update_todo(1, title("최종 보고서")).unwrap();
+        clock.advance(Duration::hours(2));
+        other.update_todo(1, UpdateTodo { priority: Some(Priority::High), ..title("보고서 초안") }).unwrap();
+        other.complete_todo(2).unwrap();
+        let added = other.create_todo(CreateTodo::new("운동".to_string())).unwrap();
+        other.add_dependency(added.id.unwrap(), 1).unwrap();
+
+        // --dry-run은 어느 쪽도 바꾸지 않음
+        let other_before = std::fs::read(&path).unwrap();
+        let plan = local.merge_database(&path, true).unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), other_before);
+        assert_eq!(local.list_todos(None, None, None).unwrap().len(), 2);
+        assert_eq!(plan.count(MergeAction::Create), 1);
+        assert_eq!(plan.count(MergeAction::Update), 2);
+        assert_eq!(plan.conflicts(), 1);
+        assert_eq!(plan.dependencies, 1);
+        assert_eq!(local.get_todo_by_id(1).unwrap().title, "최종 보고서");
+
+        let merged = local.merge_database(&path, false).unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), other_before);
+        let todo = local.get_todo_by_id(1).unwrap();
+        assert_eq!((todo.title.as_str(), todo.priority), ("보고서 초안", Priority::High));
+        assert_eq!(todo.uuid, report.uuid);
+        assert_eq!(local.get_todo_by_id(2).unwrap().status, Status::Done);
+        let added_id = merged.items.iter().find(|item| item.action == MergeAction::Create).unwrap().id.unwrap();
+        assert_eq!(local.get_todo_by_id(added_id).unwrap().uuid, added.uuid);
+        assert!(local.merge_database(&path, true).unwrap().items.is_empty());
+
+        // 지금 쓰는 파일이나 Tasky가 아닌 파일은 합치지 않음
+        assert!(other.merge_database(&path, true).is_err());
+        assert!(local.merge_database(&dir.path().join("missing.db"), true).is_err());
+    }
+
+    #[test]
+    fn test_merge_refuses_other_schema() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("old.db");
+        let conn = rusqlite::Connection::open(&path).unwrap();
+        conn.execute_batch("CREATE TABLE todos (id INTEGER PRIMARY KEY, title TEXT NOT NULL);").unwrap();
+        drop(conn);
+        let before = std::fs::read(&path).unwrap();
+
+        // 예전 스키마는 마이그레이션하지 않고 거절
+        let service = DefaultTodoService::in_memory().unwrap();
+        for dry_run in [true, false] {
+            let error = service.merge_database(&path, dry_run).unwrap_err();
+            assert!(error.to_string().contains("스키마 버전"), "{}", error);
+        }
+        assert_eq!(std::fs::read(&path).unwrap(), before);
+    }
+
+    #[test]
+    fn test_resolve_todo_id() {
+        let service = DefaultTodoService::in_memory().unwrap();
+        let created = service.create_todo(CreateTodo::new("보고서".to_string())).unwrap();
+        let id = created.id.unwrap();
+        assert_eq!(created.uuid.len(), 36);
+        // 무작위 UUID는 숫자로만 시작할 수 있어서(ID로 해석) 정해 둔 UUID로 바꿈
+        service.db.conn().execute("UPDATE todos SET uuid = 'f00dcafe-0000-4000-8000-000000000000' WHERE id = ?1", [id]).unwrap();
+        let todo = service.get_todo_by_id(id).unwrap();
+
+        assert_eq!(service.resolve_todo_id("42").unwrap(), 42);
+        assert_eq!(service.resolve_todo_id(&todo.uuid).unwrap(), id);
+        assert_eq!(service.resolve_todo_id(&todo.uuid[..8].to_uppercase()).unwrap(), id);
+        assert!(matches!(service.resolve_todo_id("보고서"), Err(TaskyError::InvalidInput { .. })));
+        assert!(matches!(service.resolve_todo_id("-"), Err(TaskyError::UuidPrefixNotFound { .. })));
+
+        // 앞부분이 같은 할일이 둘이면 더 길게 입력해야 함
+        let mut file = service.export_todos(&ViewDefinition::default()).unwrap();
+        file.todos[0].uuid = "abcd0000-0000-4000-8000-000000000001".to_string();
+        let mut copy = file.todos[0].clone();
+        copy.id = Some(id + 1);
+        copy.uuid = "abcd0000-0000-4000-8000-000000000002".to_string();
+        file.todos.push(copy);
+        service.import_todos(&file, ConflictStrategy::Renumber, false).unwrap();
+        assert!(matches!(service.resolve_todo_id("abcd"), Err(TaskyError::AmbiguousUuidPrefix { count: 2, .. })));
+        assert!(service.resolve_todo_id("abcd0000-0000-4000-8000-000000000002").is_ok());
+
+        // 휴지통의 할일도 찾음
+        service.delete_todo(id).unwrap();
+        assert_eq!(service.resolve_todo_id(&todo.uuid[..8]).unwrap(), id);
+    }
+
+    #[test]
+    fn test_archive_done_todos() {
+        let (service, clock) = service_at("2026-10-01 09:00");
+
+        let old = service.create_todo(CreateTodo::new("지난달 보고서".to_string())).unwrap().id.unwrap();
+        let recent = service.create_todo(CreateTodo::new("이번주 회의".to_string())).unwrap().id.unwrap();
+        let pending = service.create_todo(CreateTodo::new("진행중".to_string())).unwrap().id.unwrap();
+
+        service.complete_todo(old).unwrap();
+        clock.advance(chrono::Duration::days(14));
+        service.complete_todo(recent).unwrap();
+
+        assert!(service.archive_todo(pending).is_err());
+
+        let cutoff = clock.now() - chrono::Duration::days(7);
+        let archived = service.archive_done_before(cutoff).unwrap();
+        assert_eq!(archived.len(), 1);
+        assert_eq!(archived[0].id, Some(old));
+        assert!(service.archive_todo(old).is_err());
+
+        // 기본 목록과 통계에서는 빠지지만 ID로는 조회 가능
+        assert_eq!(service.list_todos(None, None, None).unwrap().len(), 2);
+        let stats = service.get_stats(None, false).unwrap();
+        assert_eq!(stats.total_todos, 2);
+        assert_eq!(stats.archived_todos, 1);
+        assert_eq!(service.get_stats(None, true).unwrap().total_todos, 3);
+        assert!(service.get_todo_by_id(old).unwrap().archived_at.is_some());
+
+        let mut filter = TodoFilter::default();
+        filter.include_archived = true;
+        assert_eq!(service.list_todos(Some(filter), None, None).unwrap().len(), 3);
+
+        // 저장된 보기도 --archived를 줄 때만 포함
+        let mut definition = ViewDefinition::default();
+        assert!(service.list_view(&definition).unwrap().iter().all(|todo| todo.id != Some(old)));
+        definition.archived = true;
+        assert!(service.list_view(&definition).unwrap().iter().any(|todo| todo.id == Some(old)));
+
+        // 검색에는 나옴
+        let found = service.search_todos(&SearchQuery::parse("보고서").unwrap(), 10).unwrap();
+        assert_eq!(found.iter().map(|todo| todo.id).collect::<Vec<_>>(), vec![Some(old)]);
+
+        // 내보내기에는 보관 시각과 함께 포함되고, 가져오면 보관된 채로 들어옴
+        let file = service.export_todos(&ViewDefinition::default()).unwrap();
+        let exported = file.todos.iter().find(|todo| todo.id == Some(old)).unwrap();
+        assert_eq!(exported.archived_at, service.get_todo_by_id(old).unwrap().archived_at);
+        let (other, _) = service_at("2026-10-18 09:00");
+        other.import_todos(&file, ConflictStrategy::Fail, false).unwrap();
+        assert!(other.get_todo_by_id(old).unwrap().archived_at.is_some());
+        assert_eq!(other.list_todos(None, None, None).unwrap().len(), 2);
+
+        // 보관 해제하면 다시 목록에
+        service.unarchive_todo(old).unwrap();
+        assert_eq!(service.list_todos(None, None, None).unwrap().len(), 3);
+        service.archive_todo(old).unwrap();
+
+        // 완료를 취소하면 보관도 해제되고 기본 목록과 통계로 돌아옴
+        let todo = service.uncomplete_todo(old).unwrap();
+        assert!(todo.archived_at.is_none());
+        assert!(service.unarchive_todo(old).is_err());
+        assert_eq!(service.list_todos(None, None, None).unwrap().len(), 3);
+        assert_eq!(service.get_stats(None, false).unwrap().archived_todos, 0);
+    }
+
+    #[test]
+    fn test_complete_recurring_at_date_limit() {
+        let (service, _) = service_at("2026-10-15 08:00");
+
+        // 다음 회차가 표현할 수 있는 날짜를 넘으면 오류이고 완료도 되지 않음
+        let last_day = chrono::NaiveDate::MAX.and_hms_opt(0, 0, 0).unwrap().and_utc();
+        let todo = service.create_todo(CreateTodo::new("마지막 날".to_string())
+            .with_due_date(last_day)
+            .with_recurrence(RecurrenceRule::Days(1))).unwrap();
+        let id = todo.id.unwrap();
+
+        assert!(matches!(service.complete_todo(id), Err(TaskyError::InvalidInput { .. })));
+        assert_eq!(service.get_todo_by_id(id).unwrap().status, Status::Pending);
+        assert_eq!(service.list_series().unwrap()[0].total_todos, 1);
+    }
+
+    #[test]
+    fn test_undo_recurring_done_removes_next_occurrence() {
+        let (service, clock) = service_at("2026-10-15 08:00");
+        let todo = service.create_todo(CreateTodo::new("물 주기".to_string())
+            .with_due_date(clock.today_start())
+            .with_recurrence(RecurrenceRule::Days(1))).unwrap();
+        let series_id = todo.series_id.unwrap();
+
+        service.begin_operation("done").unwrap();
+        service.complete_todo(todo.id.unwrap()).unwrap();
+        service.end_operation().unwrap();
+        assert!(service.get_next_occurrence(series_id).unwrap().is_some());
+
+        service.undo(1).unwrap();
+        let pending = service.get_next_occurrence(series_id).unwrap().unwrap();
+        assert_eq!(pending.id, todo.id);
+    }
+
     #[test]
     fn test_validate_empty_title() {
         let service = DefaultTodoService::in_memory().unwrap();
@@ -269,7 +1805,7 @@ mod tests {
         service.complete_todo(todo3.id.unwrap()).unwrap();
         
         // 통계 확인
-        let stats = service.get_stats().unwrap();
+        let stats = service.get_stats(None, false).unwrap();
         assert_eq!(stats.total_todos, 3);
         assert_eq!(stats.pending_todos, 2);
         assert_eq!(stats.completed_todos, 1);
@@ -277,23 +1813,38 @@ mod tests {
     }
     
     #[test]
-    fn test_overdue_todos() {
+    fn test_tags_are_normalized() {
         let service = DefaultTodoService::in_memory().unwrap();
 
+        let create = CreateTodo::new("태그 할일".to_string())
+            .with_tags(vec!["#Work".to_string(), "work".to_string(), " home ".to_string()]);
+        let todo = service.create_todo(create).unwrap();
+        assert_eq!(todo.tags, vec!["home".to_string(), "work".to_string()]);
+
+        let create = CreateTodo::new("잘못된 태그".to_string())
+            .with_tags(vec!["a,b".to_string()]);
+        assert!(service.create_todo(create).is_err());
+    }
+
+    #[test]
+    fn test_overdue_todos() {
+        // 자정 직전에도 결과가 흔들리지 않아야 함
+        let (service, clock) = service_at("2026-10-15 23:59:30");
+
         // 어제 마감인 할일 생성
-        let yesterday = Utc::now() - Duration::days(1);
+        let yesterday = clock.now() - Duration::days(1);
         let create_overdue = CreateTodo::new("늦은 할일".to_string())
             .with_due_date(yesterday);
         service.create_todo(create_overdue).unwrap();
 
         // 오늘 마감인 할일 생성 (overdue에 포함되지 않아야 함)
-        let today = crate::utils::today_start();
+        let today = clock.today_start();
         let create_today = CreateTodo::new("오늘 할일".to_string())
             .with_due_date(today);
         service.create_todo(create_today).unwrap();
 
         // 내일 마감인 할일 생성 (overdue에 포함되지 않아야 함)
-        let tomorrow = Utc::now() + Duration::days(1);
+        let tomorrow = clock.now() + Duration::days(1);
         let create_future = CreateTodo::new("내일 할일".to_string())
             .with_due_date(tomorrow);
         service.create_todo(create_future).unwrap();
@@ -308,4 +1859,29 @@ mod tests {
         assert_eq!(today_todos.len(), 1);
         assert_eq!(today_todos[0].title, "오늘 할일");
     }
+
+    #[test]
+    fn test_overdue_follows_clock() {
+        let (service, clock) = service_at("2026-10-15 23:00");
+
+        let timed = service.create_todo(CreateTodo::new("회의".to_string())
+            .with_due_time(clock.now() + Duration::minutes(30))).unwrap();
+        let all_day = service.create_todo(CreateTodo::new("제출".to_string())
+            .with_due_date(clock.today_start())).unwrap();
+        assert_eq!(timed.created_at, clock.now());
+        assert!(service.get_overdue_todos().unwrap().is_empty());
+
+        // 마감 시각이 지나면 시각 마감만 기한 초과
+        clock.advance(Duration::minutes(45));
+        let overdue = service.get_overdue_todos().unwrap();
+        assert_eq!(overdue.len(), 1);
+        assert_eq!(overdue[0].id, timed.id);
+        assert!(timed.is_overdue(&*clock));
+        assert!(!all_day.is_overdue(&*clock));
+
+        // 자정을 넘기면 오늘 종일 마감도 기한 초과
+        clock.advance(Duration::hours(1));
+        assert_eq!(service.get_overdue_todos().unwrap().len(), 2);
+        assert!(all_day.is_overdue(&*clock));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,138 @@
+//! 현재 시각 공급자
+//!
+//! 저장소, 서비스, 날짜 유틸리티는 `Utc::now()`를 직접 부르지 않고 `Clock`을 통해 현재 시각을 얻는다.
+//! 기본은 시스템 시계이며, 테스트는 `FixedClock`을, CLI는 `TASKY_NOW` 환경변수로 시각을 고정할 수 있다.
+
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, Utc};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use crate::error::{Result, TaskyError};
+use crate::utils::date::{day_end, day_start, local_to_utc};
+
+pub trait Clock: fmt::Debug + Send + Sync {
+  fn now(&self) -> DateTime<Utc>;
+
+  /// 로컬 기준 오늘 날짜
+  fn today(&self) -> NaiveDate {
+    self.now().with_timezone(&Local).date_naive()
+  }
+
+  /// 오늘 로컬 자정
+  fn today_start(&self) -> DateTime<Utc> {
+    day_start(self.today())
+  }
+
+  /// 오늘 로컬 23:59:59
+  fn today_end(&self) -> DateTime<Utc> {
+    day_end(self.today())
+  }
+}
+
+pub type SharedClock = Arc<dyn Clock>;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+  fn now(&self) -> DateTime<Utc> {
+    Utc::now()
+  }
+}
+
+/// 지정한 시각에 멈춰 있는 시계. `set`/`advance`로만 움직인다.
+#[derive(Debug)]
+pub struct FixedClock {
+  now: Mutex<DateTime<Utc>>,
+}
+
+impl FixedClock {
+  pub fn new(now: DateTime<Utc>) -> Self {
+    Self { now: Mutex::new(now) }
+  }
+
+  /// `TASKY_NOW`와 같은 형식의 문자열로 생성
+  pub fn parse(source: &str) -> Result<Self> {
+    Ok(Self::new(parse_clock_time(source)?))
+  }
+
+  pub fn set(&self, now: DateTime<Utc>) {
+    *self.now.lock().unwrap() = now;
+  }
+
+  pub fn advance(&self, duration: Duration) {
+    *self.now.lock().unwrap() += duration;
+  }
+}
+
+impl Clock for FixedClock {
+  fn now(&self) -> DateTime<Utc> {
+    *self.now.lock().unwrap()
+  }
+}
+
+/// 고정 시각 파싱: RFC 3339(`2026-10-15T09:00:00Z`), 로컬 시각(`2026-10-15 09:00[:00]`, `2026-10-15T09:00`),
+/// 날짜만(`2026-10-15`, 로컬 자정)
+pub fn parse_clock_time(source: &str) -> Result<DateTime<Utc>> {
+  let source = source.trim();
+
+  if let Ok(dt) = DateTime::parse_from_rfc3339(source) {
+    return Ok(dt.with_timezone(&Utc));
+  }
+
+  for format in ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M"] {
+    if let Ok(naive) = NaiveDateTime::parse_from_str(source, format) {
+      return local_to_utc(naive, source);
+    }
+  }
+
+  match NaiveDate::parse_from_str(source, "%Y-%m-%d") {
+    Ok(date) => Ok(day_start(date)),
+    Err(_) => Err(TaskyError::InvalidDateFormat { date: source.to_string() }),
+  }
+}
+
+/// 환경변수 TASKY_NOW가 설정되어 있으면 그 시각에 고정된 시계, 아니면 시스템 시계
+pub fn clock_from_env() -> Result<SharedClock> {
+  match std::env::var("TASKY_NOW") {
+    Ok(value) if !value.trim().is_empty() => {
+      let clock = FixedClock::parse(&value).map_err(|_| TaskyError::InvalidInput {
+        message: format!("TASKY_NOW 값을 해석할 수 없습니다: {} (예: 2026-10-15T09:00:00+09:00, 2026-10-15 09:00)", value),
+      })?;
+      Ok(Arc::new(clock))
+    }
+    _ => Ok(Arc::new(SystemClock)),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_fixed_clock() {
+    let clock = FixedClock::parse("2026-10-15 23:30").unwrap();
+    assert_eq!(clock.today(), NaiveDate::from_ymd_opt(2026, 10, 15).unwrap());
+    assert_eq!(clock.today_start(), parse_clock_time("2026-10-15").unwrap());
+
+    // 자정을 넘기면 오늘이 바뀐다
+    clock.advance(Duration::hours(1));
+    assert_eq!(clock.today(), NaiveDate::from_ymd_opt(2026, 10, 16).unwrap());
+    assert_eq!(clock.today_end() - clock.today_start(), Duration::seconds(86399));
+  }
+
+  #[test]
+  fn test_parse_clock_time() {
+    let utc = parse_clock_time("2026-10-15T09:00:00Z").unwrap();
+    assert_eq!(utc.to_rfc3339(), "2026-10-15T09:00:00+00:00");
+
+    let offset = parse_clock_time("2026-10-15T18:00:00+09:00").unwrap();
+    assert_eq!(offset, utc);
+
+    assert_eq!(
+      parse_clock_time("2026-10-15 09:00").unwrap(),
+      parse_clock_time("2026-10-15T09:00:00").unwrap()
+    );
+    assert!(parse_clock_time("yesterday").is_err());
+  }
+}
@@ -1,31 +1,17 @@
-use chrono::{DateTime, Local, NaiveDate, TimeZone, Utc};
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
 
 use crate::error::{Result, TaskyError};
+use crate::utils::clock::Clock;
+use crate::utils::natural_date::parse_natural_date;
 
-fn parse_relative_date(date_str: &str) -> Option<i64> {
-    if date_str.starts_with('+') {
-        // +n 형식 (n일 후)
-        date_str[1..].parse::<i64>().ok()
-    } else if date_str.starts_with('-') {
-        // -n 형식 (n일 전)
-        date_str[1..].parse::<i64>().map(|n| -n).ok()
-    } else {
-        None
-    }
+/// 파싱된 마감일. 종일(all_day)이면 로컬 자정, 아니면 지정한 시각
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DueDate {
+    pub at: DateTime<Utc>,
+    pub all_day: bool,
 }
 
-pub fn parse_date(date_str: &str) -> Result<DateTime<Utc>> {
-    // 상대적 날짜 형식 지원 (+n, -n)
-    if let Some(relative_days) = parse_relative_date(date_str) {
-        let today = Local::now().date_naive();
-        let target_date = today + chrono::Duration::days(relative_days);
-        let local_datetime = Local
-            .from_local_datetime(&target_date.and_hms_opt(0, 0, 0).unwrap())
-            .single()
-            .ok_or_else(|| TaskyError::InvalidDateFormat { date: date_str.to_string() })?;
-        return Ok(local_datetime.with_timezone(&Utc));
-    }
-
+fn parse_naive_date(date_str: &str, today: NaiveDate) -> Option<NaiveDate> {
     // 다양한 날짜 형식을 지원
     let date_formats = vec![
         "%Y-%m-%d",        // ISO 형식: 2024-12-31
@@ -39,23 +25,118 @@ pub fn parse_date(date_str: &str) -> Result<DateTime<Utc>> {
         "%d %B %Y",        // 전체 월 이름 한국식: 31 December 2024
     ];
 
-    let mut naive_date = None;
-    for format in &date_formats {
-        if let Ok(date) = NaiveDate::parse_from_str(date_str, format) {
-            naive_date = Some(date);
-            break;
+    date_formats
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(date_str, format).ok())
+        // 상대 날짜 (today, next friday, in 2 weeks, 내일, 다음주 금요일 등)
+        .or_else(|| parse_natural_date(date_str, today))
+}
+
+/// 시각 파싱: `14:30`, `9am`, `9:30pm`, `15시`, `오후 3시 30분`
+fn parse_time(time_str: &str) -> Option<NaiveTime> {
+    let lower = time_str.trim().to_lowercase();
+
+    for format in ["%H:%M", "%H:%M:%S"] {
+        if let Ok(time) = NaiveTime::parse_from_str(&lower, format) {
+            return Some(time);
         }
     }
 
-    let naive_date = naive_date
-        .ok_or_else(|| TaskyError::InvalidDateFormat { date: date_str.to_string() })?;
+    // 12시간제 (am/pm, 오전/오후)
+    let (rest, meridiem) = if let Some(rest) = lower.strip_suffix("am") {
+        (rest.trim(), Some(false))
+    } else if let Some(rest) = lower.strip_suffix("pm") {
+        (rest.trim(), Some(true))
+    } else if let Some(rest) = lower.strip_prefix("오전") {
+        (rest.trim(), Some(false))
+    } else if let Some(rest) = lower.strip_prefix("오후") {
+        (rest.trim(), Some(true))
+    } else {
+        (lower.as_str(), None)
+    };
+
+    let (hour, minute) = if let Some(korean) = rest.strip_suffix('분') {
+        // 3시 30분
+        let (hour, minute) = korean.split_once('시')?;
+        (hour.trim().parse::<u32>().ok()?, minute.trim().parse::<u32>().ok()?)
+    } else if let Some(hour) = rest.strip_suffix('시') {
+        (hour.trim().parse::<u32>().ok()?, 0)
+    } else if meridiem.is_some() {
+        match rest.split_once(':') {
+            Some((hour, minute)) => (hour.parse::<u32>().ok()?, minute.parse::<u32>().ok()?),
+            None => (rest.parse::<u32>().ok()?, 0),
+        }
+    } else {
+        return None;
+    };
+
+    let hour = match meridiem {
+        Some(pm) => {
+            if !(1..=12).contains(&hour) {
+                return None;
+            }
+            match (pm, hour) {
+                (false, 12) => 0,
+                (true, 12) => 12,
+                (true, h) => h + 12,
+                (false, h) => h,
+            }
+        }
+        None => hour,
+    };
 
-    let local_datetime = Local
-        .from_local_datetime(&naive_date.and_hms_opt(0, 0, 0).unwrap())
-        .single()
+    NaiveTime::from_hms_opt(hour, minute, 0)
+}
+
+pub(crate) fn local_to_utc(naive: NaiveDateTime, source: &str) -> Result<DateTime<Utc>> {
+    Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok_or_else(|| TaskyError::InvalidDateFormat { date: source.to_string() })
+}
+
+/// 날짜 파싱. 상대 날짜는 `clock`의 오늘을 기준으로 해석
+pub fn parse_date(date_str: &str, clock: &dyn Clock) -> Result<DateTime<Utc>> {
+    let naive_date = parse_naive_date(date_str, clock.today())
         .ok_or_else(|| TaskyError::InvalidDateFormat { date: date_str.to_string() })?;
 
-    Ok(local_datetime.with_timezone(&Utc))
+    local_to_utc(naive_date.and_hms_opt(0, 0, 0).unwrap(), date_str)
+}
+
+/// 마감일 파싱. 날짜만 있으면 종일, 시각이 붙으면(`2026-10-20 14:30`, `tomorrow 9am`) 해당 시각
+pub fn parse_due(due_str: &str, clock: &dyn Clock) -> Result<DueDate> {
+    let due_str = due_str.trim();
+    let today = clock.today();
+
+    if let Ok(at) = parse_date(due_str, clock) {
+        return Ok(DueDate { at, all_day: true });
+    }
+
+    // ISO 형식: 2026-10-20T14:30
+    for format in ["%Y-%m-%dT%H:%M", "%Y-%m-%dT%H:%M:%S"] {
+        if let Ok(naive) = NaiveDateTime::parse_from_str(due_str, format) {
+            return Ok(DueDate { at: local_to_utc(naive, due_str)?, all_day: false });
+        }
+    }
+
+    // 시각만 있으면 오늘
+    if let Some(time) = parse_time(due_str) {
+        return Ok(DueDate { at: local_to_utc(today.and_time(time), due_str)?, all_day: false });
+    }
+
+    // 뒤쪽 단어들을 시각, 앞쪽을 날짜로 나눠서 시도
+    let words: Vec<&str> = due_str.split_whitespace().collect();
+    for split in (1..words.len()).rev() {
+        let date_part = words[..split].join(" ");
+        let time_part = words[split..].join(" ");
+
+        if let (Some(date), Some(time)) = (parse_naive_date(&date_part, today), parse_time(&time_part)) {
+            return Ok(DueDate { at: local_to_utc(date.and_time(time), due_str)?, all_day: false });
+        }
+    }
+
+    Err(TaskyError::InvalidDateFormat { date: due_str.to_string() })
 }
 
 pub fn format_datetime(dt: &DateTime<Utc>) -> String {
@@ -68,9 +149,17 @@ pub fn format_date(dt: &DateTime<Utc>) -> String {
   local.format("%Y-%m-%d").to_string()
 }
 
-pub fn format_relative_time(dt: &DateTime<Utc>) -> String {
-  let now = Utc::now();
-  let duration = dt.signed_duration_since(now);
+/// 종일 마감은 날짜만, 시각이 있는 마감은 `YYYY-MM-DD HH:MM`
+pub fn format_due(dt: &DateTime<Utc>, all_day: bool) -> String {
+  if all_day {
+    format_date(dt)
+  } else {
+    dt.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string()
+  }
+}
+
+pub fn format_relative_time(dt: &DateTime<Utc>, clock: &dyn Clock) -> String {
+  let duration = dt.signed_duration_since(clock.now());
 
   if duration.num_days() > 0 {
     format!("{}일 후", duration.num_days())
@@ -89,76 +178,153 @@ pub fn format_relative_time(dt: &DateTime<Utc>) -> String {
   }
 }
 
-pub fn today_start() -> DateTime<Utc> {
-  let local_today = Local::now().date_naive();
-  let local_start = Local
-      .from_local_datetime(&local_today.and_hms_opt(0, 0, 0).unwrap())
-      .single()
-      .unwrap();
-  local_start.with_timezone(&Utc)
+/// 기간 파싱: `30d`, `2w`, `12h`, `30일`, `2주`, `12시간` (공백 허용)
+pub fn parse_duration(source: &str) -> Result<Duration> {
+  let compact: String = source.chars().filter(|c| !c.is_whitespace()).collect();
+  let lower = compact.to_lowercase();
+  let split = lower.find(|c: char| !c.is_ascii_digit()).unwrap_or(lower.len());
+  let (number, unit) = lower.split_at(split);
+
+  let invalid = || TaskyError::InvalidInput {
+    message: format!("잘못된 기간: {}. 예: 30d, 2w, 12h, 30일", source),
+  };
+  let amount: i64 = number.parse().map_err(|_| invalid())?;
+
+  // 범위를 넘는 기간도 잘못된 기간
+  let duration = match unit {
+    "h" | "hour" | "hours" | "시간" => Duration::try_hours(amount),
+    "d" | "day" | "days" | "일" => Duration::try_days(amount),
+    "w" | "week" | "weeks" | "주" => Duration::try_weeks(amount),
+    _ => None,
+  };
+  duration.ok_or_else(invalid)
 }
 
-pub fn today_end() -> DateTime<Utc> {
-  let local_today = Local::now().date_naive();
-  let local_end = Local
-      .from_local_datetime(&local_today.and_hms_opt(23, 59, 59).unwrap())
-      .single()
-      .unwrap();
-  
-  local_end.with_timezone(&Utc)
+/// 해당 날짜의 로컬 자정
+pub fn day_start(date: NaiveDate) -> DateTime<Utc> {
+  local_to_utc(date.and_hms_opt(0, 0, 0).unwrap(), "")
+      .unwrap_or_else(|_| Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0).unwrap()))
+}
+
+/// 해당 날짜의 로컬 23:59:59
+pub fn day_end(date: NaiveDate) -> DateTime<Utc> {
+  local_to_utc(date.and_hms_opt(23, 59, 59).unwrap(), "")
+      .unwrap_or_else(|_| Utc.from_utc_datetime(&date.and_hms_opt(23, 59, 59).unwrap()))
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::utils::clock::{FixedClock, SystemClock};
 
   #[test]
   fn test_parse_date() {
     // ISO 형식
-    let result = parse_date("2024-01-15");
+    let result = parse_date("2024-01-15", &SystemClock);
     assert!(result.is_ok());
 
     // ISO 슬래시 형식
-    let result = parse_date("2024/12/31");
+    let result = parse_date("2024/12/31", &SystemClock);
     assert!(result.is_ok());
 
     // ISO 점 형식
-    let result = parse_date("2024.10.10");
+    let result = parse_date("2024.10.10", &SystemClock);
     assert!(result.is_ok());
 
     // 미국 형식
-    let result = parse_date("12/31/2024");
+    let result = parse_date("12/31/2024", &SystemClock);
     assert!(result.is_ok());
 
     // 유럽 형식
-    let result = parse_date("31/12/2024");
+    let result = parse_date("31/12/2024", &SystemClock);
     assert!(result.is_ok());
 
     // 자연스러운 형식
-    let result = parse_date("Dec 31, 2024");
+    let result = parse_date("Dec 31, 2024", &SystemClock);
     assert!(result.is_ok());
 
     // 한국식
-    let result = parse_date("31 Dec 2024");
+    let result = parse_date("31 Dec 2024", &SystemClock);
     assert!(result.is_ok());
 
     // 전체 월 이름
-    let result = parse_date("December 31, 2024");
+    let result = parse_date("December 31, 2024", &SystemClock);
     assert!(result.is_ok());
 
     // 잘못된 형식
-    let result = parse_date("invalid");
+    let result = parse_date("invalid", &SystemClock);
     assert!(result.is_err());
   }
 
+  #[test]
+  fn test_parse_due() {
+    let clock = FixedClock::parse("2026-10-15 12:00").unwrap();
+    let parse_due = |input: &str| super::parse_due(input, &clock);
+
+    let due = parse_due("2026-10-20").unwrap();
+    assert!(due.all_day);
+
+    let due = parse_due("2026-10-20 14:30").unwrap();
+    assert!(!due.all_day);
+    assert_eq!(format_due(&due.at, due.all_day), "2026-10-20 14:30");
+
+    let due = parse_due("2026-10-20T09:05").unwrap();
+    assert_eq!(format_due(&due.at, due.all_day), "2026-10-20 09:05");
+
+    let due = parse_due("tomorrow 9am").unwrap();
+    assert_eq!(format_due(&due.at, due.all_day), "2026-10-16 09:00");
+
+    let due = parse_due("2026-10-20 12am").unwrap();
+    assert_eq!(format_due(&due.at, due.all_day), "2026-10-20 00:00");
+
+    assert!(parse_due("2026-10-20 25:00").is_err());
+    assert!(parse_due("2026-10-20 13pm").is_err());
+  }
+
+  #[test]
+  fn test_parse_due_natural_dates() {
+    // 2026-10-15 (목요일) 기준
+    let clock = FixedClock::parse("2026-10-15 12:00").unwrap();
+    let format = |input: &str| {
+      let due = parse_due(input, &clock).unwrap();
+      format_due(&due.at, due.all_day)
+    };
+
+    assert_eq!(format("next friday"), "2026-10-23");
+    assert_eq!(format("next friday 3pm"), "2026-10-23 15:00");
+    assert_eq!(format("eom"), "2026-10-31");
+    assert_eq!(format("in 2 weeks 09:30"), "2026-10-29 09:30");
+    assert_eq!(format("내일 오후 3시 30분"), "2026-10-16 15:30");
+    assert_eq!(format("다음주 금요일 오전 10시"), "2026-10-23 10:00");
+    assert_eq!(format("이번달 말"), "2026-10-31");
+    assert_eq!(format("모레 18:00"), "2026-10-17 18:00");
+    assert_eq!(format("14:00"), "2026-10-15 14:00");
+    // 고정 형식이 상대 날짜보다 우선
+    assert_eq!(format("12/31/2024"), "2024-12-31");
+  }
+
   #[test]
   fn test_format_relative_time() {
-    let now = Utc::now();
-    let tomorrow = now + chrono::Duration::days(1);
-    let yesterday = now - chrono::Duration::days(1);
+    let clock = FixedClock::parse("2026-10-15T09:00:00Z").unwrap();
+    let now = clock.now();
 
-    assert!(format_relative_time(&tomorrow).contains("후"));
-    assert!(format_relative_time(&yesterday).contains("전"));
-    assert_eq!(format_relative_time(&now), "지금");
+    assert_eq!(format_relative_time(&(now + chrono::Duration::days(1)), &clock), "1일 후");
+    assert_eq!(format_relative_time(&(now - chrono::Duration::days(2)), &clock), "2일 전");
+    assert_eq!(format_relative_time(&(now + chrono::Duration::hours(3)), &clock), "3시간 후");
+    assert_eq!(format_relative_time(&now, &clock), "지금");
+  }
+
+  #[test]
+  fn test_parse_duration() {
+    assert_eq!(parse_duration("30d").unwrap(), Duration::days(30));
+    assert_eq!(parse_duration("2w").unwrap(), Duration::weeks(2));
+    assert_eq!(parse_duration("12 hours").unwrap(), Duration::hours(12));
+    assert_eq!(parse_duration("30일").unwrap(), Duration::days(30));
+    assert!(parse_duration("30").is_err());
+    assert!(parse_duration("d").is_err());
+    assert!(parse_duration("3y").is_err());
+    assert!(parse_duration("999999999999999999d").is_err());
+    assert!(parse_duration("999999999999999999h").is_err());
+    assert!(parse_duration("99999999999999999999w").is_err());
   }
 }
\ No newline at end of file
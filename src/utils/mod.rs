@@ -1,7 +1,12 @@
+pub mod clock;
 pub mod date;
+pub mod natural_date;
 pub mod text;
 
+pub use clock::{clock_from_env, parse_clock_time, Clock, FixedClock, SharedClock, SystemClock};
 pub use date::{
-    format_date, format_datetime, format_relative_time, parse_date, today_end, today_start,
+    day_end, day_start, format_date, format_datetime, format_due, format_relative_time, parse_date,
+    parse_due, parse_duration, DueDate,
 };
+pub use natural_date::parse_natural_date;
 pub use text::{truncate_text, truncate_title_for_terminal};
\ No newline at end of file
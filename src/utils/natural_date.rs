@@ -0,0 +1,451 @@
+//! 자연어 상대 날짜 파서
+//!
+//! `today`, `next friday`, `in 2 weeks`, `eom`, `내일`, `다음주 금요일`, `이번달 말` 같은
+//! 표현을 기준 날짜(`today`)로부터 계산한다. 현재 시각을 직접 읽지 않으므로 결과가 결정적이다.
+//! 주는 월요일에 시작한다.
+
+use chrono::{Datelike, Duration, Months, NaiveDate, Weekday};
+
+/// 자연어 날짜를 해석. 인식하지 못하면 None
+pub fn parse_natural_date(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+  let lower = input.trim().to_lowercase();
+  if lower.is_empty() {
+    return None;
+  }
+
+  parse_offset(&lower, today)
+    .or_else(|| parse_english(&lower, today))
+    .or_else(|| parse_korean(&lower.split_whitespace().collect::<String>(), today))
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Unit {
+  Day,
+  Week,
+  Month,
+  Year
+}
+
+fn add(date: NaiveDate, amount: i64, unit: Unit) -> Option<NaiveDate> {
+  match unit {
+    Unit::Day => date.checked_add_signed(Duration::try_days(amount)?),
+    Unit::Week => date.checked_add_signed(Duration::try_weeks(amount)?),
+    Unit::Month | Unit::Year => {
+      let months = if matches!(unit, Unit::Year) { amount.checked_mul(12)? } else { amount };
+      let delta = Months::new(u32::try_from(months.unsigned_abs()).ok()?);
+      if months >= 0 {
+        date.checked_add_months(delta)
+      } else {
+        date.checked_sub_months(delta)
+      }
+    }
+  }
+}
+
+fn week_start(date: NaiveDate) -> NaiveDate {
+  date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+/// 기준 날짜가 속한 주에서 `weeks`주 뒤의 해당 요일
+fn weekday_in_week(today: NaiveDate, weeks: i64, weekday: Weekday) -> NaiveDate {
+  week_start(today) + Duration::weeks(weeks) + Duration::days(weekday.num_days_from_monday() as i64)
+}
+
+/// 오늘 이후(오늘 제외) 가장 가까운 해당 요일
+fn upcoming_weekday(today: NaiveDate, weekday: Weekday) -> NaiveDate {
+  let diff = (weekday.num_days_from_monday() as i64 - today.weekday().num_days_from_monday() as i64 + 7) % 7;
+  today + Duration::days(if diff == 0 { 7 } else { diff })
+}
+
+fn first_of_month(date: NaiveDate) -> NaiveDate {
+  date.with_day(1).unwrap_or(date)
+}
+
+fn end_of_month(date: NaiveDate) -> Option<NaiveDate> {
+  Some(first_of_month(date).checked_add_months(Months::new(1))? - Duration::days(1))
+}
+
+fn end_of_year(date: NaiveDate) -> Option<NaiveDate> {
+  NaiveDate::from_ymd_opt(date.year(), 12, 31)
+}
+
+/// 월/일만 주어진 날짜: 올해 날짜가 이미 지났으면 내년
+fn next_month_day(today: NaiveDate, month: u32, day: u32) -> Option<NaiveDate> {
+  let this_year = NaiveDate::from_ymd_opt(today.year(), month, day)?;
+  if this_year < today {
+    NaiveDate::from_ymd_opt(today.year() + 1, month, day)
+  } else {
+    Some(this_year)
+  }
+}
+
+/// `+3`, `-2`, `+3d`, `+2w`, `+1m`, `+1y`
+fn parse_offset(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+  let (sign, rest) = if let Some(rest) = input.strip_prefix('+') {
+    (1, rest)
+  } else if let Some(rest) = input.strip_prefix('-') {
+    (-1, rest)
+  } else {
+    return None;
+  };
+
+  let (number, unit) = match rest.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+    Some((index, _)) => (&rest[..index], parse_unit(&rest[index..])?),
+    None => (rest, Unit::Day),
+  };
+
+  add(today, sign * number.parse::<i64>().ok()?, unit)
+}
+
+fn parse_unit(unit: &str) -> Option<Unit> {
+  match unit {
+    "d" | "day" | "days" => Some(Unit::Day),
+    "w" | "week" | "weeks" => Some(Unit::Week),
+    "m" | "month" | "months" => Some(Unit::Month),
+    "y" | "year" | "years" => Some(Unit::Year),
+    _ => None,
+  }
+}
+
+fn parse_english_weekday(word: &str) -> Option<Weekday> {
+  match word {
+    "mon" | "monday" => Some(Weekday::Mon),
+    "tue" | "tues" | "tuesday" => Some(Weekday::Tue),
+    "wed" | "wednesday" => Some(Weekday::Wed),
+    "thu" | "thur" | "thurs" | "thursday" => Some(Weekday::Thu),
+    "fri" | "friday" => Some(Weekday::Fri),
+    "sat" | "saturday" => Some(Weekday::Sat),
+    "sun" | "sunday" => Some(Weekday::Sun),
+    _ => None,
+  }
+}
+
+fn parse_english_month(word: &str) -> Option<u32> {
+  let months = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+  ];
+  let full = [
+    "january", "february", "march", "april", "may", "june", "july", "august", "september",
+    "october", "november", "december",
+  ];
+
+  months.iter().position(|m| *m == word)
+    .or_else(|| full.iter().position(|m| *m == word))
+    .map(|index| index as u32 + 1)
+}
+
+fn parse_english_count(word: &str) -> Option<i64> {
+  match word {
+    "a" | "an" | "one" => Some(1),
+    "two" => Some(2),
+    "three" => Some(3),
+    _ => word.parse::<i64>().ok().filter(|n| *n >= 0),
+  }
+}
+
+fn parse_english(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+  let words: Vec<&str> = input
+    .split_whitespace()
+    .filter(|w| *w != "the")
+    .collect();
+
+  match words.as_slice() {
+    ["today"] | ["tod"] => Some(today),
+    ["tomorrow"] | ["tmr"] | ["tmrw"] => Some(today + Duration::days(1)),
+    ["yesterday"] => Some(today - Duration::days(1)),
+
+    ["eow"] | ["end", "of", "week"] => Some(weekday_in_week(today, 0, Weekday::Sun)),
+    ["end", "of", "next", "week"] => Some(weekday_in_week(today, 1, Weekday::Sun)),
+    ["eom"] | ["end", "of", "month"] => end_of_month(today),
+    ["end", "of", "next", "month"] => end_of_month(add(today, 1, Unit::Month)?),
+    ["eoy"] | ["end", "of", "year"] => end_of_year(today),
+
+    ["next", "week"] => Some(weekday_in_week(today, 1, Weekday::Mon)),
+    ["next", "month"] => Some(first_of_month(add(today, 1, Unit::Month)?)),
+    ["next", "year"] => NaiveDate::from_ymd_opt(today.year() + 1, 1, 1),
+
+    ["this", weekday] => Some(weekday_in_week(today, 0, parse_english_weekday(weekday)?)),
+    ["next", weekday] => Some(weekday_in_week(today, 1, parse_english_weekday(weekday)?)),
+    [weekday] if parse_english_weekday(weekday).is_some() => {
+      Some(upcoming_weekday(today, parse_english_weekday(weekday)?))
+    }
+
+    ["in", count, unit] => add(today, parse_english_count(count)?, parse_unit(unit)?),
+    [count, unit, "later"] | [count, unit, "from", "now"] => {
+      add(today, parse_english_count(count)?, parse_unit(unit)?)
+    }
+    [count, unit, "ago"] => add(today, -parse_english_count(count)?, parse_unit(unit)?),
+
+    // oct 20, october 20, 20 oct
+    [month, day] if parse_english_month(month).is_some() => {
+      next_month_day(today, parse_english_month(month)?, day.trim_end_matches(',').parse().ok()?)
+    }
+    [day, month] if parse_english_month(month).is_some() => {
+      next_month_day(today, parse_english_month(month)?, day.parse().ok()?)
+    }
+
+    _ => None,
+  }
+}
+
+fn parse_korean_weekday(word: &str) -> Option<Weekday> {
+  let day = word.strip_suffix("요일").unwrap_or(word);
+  match day {
+    "월" => Some(Weekday::Mon),
+    "화" => Some(Weekday::Tue),
+    "수" => Some(Weekday::Wed),
+    "목" => Some(Weekday::Thu),
+    "금" => Some(Weekday::Fri),
+    "토" => Some(Weekday::Sat),
+    "일" => Some(Weekday::Sun),
+    _ => None,
+  }
+}
+
+/// `3일후`, `2주뒤`, `1개월후`, `1달후`, `1년후`, `3일전`
+fn parse_korean_offset(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+  let (body, sign) = if let Some(body) = input.strip_suffix('후').or_else(|| input.strip_suffix('뒤')) {
+    (body, 1)
+  } else if let Some(body) = input.strip_suffix('전') {
+    (body, -1)
+  } else {
+    return None;
+  };
+
+  let split = body.char_indices().find(|(_, c)| !c.is_ascii_digit())?.0;
+  let amount = body[..split].parse::<i64>().ok()?;
+  let unit = match &body[split..] {
+    "일" => Unit::Day,
+    "주" | "주일" => Unit::Week,
+    "개월" | "달" => Unit::Month,
+    "년" => Unit::Year,
+    _ => return None,
+  };
+
+  add(today, sign * amount, unit)
+}
+
+/// `10월20일`, `20일`
+fn parse_korean_month_day(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+  let body = input.strip_suffix('일')?;
+
+  match body.split_once('월') {
+    Some((month, day)) => next_month_day(today, month.parse().ok()?, day.parse().ok()?),
+    None => {
+      // 일만 있으면 이번 달, 이미 지났으면 다음 달
+      let day: u32 = body.parse().ok()?;
+      let this_month = today.with_day(day);
+      match this_month {
+        Some(date) if date >= today => Some(date),
+        _ => add(first_of_month(today), 1, Unit::Month)?.with_day(day),
+      }
+    }
+  }
+}
+
+/// 공백이 제거된 한국어 표현
+fn parse_korean(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+  match input {
+    "오늘" => return Some(today),
+    "내일" => return Some(today + Duration::days(1)),
+    "모레" => return Some(today + Duration::days(2)),
+    "글피" => return Some(today + Duration::days(3)),
+    "어제" => return Some(today - Duration::days(1)),
+    "그제" | "그저께" => return Some(today - Duration::days(2)),
+
+    "이번주말" => return Some(weekday_in_week(today, 0, Weekday::Sun)),
+    "다음주말" => return Some(weekday_in_week(today, 1, Weekday::Sun)),
+    "다음주" => return Some(weekday_in_week(today, 1, Weekday::Mon)),
+    "다음달" => return Some(first_of_month(add(today, 1, Unit::Month)?)),
+    "내년" => return NaiveDate::from_ymd_opt(today.year() + 1, 1, 1),
+
+    "이번달말" | "이달말" | "월말" => return end_of_month(today),
+    "다음달말" => return end_of_month(add(today, 1, Unit::Month)?),
+    "올해말" | "연말" => return end_of_year(today),
+    _ => {}
+  }
+
+  // 이번주/다음주/다다음주 + 요일
+  for (prefix, weeks) in [("다다음주", 2), ("다음주", 1), ("이번주", 0)] {
+    if let Some(day) = input.strip_prefix(prefix) {
+      return Some(weekday_in_week(today, weeks, parse_korean_weekday(day)?));
+    }
+  }
+
+  // 요일만 있으면 다가오는 요일 ("일"은 날짜와 헷갈리므로 "일요일"만 허용)
+  if input.ends_with("요일") {
+    return parse_korean_weekday(input).map(|weekday| upcoming_weekday(today, weekday));
+  }
+
+  parse_korean_offset(input, today).or_else(|| parse_korean_month_day(input, today))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // 기준일: 2026-10-15 (목요일)
+  fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, 10, 15).unwrap()
+  }
+
+  fn assert_parses(input: &str, expected: &str) {
+    let expected = NaiveDate::parse_from_str(expected, "%Y-%m-%d").unwrap();
+    assert_eq!(parse_natural_date(input, today()), Some(expected), "입력: {}", input);
+  }
+
+  #[test]
+  fn test_english_keywords() {
+    assert_parses("today", "2026-10-15");
+    assert_parses("Today", "2026-10-15");
+    assert_parses("tomorrow", "2026-10-16");
+    assert_parses("tmr", "2026-10-16");
+    assert_parses("yesterday", "2026-10-14");
+  }
+
+  #[test]
+  fn test_offsets() {
+    assert_parses("+0", "2026-10-15");
+    assert_parses("+3", "2026-10-18");
+    assert_parses("-2", "2026-10-13");
+    assert_parses("+3d", "2026-10-18");
+    assert_parses("+2w", "2026-10-29");
+    assert_parses("+1m", "2026-11-15");
+    assert_parses("+1y", "2027-10-15");
+    assert_eq!(parse_natural_date("+x", today()), None);
+  }
+
+  #[test]
+  fn test_english_weekdays() {
+    // 요일만: 오늘 이후 가장 가까운 날 (같은 요일이면 다음 주)
+    assert_parses("friday", "2026-10-16");
+    assert_parses("fri", "2026-10-16");
+    assert_parses("thursday", "2026-10-22");
+    assert_parses("monday", "2026-10-19");
+    // this: 이번 주 (월요일 시작)
+    assert_parses("this friday", "2026-10-16");
+    assert_parses("this monday", "2026-10-12");
+    // next: 다음 주
+    assert_parses("next friday", "2026-10-23");
+    assert_parses("next monday", "2026-10-19");
+    assert_parses("next sunday", "2026-10-25");
+  }
+
+  #[test]
+  fn test_english_periods() {
+    assert_parses("eow", "2026-10-18");
+    assert_parses("end of week", "2026-10-18");
+    assert_parses("end of the week", "2026-10-18");
+    assert_parses("end of next week", "2026-10-25");
+    assert_parses("eom", "2026-10-31");
+    assert_parses("end of month", "2026-10-31");
+    assert_parses("end of the month", "2026-10-31");
+    assert_parses("end of next month", "2026-11-30");
+    assert_parses("eoy", "2026-12-31");
+    assert_parses("next week", "2026-10-19");
+    assert_parses("next month", "2026-11-01");
+    assert_parses("next year", "2027-01-01");
+  }
+
+  #[test]
+  fn test_english_durations() {
+    assert_parses("in 2 weeks", "2026-10-29");
+    assert_parses("in 3 days", "2026-10-18");
+    assert_parses("in a week", "2026-10-22");
+    assert_parses("in 1 month", "2026-11-15");
+    assert_parses("in 1 year", "2027-10-15");
+    assert_parses("2 days later", "2026-10-17");
+    assert_parses("3 days from now", "2026-10-18");
+    assert_parses("1 week ago", "2026-10-08");
+    assert_eq!(parse_natural_date("in 2 fortnights", today()), None);
+  }
+
+  #[test]
+  fn test_english_month_day() {
+    assert_parses("oct 20", "2026-10-20");
+    assert_parses("October 20", "2026-10-20");
+    assert_parses("20 oct", "2026-10-20");
+    // 이미 지난 날짜는 내년
+    assert_parses("jan 5", "2027-01-05");
+    assert_eq!(parse_natural_date("feb 30", today()), None);
+  }
+
+  #[test]
+  fn test_korean_keywords() {
+    assert_parses("오늘", "2026-10-15");
+    assert_parses("내일", "2026-10-16");
+    assert_parses("모레", "2026-10-17");
+    assert_parses("글피", "2026-10-18");
+    assert_parses("어제", "2026-10-14");
+    assert_parses("그저께", "2026-10-13");
+  }
+
+  #[test]
+  fn test_korean_weeks() {
+    assert_parses("금요일", "2026-10-16");
+    assert_parses("목요일", "2026-10-22");
+    assert_parses("이번주 금요일", "2026-10-16");
+    assert_parses("이번 주 월요일", "2026-10-12");
+    assert_parses("다음주 금요일", "2026-10-23");
+    assert_parses("다음 주 금", "2026-10-23");
+    assert_parses("다다음주 월요일", "2026-10-26");
+    assert_parses("다음주", "2026-10-19");
+    assert_parses("이번주말", "2026-10-18");
+    assert_parses("다음주말", "2026-10-25");
+  }
+
+  #[test]
+  fn test_korean_months() {
+    assert_parses("이번달 말", "2026-10-31");
+    assert_parses("이번 달 말", "2026-10-31");
+    assert_parses("이달 말", "2026-10-31");
+    assert_parses("월말", "2026-10-31");
+    assert_parses("다음달 말", "2026-11-30");
+    assert_parses("다음달", "2026-11-01");
+    assert_parses("올해 말", "2026-12-31");
+    assert_parses("내년", "2027-01-01");
+  }
+
+  #[test]
+  fn test_korean_offsets_and_dates() {
+    assert_parses("3일 후", "2026-10-18");
+    assert_parses("3일뒤", "2026-10-18");
+    assert_parses("2주 후", "2026-10-29");
+    assert_parses("1개월 후", "2026-11-15");
+    assert_parses("1달 뒤", "2026-11-15");
+    assert_parses("1년 후", "2027-10-15");
+    assert_parses("2일 전", "2026-10-13");
+    assert_parses("10월 20일", "2026-10-20");
+    assert_parses("1월 5일", "2027-01-05");
+    assert_parses("20일", "2026-10-20");
+    assert_parses("3일", "2026-11-03");
+  }
+
+  #[test]
+  fn test_unrecognized() {
+    for input in ["", "someday", "next", "다음", "이번주 불요일", "13월 1일", "in days"] {
+      assert_eq!(parse_natural_date(input, today()), None, "입력: {}", input);
+    }
+  }
+
+  #[test]
+  fn test_out_of_range() {
+    // 범위를 넘는 수는 패닉 없이 인식하지 못함
+    let huge = [
+      "+999999999999999999", "-999999999999999999w", "+999999999999999999y", "+99999999999m",
+      "in 999999999999999999 days", "999999999999999999 weeks ago", "999999999999일후", "99999999999년전",
+      "+99999999999999999999",
+    ];
+    for input in huge {
+      assert_eq!(parse_natural_date(input, today()), None, "입력: {}", input);
+    }
+  }
+
+  #[test]
+  fn test_month_end_clamping() {
+    let jan_31 = NaiveDate::from_ymd_opt(2026, 1, 31).unwrap();
+    assert_eq!(parse_natural_date("+1m", jan_31), NaiveDate::from_ymd_opt(2026, 2, 28));
+    assert_eq!(parse_natural_date("다음달 말", jan_31), NaiveDate::from_ymd_opt(2026, 2, 28));
+    assert_eq!(parse_natural_date("end of next month", jan_31), NaiveDate::from_ymd_opt(2026, 2, 28));
+  }
+}